@@ -450,6 +450,7 @@ async fn add_edge(handler: &AbstractGraphCommandHandler, graph_id: GraphId, sour
         target_id: target,
         edge_type: edge_type.to_string(),
         metadata: HashMap::new(),
+        relationship: None,
     };
     
     handler.process_graph_command(
@@ -461,6 +462,7 @@ async fn add_edge(handler: &AbstractGraphCommandHandler, graph_id: GraphId, sour
                 target_id: target,
                 edge_type: edge_type.to_string(),
                 metadata: HashMap::new(),
+                relationship: None,
             },
             "demo".to_string()
         )
@@ -89,6 +89,7 @@ fn setup_demo(
             meta.insert("duration".to_string(), json!("5 minutes"));
             meta
         },
+        created_at: chrono::Utc::now(),
     });
     
     commands.spawn(NodeEntity {
@@ -107,6 +108,7 @@ fn setup_demo(
             meta.insert("duration".to_string(), json!("2 minutes"));
             meta
         },
+        created_at: chrono::Utc::now(),
     });
     
     commands.spawn(NodeEntity {
@@ -125,6 +127,7 @@ fn setup_demo(
             meta.insert("duration".to_string(), json!("1 day"));
             meta
         },
+        created_at: chrono::Utc::now(),
     });
     
     // Connect nodes
@@ -146,6 +149,7 @@ fn setup_demo(
         },
         edge_type: "sequence".to_string(),
         metadata: HashMap::new(),
+        created_at: chrono::Utc::now(),
     });
     
     let edge2_id = EdgeId::new();
@@ -166,6 +170,7 @@ fn setup_demo(
         },
         edge_type: "sequence".to_string(),
         metadata: HashMap::new(),
+        created_at: chrono::Utc::now(),
     });
     
     // Create a knowledge graph
@@ -200,6 +205,7 @@ fn setup_demo(
             meta.insert("category".to_string(), json!("Transaction"));
             meta
         },
+        created_at: chrono::Utc::now(),
     });
     
     commands.spawn(NodeEntity {
@@ -218,6 +224,7 @@ fn setup_demo(
             meta.insert("category".to_string(), json!("Finance"));
             meta
         },
+        created_at: chrono::Utc::now(),
     });
     
     commands.spawn(NodeEntity {
@@ -236,6 +243,7 @@ fn setup_demo(
             meta.insert("category".to_string(), json!("Entity"));
             meta
         },
+        created_at: chrono::Utc::now(),
     });
     
     // Connect knowledge nodes
@@ -250,6 +258,7 @@ fn setup_demo(
         },
         edge_type: "relationship".to_string(),
         metadata: HashMap::new(),
+        created_at: chrono::Utc::now(),
     });
     
     let k_edge2 = EdgeId::new();
@@ -263,6 +272,7 @@ fn setup_demo(
         },
         edge_type: "relationship".to_string(),
         metadata: HashMap::new(),
+        created_at: chrono::Utc::now(),
     });
     
     // Create and populate GraphType instances with the actual data
@@ -1,11 +1,12 @@
 //! Adapter for ConceptGraph implementation
 
 use crate::abstraction::{
-    GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
+    DataValidators, GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
     NodeData, EdgeData, Position3D,
 };
+use crate::queries::GraphStructure;
 use cim_domain::{NodeId, EdgeId, GraphId};
-use cim_conceptgraph::{ConceptGraph, SemanticRelationship, ConceptId, ConceptualPoint};
+use cim_conceptgraph::{ConceptGraph, ConceptType, SemanticRelationship, ConceptId, ConceptualPoint};
 use cim_contextgraph::{NodeId as ContextNodeId, EdgeId as ContextEdgeId};
 use std::collections::HashMap;
 
@@ -25,6 +26,12 @@ pub struct ConceptGraphAdapter {
     edge_metadata: HashMap<EdgeId, HashMap<String, serde_json::Value>>,
     // Store original node types
     node_types: HashMap<NodeId, String>,
+    // Concept type inferred from each node type
+    concept_types: HashMap<NodeId, ConceptType>,
+    // Strength given to edges whose metadata has none
+    default_strength: f32,
+    // Checks run before data is stored
+    validators: DataValidators,
 }
 
 impl ConceptGraphAdapter {
@@ -40,8 +47,129 @@ impl ConceptGraphAdapter {
             node_metadata: HashMap::new(),
             edge_metadata: HashMap::new(),
             node_types: HashMap::new(),
+            concept_types: HashMap::new(),
+            default_strength: 0.5,
+            validators: DataValidators::default(),
         }
     }
+
+    /// Check node and edge data with `validators` before storing it
+    pub fn with_validators(mut self, validators: DataValidators) -> Self {
+        self.validators = validators;
+        self
+    }
+
+    /// Get the concept type inferred for a node
+    pub fn concept_type(&self, node_id: NodeId) -> Option<&ConceptType> {
+        self.concept_types.get(&node_id)
+    }
+
+    /// Build a concept graph from a plain graph structure
+    ///
+    /// Each node keeps its node type and becomes a concept of the type mapped from it, and
+    /// each edge becomes a semantic relationship mapped from its edge type (unknown types
+    /// become `Custom`). Edges without a `strength` entry get a strength of 1.0; their stored
+    /// metadata is left as given, and the strength shows up only when the edge is read back.
+    pub fn from_structure(
+        graph_id: GraphId,
+        name: &str,
+        structure: &GraphStructure,
+    ) -> GraphResult<Self> {
+        let mut adapter = Self::new(graph_id, name);
+        adapter.default_strength = 1.0;
+
+        for node in &structure.nodes {
+            let position = node
+                .position_3d
+                .or_else(|| node.position_2d.map(Position3D::from))
+                .unwrap_or_default();
+
+            adapter.add_node(
+                node.node_id,
+                NodeData {
+                    node_type: node.node_type.clone(),
+                    position,
+                    metadata: node.metadata.clone(),
+                },
+            )?;
+        }
+
+        for edge in &structure.edges {
+            adapter.add_edge(
+                edge.edge_id,
+                edge.source_id,
+                edge.target_id,
+                EdgeData {
+                    edge_type: edge.edge_type.clone(),
+                    metadata: edge.metadata.clone(),
+                },
+            )?;
+        }
+
+        Ok(adapter)
+    }
+
+    /// Stop exposing a node and the edges attached to it
+    ///
+    /// The wrapped ConceptGraph has no removal, so the concept stays in it unmapped; every
+    /// lookup goes through the adapter's maps, which no longer know the node.
+    pub fn remove_node(&mut self, node_id: NodeId) -> GraphResult<()> {
+        let ctx_id = self
+            .node_id_map
+            .remove(&node_id)
+            .ok_or(GraphOperationError::NodeNotFound(node_id))?;
+        self.reverse_node_map.remove(&ctx_id);
+        self.node_metadata.remove(&node_id);
+        self.node_types.remove(&node_id);
+        self.concept_types.remove(&node_id);
+
+        let attached: Vec<EdgeId> = {
+            let graph = self.graph.lock().unwrap();
+            self.edge_id_map
+                .iter()
+                .filter(|(_, edge_ctx)| {
+                    graph
+                        .get_edge(**edge_ctx)
+                        .is_some_and(|(_, source, target)| source == ctx_id || target == ctx_id)
+                })
+                .map(|(edge_id, _)| *edge_id)
+                .collect()
+        };
+        for edge_id in attached {
+            if let Some(edge_ctx) = self.edge_id_map.remove(&edge_id) {
+                self.reverse_edge_map.remove(&edge_ctx);
+            }
+            self.edge_metadata.remove(&edge_id);
+        }
+
+        Ok(())
+    }
+}
+
+/// Map a node type to the concept type used by the concept graph
+pub fn concept_type_for(node_type: &str) -> ConceptType {
+    match node_type {
+        "entity" | "concept" => ConceptType::Entity,
+        "value_object" | "attribute" | "property" => ConceptType::ValueObject,
+        "aggregate" => ConceptType::Aggregate,
+        "service" | "process" | "task" | "activity" => ConceptType::Service,
+        "event" => ConceptType::Event,
+        "command" => ConceptType::Command,
+        "query" => ConceptType::Query,
+        "policy" => ConceptType::Policy,
+        other => ConceptType::Custom(other.to_string()),
+    }
+}
+
+/// Map an edge type to the semantic relationship used by the concept graph
+pub fn semantic_relationship_for(edge_type: &str) -> SemanticRelationship {
+    match edge_type {
+        "similarity" => SemanticRelationship::Similarity,
+        "hierarchy" => SemanticRelationship::Hierarchy,
+        "meronymy" => SemanticRelationship::Meronymy,
+        "causality" => SemanticRelationship::Causality,
+        other => SemanticRelationship::Custom(other.to_string()),
+    }
 }
 
 impl GraphImplementation for ConceptGraphAdapter {
@@ -50,9 +178,11 @@ impl GraphImplementation for ConceptGraphAdapter {
     }
     
     fn add_node(&mut self, node_id: NodeId, data: NodeData) -> GraphResult<()> {
+        self.validators.check_node(&data)?;
         // Store original metadata and type
         self.node_metadata.insert(node_id, data.metadata.clone());
         self.node_types.insert(node_id, data.node_type.clone());
+        self.concept_types.insert(node_id, concept_type_for(&data.node_type));
         
         // Create a ConceptNode from NodeData
         let concept_id = ConceptId::new();
@@ -79,6 +209,7 @@ impl GraphImplementation for ConceptGraphAdapter {
     }
     
     fn add_edge(&mut self, edge_id: EdgeId, source: NodeId, target: NodeId, data: EdgeData) -> GraphResult<()> {
+        self.validators.check_edge(&data)?;
         // Store original metadata
         self.edge_metadata.insert(edge_id, data.metadata.clone());
         
@@ -88,17 +219,11 @@ impl GraphImplementation for ConceptGraphAdapter {
             .ok_or(GraphOperationError::NodeNotFound(target))?;
         
         // Convert edge type to semantic relationship
-        let relationship = match data.edge_type.as_str() {
-            "similarity" => SemanticRelationship::Similarity,
-            "hierarchy" => SemanticRelationship::Hierarchy,
-            "meronymy" => SemanticRelationship::Meronymy,
-            "causality" => SemanticRelationship::Causality,
-            other => SemanticRelationship::Custom(other.to_string()),
-        };
+        let relationship = semantic_relationship_for(&data.edge_type);
         
         let strength = data.metadata.get("strength")
             .and_then(|v| v.as_f64())
-            .unwrap_or(0.5) as f32;
+            .map_or(self.default_strength, |strength| strength as f32);
         
         let ctx_edge_id = self.graph.lock().unwrap().connect_concepts(*source_ctx, *target_ctx, relationship, strength)
             .map_err(|e| GraphOperationError::EdgeCreationFailed(e.to_string()))?;
@@ -299,4 +424,101 @@ impl GraphImplementation for ConceptGraphAdapter {
             })
             .collect()
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::{EdgeInfo, NodeInfo};
+
+    #[test]
+    fn test_from_structure_maps_edges_to_relationships() {
+        let graph_id = GraphId::new();
+        let parent = NodeId::new();
+        let child = NodeId::new();
+        let edge_id = EdgeId::new();
+
+        let node = |node_id| NodeInfo {
+            node_id,
+            graph_id,
+            node_type: "entity".to_string(),
+            position_2d: None,
+            position_3d: None,
+            metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
+            last_modified: chrono::Utc::now(),
+        };
+
+        let structure = GraphStructure {
+            nodes: vec![node(parent), node(child)],
+            edges: vec![EdgeInfo {
+                edge_id,
+                graph_id,
+                source_id: parent,
+                target_id: child,
+                edge_type: "hierarchy".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+                last_modified: chrono::Utc::now(),
+            }],
+            adjacency_list: HashMap::from([(parent, vec![child])]),
+        };
+
+        let adapter = ConceptGraphAdapter::from_structure(graph_id, "Concepts", &structure).unwrap();
+
+        assert_eq!(adapter.list_nodes().len(), 2);
+        let edges = adapter.list_edges();
+        assert_eq!(edges.len(), 1);
+
+        let (id, data, source, target) = &edges[0];
+        assert_eq!(*id, edge_id);
+        assert_eq!(*source, parent);
+        assert_eq!(*target, child);
+        assert_eq!(data.metadata.get("strength"), Some(&serde_json::json!(1.0)));
+        assert!(!adapter.edge_metadata[&edge_id].contains_key("strength"));
+        assert!(structure.edges[0].metadata.is_empty());
+
+        let stored = adapter.edge_id_map[&edge_id];
+        let graph = adapter.graph.lock().unwrap();
+        let (edge, _, _) = graph.get_edge(stored).unwrap();
+        assert!(matches!(edge.relationship_type, SemanticRelationship::Hierarchy));
+        assert!(matches!(adapter.concept_type(parent), Some(ConceptType::Entity)));
+        assert!(matches!(adapter.concept_type(child), Some(ConceptType::Entity)));
+        assert!(matches!(
+            semantic_relationship_for("depends_on"),
+            SemanticRelationship::Custom(ref name) if name == "depends_on"
+        ));
+    }
+
+    #[test]
+    fn test_remove_node_forgets_node_and_attached_edges() {
+        let mut adapter = ConceptGraphAdapter::new(GraphId::new(), "Concepts");
+        let (a, b, c) = (NodeId::new(), NodeId::new(), NodeId::new());
+        for node_id in [a, b, c] {
+            let data = NodeData {
+                node_type: "event".to_string(),
+                position: Position3D::default(),
+                metadata: HashMap::new(),
+            };
+            adapter.add_node(node_id, data).unwrap();
+        }
+        let edge = |edge_type: &str| EdgeData {
+            edge_type: edge_type.to_string(),
+            metadata: HashMap::new(),
+        };
+        let (ab, bc) = (EdgeId::new(), EdgeId::new());
+        adapter.add_edge(ab, a, b, edge("causality")).unwrap();
+        adapter.add_edge(bc, b, c, edge("causality")).unwrap();
+
+        adapter.remove_node(a).unwrap();
+
+        assert!(adapter.concept_type(a).is_none());
+        assert!(adapter.get_node(a).is_err());
+        assert!(adapter.get_edge(ab).is_err());
+        assert_eq!(adapter.list_nodes().len(), 2);
+        assert_eq!(adapter.list_edges().len(), 1);
+        assert!(adapter.get_edge(bc).is_ok());
+        assert!(matches!(adapter.concept_type(b), Some(ConceptType::Event)));
+        assert!(adapter.remove_node(a).is_err());
+    }
+}
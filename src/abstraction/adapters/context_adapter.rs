@@ -1,7 +1,7 @@
 //! Adapter for ContextGraph implementation
 
 use crate::abstraction::{
-    GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
+    DataValidators, GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
     NodeData, EdgeData, Position3D,
 };
 use cim_contextgraph::ContextGraph;
@@ -20,6 +20,8 @@ pub struct ContextGraphAdapter {
     // Reverse maps
     node_id_reverse: HashMap<cim_contextgraph::NodeId, NodeId>,
     edge_id_reverse: HashMap<cim_contextgraph::EdgeId, EdgeId>,
+    // Checks run before data is stored
+    validators: DataValidators,
 }
 
 impl ContextGraphAdapter {
@@ -35,8 +37,15 @@ impl ContextGraphAdapter {
             edge_id_map: HashMap::new(),
             node_id_reverse: HashMap::new(),
             edge_id_reverse: HashMap::new(),
+            validators: DataValidators::default(),
         }
     }
+
+    /// Check node and edge data with `validators` before storing it
+    pub fn with_validators(mut self, validators: DataValidators) -> Self {
+        self.validators = validators;
+        self
+    }
 }
 
 impl GraphImplementation for ContextGraphAdapter {
@@ -45,6 +54,7 @@ impl GraphImplementation for ContextGraphAdapter {
     }
     
     fn add_node(&mut self, node_id: NodeId, data: NodeData) -> GraphResult<()> {
+        self.validators.check_node(&data)?;
         // Convert NodeData to Value
         let value = serde_json::json!({
             "type": data.node_type,
@@ -63,6 +73,7 @@ impl GraphImplementation for ContextGraphAdapter {
     }
     
     fn add_edge(&mut self, edge_id: EdgeId, source: NodeId, target: NodeId, data: EdgeData) -> GraphResult<()> {
+        self.validators.check_edge(&data)?;
         let from_ctx = self.node_id_map.get(&source)
             .ok_or(GraphOperationError::NodeNotFound(source))?;
         let to_ctx = self.node_id_map.get(&target)
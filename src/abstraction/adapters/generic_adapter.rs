@@ -0,0 +1,167 @@
+//! Adapter for a plain in-memory graph with no backing domain model
+
+use crate::abstraction::{
+    DataValidators, GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
+    NodeData, EdgeData,
+};
+use cim_domain::{NodeId, EdgeId, GraphId};
+use std::collections::HashMap;
+
+/// Adapter that stores nodes and edges directly, for graphs that don't fit the
+/// context, concept, workflow or IPLD models
+#[derive(Clone)]
+pub struct GenericGraphAdapter {
+    graph_id: GraphId,
+    metadata: GraphMetadata,
+    nodes: HashMap<NodeId, NodeData>,
+    edges: HashMap<EdgeId, (EdgeData, NodeId, NodeId)>,
+    // Checks run before data is stored
+    validators: DataValidators,
+}
+
+impl GenericGraphAdapter {
+    /// Create a new adapter
+    pub fn new(graph_id: GraphId, name: String) -> Self {
+        Self {
+            graph_id,
+            metadata: GraphMetadata {
+                name,
+                description: "Generic graph adapter".to_string(),
+                properties: HashMap::new(),
+            },
+            nodes: HashMap::new(),
+            edges: HashMap::new(),
+            validators: DataValidators::default(),
+        }
+    }
+
+    /// Check node and edge data with `validators` before storing it
+    pub fn with_validators(mut self, validators: DataValidators) -> Self {
+        self.validators = validators;
+        self
+    }
+}
+
+impl GraphImplementation for GenericGraphAdapter {
+    fn graph_id(&self) -> GraphId {
+        self.graph_id
+    }
+
+    fn add_node(&mut self, node_id: NodeId, data: NodeData) -> GraphResult<()> {
+        self.validators.check_node(&data)?;
+        self.nodes.insert(node_id, data);
+        Ok(())
+    }
+
+    fn add_edge(
+        &mut self,
+        edge_id: EdgeId,
+        source: NodeId,
+        target: NodeId,
+        data: EdgeData,
+    ) -> GraphResult<()> {
+        self.validators.check_edge(&data)?;
+        for node_id in [source, target] {
+            if !self.nodes.contains_key(&node_id) {
+                return Err(GraphOperationError::NodeNotFound(node_id));
+            }
+        }
+        self.edges.insert(edge_id, (data, source, target));
+        Ok(())
+    }
+
+    fn get_node(&self, node_id: NodeId) -> GraphResult<NodeData> {
+        self.nodes
+            .get(&node_id)
+            .cloned()
+            .ok_or(GraphOperationError::NodeNotFound(node_id))
+    }
+
+    fn get_edge(&self, edge_id: EdgeId) -> GraphResult<(EdgeData, NodeId, NodeId)> {
+        self.edges
+            .get(&edge_id)
+            .cloned()
+            .ok_or(GraphOperationError::EdgeNotFound(edge_id))
+    }
+
+    fn list_nodes(&self) -> Vec<(NodeId, NodeData)> {
+        self.nodes
+            .iter()
+            .map(|(node_id, data)| (*node_id, data.clone()))
+            .collect()
+    }
+
+    fn list_edges(&self) -> Vec<(EdgeId, EdgeData, NodeId, NodeId)> {
+        self.edges
+            .iter()
+            .map(|(edge_id, (data, source, target))| (*edge_id, data.clone(), *source, *target))
+            .collect()
+    }
+
+    fn get_metadata(&self) -> GraphMetadata {
+        self.metadata.clone()
+    }
+
+    fn update_metadata(&mut self, metadata: GraphMetadata) -> GraphResult<()> {
+        self.metadata = metadata;
+        Ok(())
+    }
+
+    fn find_nodes_by_type(&self, node_type: &str) -> Vec<NodeId> {
+        self.nodes
+            .iter()
+            .filter(|(_, data)| data.node_type == node_type)
+            .map(|(node_id, _)| *node_id)
+            .collect()
+    }
+
+    fn find_edges_by_type(&self, edge_type: &str) -> Vec<EdgeId> {
+        self.edges
+            .iter()
+            .filter(|(_, (data, _, _))| data.edge_type == edge_type)
+            .map(|(edge_id, _)| *edge_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abstraction::{GraphType, Position3D};
+
+    #[test]
+    fn test_generic_graph_adds_and_lists() {
+        let mut graph = GraphType::new_generic(GraphId::new(), "scratch");
+        let a = NodeId::new();
+        let b = NodeId::new();
+        for node_id in [a, b] {
+            let data = NodeData {
+                node_type: "item".to_string(),
+                position: Position3D::default(),
+                metadata: HashMap::new(),
+            };
+            graph.add_node(node_id, data).unwrap();
+        }
+
+        let edge_id = EdgeId::new();
+        let edge = EdgeData {
+            edge_type: "link".to_string(),
+            metadata: HashMap::new(),
+        };
+        graph.add_edge(edge_id, a, b, edge.clone()).unwrap();
+
+        assert_eq!(graph.list_nodes().len(), 2);
+        assert_eq!(graph.find_nodes_by_type("item").len(), 2);
+        let edges = graph.list_edges();
+        assert_eq!(edges.len(), 1);
+        assert_eq!((edges[0].0, edges[0].2, edges[0].3), (edge_id, a, b));
+        assert_eq!(graph.find_edges_by_type("link"), vec![edge_id]);
+        assert_eq!(graph.get_metadata().name, "scratch");
+
+        let missing = NodeId::new();
+        assert!(matches!(
+            graph.add_edge(EdgeId::new(), a, missing, edge),
+            Err(GraphOperationError::NodeNotFound(id)) if id == missing
+        ));
+    }
+}
@@ -1,7 +1,7 @@
 //! Adapter for IpldGraph implementation
 
 use crate::abstraction::{
-    GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
+    DataValidators, GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
     NodeData, EdgeData, Position3D,
 };
 use cim_domain::{NodeId, EdgeId, GraphId};
@@ -33,6 +33,8 @@ pub struct IpldGraphAdapter {
     // Store original positions and node types
     node_positions: HashMap<NodeId, Position3D>,
     node_types: HashMap<NodeId, String>,
+    // Checks run before data is stored
+    validators: DataValidators,
 }
 
 impl IpldGraphAdapter {
@@ -48,8 +50,15 @@ impl IpldGraphAdapter {
             edge_metadata: HashMap::new(),
             node_positions: HashMap::new(),
             node_types: HashMap::new(),
+            validators: DataValidators::default(),
         }
     }
+
+    /// Check node and edge data with `validators` before storing it
+    pub fn with_validators(mut self, validators: DataValidators) -> Self {
+        self.validators = validators;
+        self
+    }
     
     /// Generate a CID from content
     fn generate_cid(content: &[u8]) -> Cid {
@@ -68,6 +77,34 @@ impl IpldGraphAdapter {
         let mh = multihash::Multihash::from_bytes(&multihash_bytes).unwrap();
         Cid::new_v1(0x55, mh) // 0x55 is raw codec
     }
+
+    /// Record a named IPLD link from `parent` to the node addressed by `cid`
+    ///
+    /// The link is stored as a `"link"` edge whose metadata carries the target `cid` and the
+    /// link's `field` name, so it is listed alongside the other edges.
+    pub fn add_link(&mut self, parent: NodeId, cid: String, field: String) -> GraphResult<EdgeId> {
+        let parent_cid = *self.node_to_cid.get(&parent)
+            .ok_or(GraphOperationError::NodeNotFound(parent))?;
+        let target_cid = cid.parse::<Cid>()
+            .map_err(|e| GraphOperationError::InvalidOperation(format!("Invalid CID {cid}: {e}")))?;
+        if !self.cid_to_node.contains_key(&target_cid) {
+            return Err(GraphOperationError::InvalidOperation(format!(
+                "No node with CID {cid} in this graph"
+            )));
+        }
+
+        self.dag.add_reference(parent_cid, target_cid)
+            .map_err(|e| GraphOperationError::EdgeCreationFailed(e.to_string()))?;
+
+        let edge_id = EdgeId::new();
+        let mut metadata = HashMap::new();
+        metadata.insert("cid".to_string(), serde_json::Value::String(cid));
+        metadata.insert("field".to_string(), serde_json::Value::String(field));
+        self.edge_metadata.insert(edge_id, metadata);
+        self.edge_map.insert(edge_id, (parent_cid, target_cid, "link".to_string()));
+
+        Ok(edge_id)
+    }
 }
 
 impl GraphImplementation for IpldGraphAdapter {
@@ -76,6 +113,7 @@ impl GraphImplementation for IpldGraphAdapter {
     }
     
     fn add_node(&mut self, node_id: NodeId, data: NodeData) -> GraphResult<()> {
+        self.validators.check_node(&data)?;
         // Store original metadata, position, and type
         self.node_metadata.insert(node_id, data.metadata.clone());
         self.node_positions.insert(node_id, data.position);
@@ -148,6 +186,7 @@ impl GraphImplementation for IpldGraphAdapter {
     }
     
     fn add_edge(&mut self, edge_id: EdgeId, source: NodeId, target: NodeId, data: EdgeData) -> GraphResult<()> {
+        self.validators.check_edge(&data)?;
         // Store original metadata
         self.edge_metadata.insert(edge_id, data.metadata.clone());
         
@@ -365,4 +404,46 @@ impl GraphImplementation for IpldGraphAdapter {
             })
             .collect()
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_link_surfaces_as_edge() {
+        let mut adapter = IpldGraphAdapter::new(GraphId::new());
+        let parent = NodeId::new();
+        let child = NodeId::new();
+        for node_id in [parent, child] {
+            let data = NodeData {
+                node_type: "document".to_string(),
+                position: Position3D::default(),
+                metadata: HashMap::new(),
+            };
+            adapter.add_node(node_id, data).unwrap();
+        }
+
+        let child_cid = adapter.get_node(child).unwrap().metadata["cid"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let edge_id = adapter
+            .add_link(parent, child_cid.clone(), "attachment".to_string())
+            .unwrap();
+
+        let edges = adapter.list_edges();
+        assert_eq!(edges.len(), 1);
+        let (id, data, source, target) = &edges[0];
+        assert_eq!(*id, edge_id);
+        assert_eq!(*source, parent);
+        assert_eq!(*target, child);
+        assert_eq!(data.edge_type, "link");
+        assert_eq!(data.metadata["cid"], serde_json::json!(child_cid));
+        assert_eq!(data.metadata["field"], serde_json::json!("attachment"));
+
+        assert!(adapter
+            .add_link(parent, "not-a-cid".to_string(), "broken".to_string())
+            .is_err());
+    }
+}
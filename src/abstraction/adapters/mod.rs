@@ -4,8 +4,10 @@ pub mod context_adapter;
 pub mod concept_adapter;
 pub mod workflow_adapter;
 pub mod ipld_adapter;
+pub mod generic_adapter;
 
 pub use context_adapter::ContextGraphAdapter;
 pub use concept_adapter::ConceptGraphAdapter;
 pub use workflow_adapter::WorkflowGraphAdapter;
-pub use ipld_adapter::IpldGraphAdapter; 
\ No newline at end of file
+pub use ipld_adapter::IpldGraphAdapter;
+pub use generic_adapter::GenericGraphAdapter; 
\ No newline at end of file
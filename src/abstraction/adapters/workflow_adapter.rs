@@ -1,7 +1,7 @@
 //! Adapter for WorkflowGraph implementation
 
 use crate::abstraction::{
-    GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
+    DataValidators, GraphImplementation, GraphMetadata, GraphOperationError, GraphResult,
     NodeData, EdgeData, Position3D,
 };
 use cim_domain::{NodeId, EdgeId, GraphId};
@@ -26,6 +26,8 @@ pub struct WorkflowGraphAdapter {
     node_positions: HashMap<NodeId, Position3D>,
     // Store original edge types
     edge_types: HashMap<EdgeId, String>,
+    // Checks run before data is stored
+    validators: DataValidators,
 }
 
 impl WorkflowGraphAdapter {
@@ -44,8 +46,15 @@ impl WorkflowGraphAdapter {
             edge_metadata: HashMap::new(),
             node_positions: HashMap::new(),
             edge_types: HashMap::new(),
+            validators: DataValidators::default(),
         }
     }
+
+    /// Check node and edge data with `validators` before storing it
+    pub fn with_validators(mut self, validators: DataValidators) -> Self {
+        self.validators = validators;
+        self
+    }
 }
 
 impl GraphImplementation for WorkflowGraphAdapter {
@@ -54,6 +63,7 @@ impl GraphImplementation for WorkflowGraphAdapter {
     }
     
     fn add_node(&mut self, node_id: NodeId, data: NodeData) -> GraphResult<()> {
+        self.validators.check_node(&data)?;
         // Store original metadata and position
         self.node_metadata.insert(node_id, data.metadata.clone());
         self.node_positions.insert(node_id, data.position);
@@ -114,6 +124,7 @@ impl GraphImplementation for WorkflowGraphAdapter {
     }
     
     fn add_edge(&mut self, edge_id: EdgeId, source: NodeId, target: NodeId, data: EdgeData) -> GraphResult<()> {
+        self.validators.check_edge(&data)?;
         // Store original metadata and edge type
         self.edge_metadata.insert(edge_id, data.metadata.clone());
         self.edge_types.insert(edge_id, data.edge_type.clone());
@@ -318,4 +329,39 @@ impl GraphImplementation for WorkflowGraphAdapter {
             Vec::new()
         }
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validator_rejects_unknown_step_type() {
+        let validators = DataValidators::new().with_node_validator(|data| {
+            match data.node_type.as_str() {
+                "manual" | "automated" | "decision" | "approval" => Ok(()),
+                other => Err(GraphOperationError::InvalidNodeType(other.to_string())),
+            }
+        });
+        let mut graph = WorkflowGraphAdapter::new(GraphId::new(), "Onboarding")
+            .with_validators(validators);
+
+        let step = |node_type: &str| NodeData {
+            node_type: node_type.to_string(),
+            position: Position3D::default(),
+            metadata: HashMap::new(),
+        };
+        graph.add_node(NodeId::new(), step("manual")).unwrap();
+
+        let rejected = NodeId::new();
+        assert!(matches!(
+            graph.add_node(rejected, step("teleport")),
+            Err(GraphOperationError::InvalidNodeType(node_type)) if node_type == "teleport"
+        ));
+        assert!(matches!(
+            graph.get_node(rejected),
+            Err(GraphOperationError::NodeNotFound(_))
+        ));
+        assert_eq!(graph.list_nodes().len(), 1);
+    }
+}
@@ -5,6 +5,7 @@
 
 use super::{GraphType, GraphImplementation, NodeData, EdgeData, GraphOperationError};
 use cim_domain::{GraphId, NodeId, EdgeId};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 
@@ -30,8 +31,46 @@ pub enum CompositionError {
 /// Result type for composition operations
 pub type CompositionResult<T> = Result<T, CompositionError>;
 
+/// Merge two metadata maps, `other` winning on scalar conflicts
+///
+/// Arrays are combined without duplicates and objects are merged key by key.
+pub(crate) fn merge_metadata(
+    mut base: HashMap<String, Value>,
+    other: HashMap<String, Value>,
+) -> HashMap<String, Value> {
+    for (key, value) in other {
+        if let Some(base_value) = base.get_mut(&key) {
+            // If both have the same key, try to merge intelligently
+            match (base_value, value) {
+                (Value::Array(base_arr), Value::Array(other_arr)) => {
+                    // Merge arrays by concatenating unique values
+                    for item in other_arr {
+                        if !base_arr.contains(&item) {
+                            base_arr.push(item);
+                        }
+                    }
+                }
+                (Value::Object(base_obj), Value::Object(other_obj)) => {
+                    // Recursively merge objects
+                    for (k, v) in other_obj {
+                        base_obj.insert(k, v);
+                    }
+                }
+                (base_val, other_val) => {
+                    // For other types, keep the other value
+                    *base_val = other_val;
+                }
+            }
+        } else {
+            // Key doesn't exist in base, add it
+            base.insert(key, value);
+        }
+    }
+    base
+}
+
 /// Strategy for resolving conflicts during composition
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum ConflictResolution {
     /// Keep the first occurrence, ignore subsequent ones
     KeepFirst,
@@ -144,38 +183,10 @@ impl DefaultGraphComposer {
     /// Merge two metadata maps
     fn merge_metadata(
         &self,
-        mut base: HashMap<String, Value>,
+        base: HashMap<String, Value>,
         other: HashMap<String, Value>,
     ) -> HashMap<String, Value> {
-        for (key, value) in other {
-            if let Some(base_value) = base.get_mut(&key) {
-                // If both have the same key, try to merge intelligently
-                match (base_value, value) {
-                    (Value::Array(base_arr), Value::Array(other_arr)) => {
-                        // Merge arrays by concatenating unique values
-                        for item in other_arr {
-                            if !base_arr.contains(&item) {
-                                base_arr.push(item);
-                            }
-                        }
-                    }
-                    (Value::Object(base_obj), Value::Object(other_obj)) => {
-                        // Recursively merge objects
-                        for (k, v) in other_obj {
-                            base_obj.insert(k, v);
-                        }
-                    }
-                    (base_val, other_val) => {
-                        // For other types, keep the other value
-                        *base_val = other_val;
-                    }
-                }
-            } else {
-                // Key doesn't exist in base, add it
-                base.insert(key, value);
-            }
-        }
-        base
+        merge_metadata(base, other)
     }
     
     /// Resolve node conflict based on strategy
@@ -290,6 +301,7 @@ impl GraphComposer for DefaultGraphComposer {
             "concept" => GraphType::new_concept(target_id, "Composed Graph"),
             "workflow" => GraphType::new_workflow(target_id, "Composed Graph"),
             "ipld" => GraphType::new_ipld(target_id),
+            "generic" => GraphType::new_generic(target_id, "Composed Graph"),
             _ => return Err(CompositionError::GraphOperationFailed(
                 GraphOperationError::InvalidOperation(format!("Unknown graph type: {target_type}"))
             )),
@@ -9,6 +9,7 @@ pub mod adapters;
 pub mod transformations;
 pub mod composition;
 pub mod integration;
+pub mod validation;
 
 #[cfg(test)]
 mod transformations_test;
@@ -22,6 +23,7 @@ pub use crate::value_objects::Position3D;
 // Re-export adapters for convenience
 pub use adapters::{
     ContextGraphAdapter, ConceptGraphAdapter, WorkflowGraphAdapter, IpldGraphAdapter,
+    GenericGraphAdapter,
 };
 
 // Re-export transformations for convenience
@@ -36,6 +38,9 @@ pub use composition::{
     CompositionError, CompositionResult, ConflictResolution,
 };
 
+// Re-export validation for convenience
+pub use validation::{DataValidators, EdgeValidator, NodeValidator};
+
 // Re-export integration for convenience
 pub use integration::{
     GraphAbstractionLayer, GraphAbstractionPlugin,
@@ -142,6 +147,7 @@ pub enum GraphType {
     Concept(ConceptGraphAdapter),
     Workflow(WorkflowGraphAdapter),
     Ipld(IpldGraphAdapter),
+    Generic(GenericGraphAdapter),
 }
 
 impl GraphType {
@@ -164,6 +170,24 @@ impl GraphType {
     pub fn new_ipld(graph_id: GraphId) -> Self {
         GraphType::Ipld(IpldGraphAdapter::new(graph_id))
     }
+    
+    /// Create a new generic in-memory graph
+    pub fn new_generic(graph_id: GraphId, name: &str) -> Self {
+        GraphType::Generic(GenericGraphAdapter::new(graph_id, name.to_string()))
+    }
+
+    /// Check node and edge data with `validators` before the adapter stores it
+    pub fn with_validators(self, validators: DataValidators) -> Self {
+        match self {
+            GraphType::Context(adapter) => GraphType::Context(adapter.with_validators(validators)),
+            GraphType::Concept(adapter) => GraphType::Concept(adapter.with_validators(validators)),
+            GraphType::Workflow(adapter) => {
+                GraphType::Workflow(adapter.with_validators(validators))
+            }
+            GraphType::Ipld(adapter) => GraphType::Ipld(adapter.with_validators(validators)),
+            GraphType::Generic(adapter) => GraphType::Generic(adapter.with_validators(validators)),
+        }
+    }
 }
 
 // Implement GraphImplementation for GraphType by delegating to the inner implementation
@@ -174,6 +198,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.graph_id(),
             GraphType::Workflow(adapter) => adapter.graph_id(),
             GraphType::Ipld(adapter) => adapter.graph_id(),
+            GraphType::Generic(adapter) => adapter.graph_id(),
         }
     }
     
@@ -183,6 +208,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.add_node(node_id, data),
             GraphType::Workflow(adapter) => adapter.add_node(node_id, data),
             GraphType::Ipld(adapter) => adapter.add_node(node_id, data),
+            GraphType::Generic(adapter) => adapter.add_node(node_id, data),
         }
     }
     
@@ -192,6 +218,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.add_edge(edge_id, source, target, data),
             GraphType::Workflow(adapter) => adapter.add_edge(edge_id, source, target, data),
             GraphType::Ipld(adapter) => adapter.add_edge(edge_id, source, target, data),
+            GraphType::Generic(adapter) => adapter.add_edge(edge_id, source, target, data),
         }
     }
     
@@ -201,6 +228,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.get_node(node_id),
             GraphType::Workflow(adapter) => adapter.get_node(node_id),
             GraphType::Ipld(adapter) => adapter.get_node(node_id),
+            GraphType::Generic(adapter) => adapter.get_node(node_id),
         }
     }
     
@@ -210,6 +238,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.get_edge(edge_id),
             GraphType::Workflow(adapter) => adapter.get_edge(edge_id),
             GraphType::Ipld(adapter) => adapter.get_edge(edge_id),
+            GraphType::Generic(adapter) => adapter.get_edge(edge_id),
         }
     }
     
@@ -219,6 +248,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.list_nodes(),
             GraphType::Workflow(adapter) => adapter.list_nodes(),
             GraphType::Ipld(adapter) => adapter.list_nodes(),
+            GraphType::Generic(adapter) => adapter.list_nodes(),
         }
     }
     
@@ -228,6 +258,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.list_edges(),
             GraphType::Workflow(adapter) => adapter.list_edges(),
             GraphType::Ipld(adapter) => adapter.list_edges(),
+            GraphType::Generic(adapter) => adapter.list_edges(),
         }
     }
     
@@ -237,6 +268,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.get_metadata(),
             GraphType::Workflow(adapter) => adapter.get_metadata(),
             GraphType::Ipld(adapter) => adapter.get_metadata(),
+            GraphType::Generic(adapter) => adapter.get_metadata(),
         }
     }
     
@@ -246,6 +278,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.update_metadata(metadata),
             GraphType::Workflow(adapter) => adapter.update_metadata(metadata),
             GraphType::Ipld(adapter) => adapter.update_metadata(metadata),
+            GraphType::Generic(adapter) => adapter.update_metadata(metadata),
         }
     }
     
@@ -255,6 +288,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.find_nodes_by_type(node_type),
             GraphType::Workflow(adapter) => adapter.find_nodes_by_type(node_type),
             GraphType::Ipld(adapter) => adapter.find_nodes_by_type(node_type),
+            GraphType::Generic(adapter) => adapter.find_nodes_by_type(node_type),
         }
     }
     
@@ -264,6 +298,7 @@ impl GraphImplementation for GraphType {
             GraphType::Concept(adapter) => adapter.find_edges_by_type(edge_type),
             GraphType::Workflow(adapter) => adapter.find_edges_by_type(edge_type),
             GraphType::Ipld(adapter) => adapter.find_edges_by_type(edge_type),
+            GraphType::Generic(adapter) => adapter.find_edges_by_type(edge_type),
         }
     }
 } 
\ No newline at end of file
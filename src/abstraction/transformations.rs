@@ -91,6 +91,7 @@ impl DefaultGraphTransformer {
             GraphType::Concept(_) => "concept",
             GraphType::Workflow(_) => "workflow",
             GraphType::Ipld(_) => "ipld",
+            GraphType::Generic(_) => "generic",
         }
     }
     
@@ -227,6 +228,7 @@ impl DefaultGraphTransformer {
             "concept" => GraphType::new_concept(graph_id, &source_metadata.name),
             "workflow" => GraphType::new_workflow(graph_id, &source_metadata.name),
             "ipld" => GraphType::new_ipld(graph_id),
+            "generic" => GraphType::new_generic(graph_id, &source_metadata.name),
             _ => return Err(TransformationError::UnsupportedTransformation(
                 "unknown".to_string(),
                 target_type.to_string(),
@@ -0,0 +1,66 @@
+//! Schema checks adapters run before storing node and edge data
+
+use super::{EdgeData, GraphResult, NodeData};
+use std::fmt;
+use std::sync::Arc;
+
+/// Check run on node data before an adapter stores it
+pub type NodeValidator = Arc<dyn Fn(&NodeData) -> GraphResult<()> + Send + Sync>;
+
+/// Check run on edge data before an adapter stores it
+pub type EdgeValidator = Arc<dyn Fn(&EdgeData) -> GraphResult<()> + Send + Sync>;
+
+/// Optional node and edge validators carried by an adapter
+///
+/// Adapters accept any data by default. A validator returning an error, typically
+/// `InvalidNodeType` or `InvalidEdgeType`, makes `add_node` or `add_edge` fail without
+/// changing the graph.
+#[derive(Clone, Default)]
+pub struct DataValidators {
+    node: Option<NodeValidator>,
+    edge: Option<EdgeValidator>,
+}
+
+impl DataValidators {
+    /// Validators that accept everything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check node data with `validator`
+    pub fn with_node_validator(
+        mut self,
+        validator: impl Fn(&NodeData) -> GraphResult<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.node = Some(Arc::new(validator));
+        self
+    }
+
+    /// Check edge data with `validator`
+    pub fn with_edge_validator(
+        mut self,
+        validator: impl Fn(&EdgeData) -> GraphResult<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.edge = Some(Arc::new(validator));
+        self
+    }
+
+    /// Run the node validator, if any
+    pub fn check_node(&self, data: &NodeData) -> GraphResult<()> {
+        self.node.as_ref().map_or(Ok(()), |validator| validator(data))
+    }
+
+    /// Run the edge validator, if any
+    pub fn check_edge(&self, data: &EdgeData) -> GraphResult<()> {
+        self.edge.as_ref().map_or(Ok(()), |validator| validator(data))
+    }
+}
+
+impl fmt::Debug for DataValidators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataValidators")
+            .field("node", &self.node.is_some())
+            .field("edge", &self.edge.is_some())
+            .finish()
+    }
+}
@@ -4,22 +4,38 @@
 //! through the unified GraphImplementation interface.
 
 use crate::abstraction::{EdgeData, GraphImplementation, GraphMetadata, GraphType, NodeData};
-use crate::commands::{GraphCommand, GraphCommandError};
-use crate::events::{EdgeAdded, EdgeRemoved, NodeAdded, NodeRemoved};
+use crate::commands::{
+    contracted_metadata, group_member_failures, split_failures, split_moves, structure_failures,
+    GraphCommand, GraphCommandError, SPLIT_EDGE_TYPE,
+};
+use crate::events::{
+    EdgeAdded, EdgeRemoved, EdgeUpdated, GraphUpdated, HyperEdgeAdded, NodeAdded, NodeMoved,
+    NodeRemoved, NodeUpdated,
+};
+use crate::groups;
+use crate::tags;
 use crate::value_objects::Position3D;
 use cim_domain::{AggregateRoot, EdgeId, GraphId, NodeId};
+use std::collections::HashMap;
+
+use super::HyperEdge;
 
 /// An abstract graph aggregate that can work with any graph implementation
 #[derive(Clone)]
 pub struct AbstractGraph {
     /// The underlying graph implementation
     pub graph: GraphType,
+    /// Hyperedges over the graph's nodes, which graph implementations cannot store
+    hyperedges: HashMap<EdgeId, HyperEdge>,
 }
 
 impl AbstractGraph {
     /// Create a new abstract graph
     pub fn new(graph: GraphType) -> Self {
-        Self { graph }
+        Self {
+            graph,
+            hyperedges: HashMap::new(),
+        }
     }
 
     /// Get the graph ID
@@ -32,6 +48,15 @@ impl AbstractGraph {
         self.graph.get_metadata().name
     }
 
+    /// Rename the graph
+    pub fn rename(&mut self, name: String) -> Result<(), GraphCommandError> {
+        let mut metadata = self.metadata();
+        metadata.name = name;
+        self.graph
+            .update_metadata(metadata)
+            .map_err(|e| GraphCommandError::InvalidCommand(e.to_string()))
+    }
+
     /// Add a node to the graph
     pub fn add_node(&mut self, node_id: NodeId, data: NodeData) -> Result<(), GraphCommandError> {
         self.graph
@@ -46,9 +71,61 @@ impl AbstractGraph {
         if !self.contains_node(node_id) {
             return Err(GraphCommandError::NodeNotFound(node_id));
         }
+
+        // The node leaves its hyperedges; those left with fewer than two nodes are dropped
+        for hyperedge in self.hyperedges.values_mut() {
+            hyperedge.node_ids.retain(|id| *id != node_id);
+        }
+        self.hyperedges.retain(|_, hyperedge| hyperedge.node_ids.len() >= 2);
+        Ok(())
+    }
+
+    /// Replace a node's metadata, keeping its type, position and hyperedges
+    ///
+    /// Graph implementations cannot change nodes in place, so the node is stored again
+    /// under the same id.
+    pub fn replace_node_metadata(
+        &mut self,
+        node_id: NodeId,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> Result<(), GraphCommandError> {
+        let mut data = self.get_node(node_id)?;
+        data.metadata = metadata;
+        self.add_node(node_id, data)
+    }
+
+    /// Add a hyperedge connecting a group of nodes
+    pub fn add_hyperedge(
+        &mut self,
+        edge_id: EdgeId,
+        node_ids: Vec<NodeId>,
+        edge_type: String,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> Result<(), GraphCommandError> {
+        if self.hyperedges.contains_key(&edge_id) {
+            return Err(GraphCommandError::BusinessRuleViolation(format!(
+                "Hyperedge {edge_id} already exists"
+            )));
+        }
+        self.check_hyperedge_members(&node_ids)?;
+
+        self.hyperedges.insert(
+            edge_id,
+            HyperEdge {
+                id: edge_id,
+                node_ids,
+                edge_type,
+                metadata,
+            },
+        );
         Ok(())
     }
 
+    /// Get all hyperedges
+    pub fn hyperedges(&self) -> &HashMap<EdgeId, HyperEdge> {
+        &self.hyperedges
+    }
+
     /// Add an edge to the graph
     pub fn add_edge(
         &mut self,
@@ -62,6 +139,20 @@ impl AbstractGraph {
             .map_err(|e| GraphCommandError::InvalidCommand(e.to_string()))
     }
 
+    /// Move an edge onto new endpoints, keeping its type and metadata
+    ///
+    /// Graph implementations cannot re-attach edges in place, so the edge is stored again
+    /// under the same id.
+    pub fn reconnect_edge(
+        &mut self,
+        edge_id: EdgeId,
+        source: NodeId,
+        target: NodeId,
+    ) -> Result<(), GraphCommandError> {
+        let (data, _, _) = self.get_edge(edge_id)?;
+        self.add_edge(edge_id, source, target, data)
+    }
+
     /// Remove an edge from the graph
     pub fn remove_edge(&mut self, edge_id: EdgeId) -> Result<(), GraphCommandError> {
         // Since GraphImplementation doesn't have remove_edge, we'll simulate it
@@ -79,6 +170,16 @@ impl AbstractGraph {
             .map_err(|_| GraphCommandError::NodeNotFound(node_id))
     }
 
+    /// Get an edge and its endpoints by ID
+    pub fn get_edge(
+        &self,
+        edge_id: EdgeId,
+    ) -> Result<(EdgeData, NodeId, NodeId), GraphCommandError> {
+        self.graph
+            .get_edge(edge_id)
+            .map_err(|_| GraphCommandError::EdgeNotFound(edge_id))
+    }
+
     /// List all nodes
     pub fn list_nodes(&self) -> Vec<(NodeId, NodeData)> {
         self.graph.list_nodes()
@@ -133,6 +234,7 @@ impl AbstractGraph {
                     position: crate::value_objects::Position3D::default(),
                     node_type,
                     metadata,
+                    created_at: chrono::Utc::now(),
                 };
 
                 Ok(vec![Box::new(event)])
@@ -147,7 +249,11 @@ impl AbstractGraph {
                     return Err(GraphCommandError::NodeNotFound(node_id));
                 }
 
-                let event = NodeRemoved { graph_id, node_id };
+                let event = NodeRemoved {
+                    graph_id,
+                    node_id,
+                    removed_at: chrono::Utc::now(),
+                };
 
                 Ok(vec![Box::new(event)])
             }
@@ -157,6 +263,7 @@ impl AbstractGraph {
                 target_id,
                 edge_type,
                 metadata,
+                relationship,
             } => {
                 if graph_id != self.id() {
                     return Err(GraphCommandError::GraphNotFound(graph_id));
@@ -178,11 +285,12 @@ impl AbstractGraph {
                     edge_id,
                     source: source_id,
                     target: target_id,
-                    relationship: crate::components::EdgeRelationship::Association {
-                        association_type: edge_type.clone(),
-                    },
+                    relationship: relationship.unwrap_or_else(|| {
+                        crate::components::EdgeRelationship::association(&edge_type)
+                    }),
                     edge_type,
                     metadata,
+                    created_at: chrono::Utc::now(),
                 };
 
                 Ok(vec![Box::new(event)])
@@ -197,10 +305,307 @@ impl AbstractGraph {
                     return Err(GraphCommandError::EdgeNotFound(edge_id));
                 }
 
-                let event = EdgeRemoved { graph_id, edge_id };
+                let event = EdgeRemoved {
+                    graph_id,
+                    edge_id,
+                    removed: None,
+                    removed_at: chrono::Utc::now(),
+                };
 
                 Ok(vec![Box::new(event)])
             }
+            GraphCommand::SetEdgeWeight {
+                graph_id,
+                edge_id,
+                weight,
+            } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                if weight.is_nan() || weight < 0.0 {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Edge weight must be non-negative, got {weight}"
+                    )));
+                }
+                if !self.contains_edge(edge_id) {
+                    return Err(GraphCommandError::EdgeNotFound(edge_id));
+                }
+
+                let event = EdgeUpdated {
+                    graph_id,
+                    edge_id,
+                    relationship: None,
+                    metadata: std::collections::HashMap::from([(
+                        "weight".to_string(),
+                        serde_json::json!(weight),
+                    )]),
+                    endpoints: None,
+                    updated_at: chrono::Utc::now(),
+                };
+
+                Ok(vec![Box::new(event)])
+            }
+            GraphCommand::SetNodePosition {
+                graph_id,
+                node_id,
+                position,
+            } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                if ![position.x, position.y, position.z].iter().all(|v| v.is_finite()) {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Node position must be finite, got {position:?}"
+                    )));
+                }
+                self.get_node(node_id)?;
+
+                let event = NodeMoved {
+                    graph_id,
+                    node_id,
+                    position,
+                    moved_at: chrono::Utc::now(),
+                };
+
+                Ok(vec![Box::new(event)])
+            }
+            GraphCommand::RepairGraph { graph_id } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                let mut events: Vec<Box<dyn std::any::Any>> = Vec::new();
+                for edge_id in self.orphaned_edges() {
+                    events.push(Box::new(EdgeRemoved {
+                        graph_id,
+                        edge_id,
+                        removed: None,
+                        removed_at: chrono::Utc::now(),
+                    }));
+                }
+
+                Ok(events)
+            }
+            GraphCommand::CreateGroup {
+                graph_id,
+                group_id,
+                member_nodes,
+            } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                GraphCommandError::from_failures(group_member_failures(&member_nodes))?;
+                for node_id in &member_nodes {
+                    self.get_node(*node_id)?;
+                }
+
+                let membership = groups::group_metadata(group_id);
+                let mut events: Vec<Box<dyn std::any::Any>> = Vec::new();
+                for node_id in member_nodes {
+                    events.push(Box::new(NodeUpdated {
+                        graph_id,
+                        node_id,
+                        position: None,
+                        metadata: membership.clone(),
+                        node_type: None,
+                        removed_keys: Vec::new(),
+                        updated_at: chrono::Utc::now(),
+                    }));
+                }
+
+                Ok(events)
+            }
+            GraphCommand::ReverseEdges { graph_id } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                let mut events: Vec<Box<dyn std::any::Any>> = Vec::new();
+                for (edge_id, _, source, target) in self.list_edges() {
+                    if source != target {
+                        events.push(Box::new(EdgeUpdated {
+                            graph_id,
+                            edge_id,
+                            relationship: None,
+                            metadata: std::collections::HashMap::new(),
+                            endpoints: Some((target, source)),
+                            updated_at: chrono::Utc::now(),
+                        }));
+                    }
+                }
+
+                Ok(events)
+            }
+            GraphCommand::SplitNode {
+                graph_id,
+                node_id,
+                incoming_to_first,
+                outgoing_to_second,
+            } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                let node = self.get_node(node_id)?;
+                GraphCommandError::from_failures(split_failures(
+                    node_id,
+                    &incoming_to_first,
+                    &outgoing_to_second,
+                    |edge_id| self.get_edge_endpoints(edge_id).ok(),
+                ))?;
+
+                let second_id = NodeId::new();
+                let link_edge_id = EdgeId::new();
+                let edges = self.list_edges().into_iter();
+                let moved = split_moves(
+                    node_id,
+                    second_id,
+                    &incoming_to_first,
+                    &outgoing_to_second,
+                    edges.map(|(edge_id, _, source, target)| (edge_id, source, target)),
+                );
+                let mut events: Vec<Box<dyn std::any::Any>> = vec![Box::new(NodeAdded {
+                    graph_id,
+                    node_id: second_id,
+                    position: node.position,
+                    node_type: node.node_type,
+                    metadata: node.metadata,
+                    created_at: chrono::Utc::now(),
+                })];
+
+                for (edge_id, source, target) in moved {
+                    events.push(Box::new(EdgeUpdated {
+                        graph_id,
+                        edge_id,
+                        relationship: None,
+                        metadata: std::collections::HashMap::new(),
+                        endpoints: Some((source, target)),
+                        updated_at: chrono::Utc::now(),
+                    }));
+                }
+
+                events.push(Box::new(EdgeAdded {
+                    graph_id,
+                    edge_id: link_edge_id,
+                    source: node_id,
+                    target: second_id,
+                    relationship: crate::components::EdgeRelationship::association(
+                        SPLIT_EDGE_TYPE,
+                    ),
+                    edge_type: SPLIT_EDGE_TYPE.to_string(),
+                    metadata: std::collections::HashMap::new(),
+                    created_at: chrono::Utc::now(),
+                }));
+
+                Ok(events)
+            }
+            GraphCommand::ContractEdge {
+                graph_id,
+                edge_id,
+                metadata_merge,
+            } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                let (kept, merged) = self.get_edge_endpoints(edge_id)?;
+                if kept == merged {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Edge {edge_id} is a self-loop and cannot be contracted"
+                    )));
+                }
+                let kept_metadata = self.get_node(kept)?.metadata;
+                let metadata = contracted_metadata(
+                    metadata_merge,
+                    &kept_metadata,
+                    &self.get_node(merged)?.metadata,
+                )?;
+
+                let mut events: Vec<Box<dyn std::any::Any>> = vec![Box::new(EdgeRemoved {
+                    graph_id,
+                    edge_id,
+                    removed: None,
+                    removed_at: chrono::Utc::now(),
+                })];
+                let rewire = |node_id: NodeId| if node_id == merged { kept } else { node_id };
+                for (id, _, source, target) in self.list_edges() {
+                    if id != edge_id && (source == merged || target == merged) {
+                        events.push(Box::new(EdgeUpdated {
+                            graph_id,
+                            edge_id: id,
+                            relationship: None,
+                            metadata: std::collections::HashMap::new(),
+                            endpoints: Some((rewire(source), rewire(target))),
+                            updated_at: chrono::Utc::now(),
+                        }));
+                    }
+                }
+                events.push(Box::new(NodeRemoved {
+                    graph_id,
+                    node_id: merged,
+                    removed_at: chrono::Utc::now(),
+                }));
+                if metadata != kept_metadata {
+                    events.push(Box::new(NodeUpdated {
+                        graph_id,
+                        node_id: kept,
+                        position: None,
+                        metadata,
+                        node_type: None,
+                        removed_keys: Vec::new(),
+                        updated_at: chrono::Utc::now(),
+                    }));
+                }
+
+                Ok(events)
+            }
+            GraphCommand::ImportStructure {
+                graph_id,
+                structure,
+            } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                let existing = self.list_nodes().into_iter().map(|(id, _)| id).collect();
+                GraphCommandError::from_failures(structure_failures(&structure, &existing))?;
+
+                let created_at = chrono::Utc::now();
+                let mut events: Vec<Box<dyn std::any::Any>> = Vec::new();
+                for node in structure.nodes {
+                    let position = node
+                        .position_3d
+                        .or_else(|| node.position_2d.map(Position3D::from))
+                        .unwrap_or_default();
+                    events.push(Box::new(NodeAdded {
+                        graph_id,
+                        node_id: node.node_id,
+                        position,
+                        node_type: node.node_type,
+                        metadata: node.metadata,
+                        created_at,
+                    }));
+                }
+                for edge in structure.edges {
+                    events.push(Box::new(EdgeAdded {
+                        graph_id,
+                        edge_id: edge.edge_id,
+                        source: edge.source_id,
+                        target: edge.target_id,
+                        relationship: crate::components::EdgeRelationship::association(
+                            &edge.edge_type,
+                        ),
+                        edge_type: edge.edge_type,
+                        metadata: edge.metadata,
+                        created_at,
+                    }));
+                }
+
+                Ok(events)
+            }
             GraphCommand::ChangeNodeMetadata {
                 graph_id,
                 node_id,
@@ -210,24 +615,134 @@ impl AbstractGraph {
                     return Err(GraphCommandError::GraphNotFound(graph_id));
                 }
 
-                // For now, we'll just emit events as if we removed and re-added the node
-                let node_removed = NodeRemoved { graph_id, node_id };
-
-                // Get current node data to preserve type
                 let current_node = self
                     .graph
                     .get_node(node_id)
                     .map_err(|_| GraphCommandError::NodeNotFound(node_id))?;
 
-                let node_added = NodeAdded {
+                let node_updated = NodeUpdated::replacing_metadata(
                     graph_id,
                     node_id,
-                    position: crate::value_objects::Position3D::default(),
-                    node_type: current_node.node_type,
-                    metadata: new_metadata,
+                    &current_node.metadata,
+                    new_metadata,
+                );
+
+                Ok(vec![Box::new(node_updated)])
+            }
+            GraphCommand::MergeNodeMetadata {
+                graph_id,
+                node_id,
+                metadata,
+            } => {
+                let mut new_metadata = self.get_node(node_id)?.metadata;
+                new_metadata.extend(metadata);
+                self.handle_command(GraphCommand::ChangeNodeMetadata {
+                    graph_id,
+                    node_id,
+                    new_metadata,
+                })
+            }
+            GraphCommand::TagNode {
+                graph_id,
+                node_id,
+                tag,
+            } => {
+                let current_node = self.get_node(node_id)?;
+                match tags::with_tag(&current_node.metadata, &tag)? {
+                    Some(new_metadata) => self.handle_command(GraphCommand::ChangeNodeMetadata {
+                        graph_id,
+                        node_id,
+                        new_metadata,
+                    }),
+                    None => Ok(Vec::new()),
+                }
+            }
+            GraphCommand::UntagNode {
+                graph_id,
+                node_id,
+                tag,
+            } => {
+                let current_node = self.get_node(node_id)?;
+                match tags::without_tag(&current_node.metadata, &tag)? {
+                    Some(new_metadata) => self.handle_command(GraphCommand::ChangeNodeMetadata {
+                        graph_id,
+                        node_id,
+                        new_metadata,
+                    }),
+                    None => Ok(Vec::new()),
+                }
+            }
+            GraphCommand::RelabelNodeType {
+                graph_id,
+                node_id,
+                new_node_type,
+            } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                if new_node_type.trim().is_empty() {
+                    return Err(GraphCommandError::InvalidCommand(
+                        "Node type cannot be empty".to_string(),
+                    ));
+                }
+                self.get_node(node_id)?;
+
+                let event = NodeUpdated {
+                    graph_id,
+                    node_id,
+                    position: None,
+                    metadata: std::collections::HashMap::new(),
+                    node_type: Some(new_node_type),
+                    removed_keys: Vec::new(),
+                    updated_at: chrono::Utc::now(),
+                };
+
+                Ok(vec![Box::new(event)])
+            }
+            GraphCommand::RenameGraph { graph_id, new_name } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                if new_name.trim().is_empty() {
+                    return Err(GraphCommandError::InvalidCommand(
+                        "Graph name cannot be empty".to_string(),
+                    ));
+                }
+
+                let event = GraphUpdated {
+                    graph_id,
+                    name: Some(new_name),
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                    updated_at: chrono::Utc::now(),
+                };
+
+                Ok(vec![Box::new(event)])
+            }
+            GraphCommand::AddHyperEdge {
+                graph_id,
+                node_ids,
+                edge_type,
+                metadata,
+            } => {
+                if graph_id != self.id() {
+                    return Err(GraphCommandError::GraphNotFound(graph_id));
+                }
+
+                self.check_hyperedge_members(&node_ids)?;
+
+                let event = HyperEdgeAdded {
+                    graph_id,
+                    edge_id: EdgeId::new(),
+                    node_ids,
+                    edge_type,
+                    metadata,
+                    created_at: chrono::Utc::now(),
                 };
 
-                Ok(vec![Box::new(node_removed), Box::new(node_added)])
+                Ok(vec![Box::new(event)])
             }
         }
     }
@@ -252,11 +767,42 @@ impl AbstractGraph {
         self.graph.get_node(node_id).is_ok()
     }
 
+    /// Check that a group of nodes can be joined by a hyperedge
+    pub fn check_hyperedge_members(&self, node_ids: &[NodeId]) -> Result<(), GraphCommandError> {
+        let distinct: std::collections::HashSet<_> = node_ids.iter().collect();
+        if distinct.len() != node_ids.len() {
+            return Err(GraphCommandError::InvalidCommand(
+                "Hyperedge nodes must be distinct".to_string(),
+            ));
+        }
+        if node_ids.len() < 2 {
+            return Err(GraphCommandError::InvalidCommand(
+                "Hyperedge must connect at least two nodes".to_string(),
+            ));
+        }
+        if let Some(missing) = node_ids.iter().find(|id| !self.contains_node(**id)) {
+            return Err(GraphCommandError::NodeNotFound(*missing));
+        }
+        Ok(())
+    }
+
     /// Check if graph contains an edge
     pub fn contains_edge(&self, edge_id: EdgeId) -> bool {
         self.graph.get_edge(edge_id).is_ok()
     }
 
+    /// Edges whose source or target node is not in the graph
+    pub fn orphaned_edges(&self) -> Vec<EdgeId> {
+        self.graph
+            .list_edges()
+            .into_iter()
+            .filter(|(_, _, source, target)| {
+                !self.contains_node(*source) || !self.contains_node(*target)
+            })
+            .map(|(edge_id, ..)| edge_id)
+            .collect()
+    }
+
     /// Get edge endpoints
     pub fn get_edge_endpoints(
         &self,
@@ -376,4 +922,32 @@ mod tests {
         let result = graph.remove_edge(edge_id);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_import_structure_leaves_graph_unchanged() {
+        let graph_id = GraphId::new();
+        let mut graph = AbstractGraph::new(GraphType::new_generic(graph_id, "Import"));
+
+        let [a, b] = [NodeId::new(), NodeId::new()];
+        let structure = crate::queries::GraphStructure {
+            nodes: vec![
+                crate::queries::NodeInfo::new(a, graph_id, "step"),
+                crate::queries::NodeInfo::new(b, graph_id, "step"),
+            ],
+            edges: vec![crate::queries::EdgeInfo::new(EdgeId::new(), graph_id, a, b, "next")],
+            adjacency_list: HashMap::new(),
+        };
+
+        let events = graph
+            .handle_command(GraphCommand::ImportStructure {
+                graph_id,
+                structure,
+            })
+            .unwrap();
+        assert_eq!(events.len(), 3);
+
+        // Applying the events is left to the command handler
+        assert_eq!(graph.node_count(), 0);
+        assert_eq!(graph.edge_count(), 0);
+    }
 }
@@ -8,6 +8,9 @@ use serde::{Deserialize, Serialize};
 use cim_domain::AggregateRoot;
 use crate::{GraphId, NodeId, EdgeId};
 use crate::commands::GraphCommandError;
+use crate::domain_events::GraphDomainEvent;
+use crate::queries::metrics::find;
+use crate::value_objects::GraphMode;
 
 /// Business node in a graph
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +68,19 @@ impl GraphEdge {
     }
 }
 
+/// Business hyperedge connecting a group of nodes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperEdge {
+    /// Unique identifier for the hyperedge
+    pub id: EdgeId,
+    /// Nodes connected by the hyperedge
+    pub node_ids: Vec<NodeId>,
+    /// Type/category of the hyperedge (e.g., "join", "fork")
+    pub edge_type: String,
+    /// Additional metadata about the hyperedge
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
 /// Business Graph aggregate for runtime operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Graph {
@@ -78,8 +94,14 @@ pub struct Graph {
     nodes: HashMap<NodeId, GraphNode>,
     /// All edges in the graph
     edges: HashMap<EdgeId, GraphEdge>,
+    /// All hyperedges in the graph
+    #[serde(default)]
+    hyperedges: HashMap<EdgeId, HyperEdge>,
     /// Additional metadata about the graph
     metadata: HashMap<String, serde_json::Value>,
+    /// Whether edges are directed, fixed at creation
+    #[serde(default)]
+    mode: GraphMode,
     /// When the graph was created
     created_at: chrono::DateTime<chrono::Utc>,
     /// When the graph was last modified
@@ -98,13 +120,135 @@ impl Graph {
             description,
             nodes: HashMap::new(),
             edges: HashMap::new(),
+            hyperedges: HashMap::new(),
             metadata: HashMap::new(),
+            mode: GraphMode::default(),
             created_at: now,
             last_modified: now,
             version: 1,
         }
     }
 
+    /// Create a new graph with metadata, taking its [`GraphMode`] from the metadata
+    pub fn with_metadata(
+        id: GraphId,
+        name: String,
+        description: String,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        let mut graph = Self::new(id, name, description);
+        graph.mode = GraphMode::from_metadata(&metadata);
+        graph.metadata = metadata;
+        graph
+    }
+
+    /// Rebuild a graph by folding its event history
+    ///
+    /// Events belonging to other graphs are ignored. Events are treated as facts,
+    /// so they are applied without re-running command validation.
+    pub fn from_events(graph_id: GraphId, events: Vec<GraphDomainEvent>) -> Self {
+        let mut graph = Self::new(graph_id, String::new(), String::new());
+        for event in &events {
+            graph.apply_event(event);
+        }
+        graph
+    }
+
+    /// Apply a single domain event to the graph state
+    pub fn apply_event(&mut self, event: &GraphDomainEvent) {
+        match event {
+            GraphDomainEvent::GraphCreated(e) if e.graph_id == self.id => {
+                self.name = e.name.clone();
+                self.description = e.description.clone();
+                self.metadata = e.metadata.clone();
+                self.mode = GraphMode::from_metadata(&e.metadata);
+                self.created_at = e.created_at;
+                self.last_modified = e.created_at;
+            }
+            GraphDomainEvent::GraphUpdated(e) if e.graph_id == self.id => {
+                if let Some(name) = &e.name {
+                    self.name = name.clone();
+                }
+                if let Some(description) = &e.description {
+                    self.description = description.clone();
+                }
+                self.metadata
+                    .extend(e.metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+                self.touch(event.occurred_at());
+            }
+            GraphDomainEvent::NodeAdded(e) if e.graph_id == self.id => {
+                let node = GraphNode::new(e.node_id, e.node_type.clone(), e.metadata.clone());
+                self.nodes.insert(e.node_id, node);
+                self.touch(event.occurred_at());
+            }
+            GraphDomainEvent::NodeRemoved(e) if e.graph_id == self.id => {
+                // Incident edges were removed by their own events before this one
+                self.detach_from_hyperedges(e.node_id);
+                self.nodes.remove(&e.node_id);
+                self.touch(event.occurred_at());
+            }
+            GraphDomainEvent::NodeUpdated(e) if e.graph_id == self.id => {
+                if let Some(node) = self.nodes.get_mut(&e.node_id) {
+                    if let Some(node_type) = &e.node_type {
+                        node.node_type = node_type.clone();
+                    }
+                    e.apply_metadata(&mut node.metadata);
+                    self.touch(event.occurred_at());
+                }
+            }
+            GraphDomainEvent::EdgeAdded(e) if e.graph_id == self.id => {
+                let edge = GraphEdge::new(
+                    e.edge_id,
+                    e.source,
+                    e.target,
+                    e.edge_type.clone(),
+                    e.metadata.clone(),
+                );
+                self.edges.insert(e.edge_id, edge);
+                self.touch(event.occurred_at());
+            }
+            GraphDomainEvent::EdgeRemoved(e) if e.graph_id == self.id => {
+                self.edges.remove(&e.edge_id);
+                self.touch(event.occurred_at());
+            }
+            GraphDomainEvent::EdgeUpdated(e) if e.graph_id == self.id => {
+                if let Some(edge) = self.edges.get_mut(&e.edge_id) {
+                    edge.metadata
+                        .extend(e.metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+                    if let Some((source_id, target_id)) = e.endpoints {
+                        edge.source_id = source_id;
+                        edge.target_id = target_id;
+                    }
+                    self.touch(event.occurred_at());
+                }
+            }
+            GraphDomainEvent::HyperEdgeAdded(e) if e.graph_id == self.id => {
+                let hyperedge = HyperEdge {
+                    id: e.edge_id,
+                    node_ids: e.node_ids.clone(),
+                    edge_type: e.edge_type.clone(),
+                    metadata: e.metadata.clone(),
+                };
+                self.hyperedges.insert(e.edge_id, hyperedge);
+                self.touch(event.occurred_at());
+            }
+            _ => {
+                // Events for other graphs do not affect this aggregate
+            }
+        }
+    }
+
+    /// Record a state change made at `at`
+    fn touch(&mut self, at: chrono::DateTime<chrono::Utc>) {
+        self.last_modified = at;
+        self.version += 1;
+    }
+
+    /// Set when the graph last changed, to the time of the command that changed it
+    pub fn set_last_modified(&mut self, at: chrono::DateTime<chrono::Utc>) {
+        self.last_modified = at;
+    }
+
     /// Get the graph's name
     pub fn name(&self) -> &str {
         &self.name
@@ -125,11 +269,21 @@ impl Graph {
         &self.edges
     }
 
+    /// Get all hyperedges
+    pub fn hyperedges(&self) -> &HashMap<EdgeId, HyperEdge> {
+        &self.hyperedges
+    }
+
     /// Get graph metadata
     pub fn metadata(&self) -> &HashMap<String, serde_json::Value> {
         &self.metadata
     }
 
+    /// Get whether the graph's edges are directed
+    pub fn mode(&self) -> GraphMode {
+        self.mode
+    }
+
     /// Get creation timestamp
     pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
         self.created_at
@@ -145,6 +299,21 @@ impl Graph {
         self.version
     }
 
+    /// Give the graph a new name, keeping its description and metadata
+    pub fn rename(&mut self, name: String) -> Result<(), GraphCommandError> {
+        if name.trim().is_empty() {
+            return Err(GraphCommandError::InvalidCommand(
+                "Graph name cannot be empty".to_string(),
+            ));
+        }
+
+        self.name = name;
+        self.last_modified = chrono::Utc::now();
+        self.version += 1;
+
+        Ok(())
+    }
+
     /// Add a node to the graph
     pub fn add_node(
         &mut self,
@@ -186,6 +355,9 @@ impl Graph {
             self.edges.remove(&edge_id);
         }
 
+        // Detach the node from any hyperedges
+        self.detach_from_hyperedges(node_id);
+
         // Remove the node
         self.nodes.remove(&node_id);
         self.last_modified = chrono::Utc::now();
@@ -218,6 +390,24 @@ impl Graph {
         Ok(())
     }
 
+    /// Change a node's type in place, keeping its metadata and edges
+    pub fn relabel_node(
+        &mut self,
+        node_id: NodeId,
+        new_node_type: String,
+    ) -> Result<(), GraphCommandError> {
+        let node = self
+            .nodes
+            .get_mut(&node_id)
+            .ok_or(GraphCommandError::NodeNotFound(node_id))?;
+
+        node.node_type = new_node_type;
+        self.last_modified = chrono::Utc::now();
+        self.version += 1;
+
+        Ok(())
+    }
+
     /// Add an edge to the graph
     pub fn add_edge(
         &mut self,
@@ -251,6 +441,24 @@ impl Graph {
         Ok(())
     }
 
+    /// Set an edge's weight, stored under `weight` in its metadata
+    pub fn set_edge_weight(
+        &mut self,
+        edge_id: EdgeId,
+        weight: f64,
+    ) -> Result<(), GraphCommandError> {
+        let edge = self
+            .edges
+            .get_mut(&edge_id)
+            .ok_or(GraphCommandError::EdgeNotFound(edge_id))?;
+
+        edge.metadata.insert("weight".to_string(), serde_json::json!(weight));
+        self.last_modified = chrono::Utc::now();
+        self.version += 1;
+
+        Ok(())
+    }
+
     /// Remove an edge from the graph
     pub fn remove_edge(&mut self, edge_id: EdgeId) -> Result<(), GraphCommandError> {
         // Check if edge exists
@@ -266,6 +474,168 @@ impl Graph {
         Ok(())
     }
 
+    /// Swap the source and target of every edge except self-loops
+    ///
+    /// Returns the reversed edges.
+    pub fn reverse_edges(&mut self) -> Vec<&GraphEdge> {
+        let mut reversed = Vec::new();
+        for edge in self.edges.values_mut() {
+            if edge.source_id != edge.target_id {
+                std::mem::swap(&mut edge.source_id, &mut edge.target_id);
+                reversed.push(&*edge);
+            }
+        }
+        if !reversed.is_empty() {
+            self.last_modified = chrono::Utc::now();
+            self.version += 1;
+        }
+        reversed
+    }
+
+    /// Move an edge to new endpoints, keeping its id, type and metadata
+    pub fn reconnect_edge(
+        &mut self,
+        edge_id: EdgeId,
+        source_id: NodeId,
+        target_id: NodeId,
+    ) -> Result<(), GraphCommandError> {
+        for node_id in [source_id, target_id] {
+            if !self.nodes.contains_key(&node_id) {
+                return Err(GraphCommandError::NodeNotFound(node_id));
+            }
+        }
+        let edge = self
+            .edges
+            .get_mut(&edge_id)
+            .ok_or(GraphCommandError::EdgeNotFound(edge_id))?;
+
+        edge.source_id = source_id;
+        edge.target_id = target_id;
+        self.last_modified = chrono::Utc::now();
+        self.version += 1;
+
+        Ok(())
+    }
+
+    /// Contract an edge, merging its target node into its source node
+    ///
+    /// The edge is removed, every other edge at the target is moved to the source, the target
+    /// is removed and the source takes `metadata`. Returns the moved edges.
+    pub fn contract_edge(
+        &mut self,
+        edge_id: EdgeId,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> Result<Vec<EdgeId>, GraphCommandError> {
+        let edge = self
+            .edges
+            .get(&edge_id)
+            .ok_or(GraphCommandError::EdgeNotFound(edge_id))?;
+        let (kept, merged) = (edge.source_id, edge.target_id);
+        if kept == merged {
+            return Err(GraphCommandError::BusinessRuleViolation(format!(
+                "Edge {edge_id} is a self-loop and cannot be contracted"
+            )));
+        }
+
+        self.edges.remove(&edge_id);
+        let mut moved = Vec::new();
+        for edge in self.edges.values_mut() {
+            if edge.source_id != merged && edge.target_id != merged {
+                continue;
+            }
+            if edge.source_id == merged {
+                edge.source_id = kept;
+            }
+            if edge.target_id == merged {
+                edge.target_id = kept;
+            }
+            moved.push(edge.id);
+        }
+        self.nodes.remove(&merged);
+        self.detach_from_hyperedges(merged);
+        if let Some(node) = self.nodes.get_mut(&kept) {
+            node.metadata = metadata;
+        }
+        self.last_modified = chrono::Utc::now();
+        self.version += 1;
+
+        Ok(moved)
+    }
+
+    /// Edges whose source or target node is not in the graph
+    pub fn orphaned_edges(&self) -> Vec<EdgeId> {
+        self.edges
+            .values()
+            .filter(|edge| {
+                !self.nodes.contains_key(&edge.source_id)
+                    || !self.nodes.contains_key(&edge.target_id)
+            })
+            .map(|edge| edge.id)
+            .collect()
+    }
+
+    /// Add a hyperedge connecting a group of nodes
+    pub fn add_hyperedge(
+        &mut self,
+        edge_id: EdgeId,
+        node_ids: Vec<NodeId>,
+        edge_type: String,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> Result<(), GraphCommandError> {
+        // Check if an edge with this ID already exists
+        if self.edges.contains_key(&edge_id) || self.hyperedges.contains_key(&edge_id) {
+            return Err(GraphCommandError::BusinessRuleViolation(
+                format!("Edge {edge_id} already exists")
+            ));
+        }
+
+        // A hyperedge must connect at least two distinct nodes
+        let mut distinct = std::collections::HashSet::new();
+        if !node_ids.iter().all(|id| distinct.insert(*id)) {
+            return Err(GraphCommandError::InvalidCommand(
+                "Hyperedge nodes must be distinct".to_string()
+            ));
+        }
+        if node_ids.len() < 2 {
+            return Err(GraphCommandError::InvalidCommand(
+                "Hyperedge must connect at least two nodes".to_string()
+            ));
+        }
+
+        // Check that all member nodes exist
+        if let Some(missing) = node_ids.iter().find(|id| !self.nodes.contains_key(id)) {
+            return Err(GraphCommandError::NodeNotFound(*missing));
+        }
+
+        let hyperedge = HyperEdge {
+            id: edge_id,
+            node_ids,
+            edge_type,
+            metadata,
+        };
+        self.hyperedges.insert(edge_id, hyperedge);
+        self.last_modified = chrono::Utc::now();
+        self.version += 1;
+
+        Ok(())
+    }
+
+    /// Get hyperedges that include a node
+    pub fn get_hyperedges_for_node(&self, node_id: NodeId) -> Vec<&HyperEdge> {
+        self.hyperedges
+            .values()
+            .filter(|hyperedge| hyperedge.node_ids.contains(&node_id))
+            .collect()
+    }
+
+    /// Remove a node from hyperedge memberships, dropping hyperedges left with fewer than two nodes
+    fn detach_from_hyperedges(&mut self, node_id: NodeId) {
+        for hyperedge in self.hyperedges.values_mut() {
+            hyperedge.node_ids.retain(|id| *id != node_id);
+        }
+        self.hyperedges.retain(|_, hyperedge| hyperedge.node_ids.len() >= 2);
+    }
+
     /// Get incoming edges for a node
     pub fn get_incoming_edges(&self, node_id: NodeId) -> Vec<&GraphEdge> {
         self.edges
@@ -283,7 +653,14 @@ impl Graph {
     }
 
     /// Check if the graph contains cycles (simple DFS-based detection)
+    ///
+    /// In [`GraphMode::Undirected`] an edge is traversable both ways, so a single edge
+    /// A–B is not a cycle; an edge only closes one when its endpoints are already connected.
     pub fn has_cycles(&self) -> bool {
+        if self.mode == GraphMode::Undirected {
+            return self.has_undirected_cycle();
+        }
+
         let mut visited = std::collections::HashSet::new();
         let mut rec_stack = std::collections::HashSet::new();
 
@@ -297,6 +674,28 @@ impl Graph {
         false
     }
 
+    /// Union-find over the edges: an edge joining nodes already connected closes a cycle
+    fn has_undirected_cycle(&self) -> bool {
+        let mut index: HashMap<NodeId, usize> = HashMap::new();
+        for edge in self.edges.values() {
+            for node_id in [edge.source_id, edge.target_id] {
+                let next = index.len();
+                index.entry(node_id).or_insert(next);
+            }
+        }
+
+        let mut parent: Vec<usize> = (0..index.len()).collect();
+        for edge in self.edges.values() {
+            let source = find(&mut parent, index[&edge.source_id]);
+            let target = find(&mut parent, index[&edge.target_id]);
+            if source == target {
+                return true;
+            }
+            parent[source] = target;
+        }
+        false
+    }
+
     /// Utility function for cycle detection
     fn has_cycle_util(
         &self,
@@ -493,6 +892,37 @@ mod tests {
         assert!(graph.has_cycles());
     }
 
+    #[test]
+    fn test_undirected_cycle_detection() {
+        let mut graph = Graph::with_metadata(
+            GraphId::new(),
+            "Undirected".to_string(),
+            String::new(),
+            HashMap::from([(
+                crate::value_objects::GRAPH_MODE_KEY.to_string(),
+                serde_json::json!("undirected"),
+            )]),
+        );
+        assert_eq!(graph.mode(), GraphMode::Undirected);
+
+        let node1 = NodeId::new();
+        let node2 = NodeId::new();
+        let node3 = NodeId::new();
+        for node_id in [node1, node2, node3] {
+            graph.add_node(node_id, "task".to_string(), HashMap::new()).unwrap();
+        }
+
+        // A single edge A–B can be walked back, but that is not a cycle
+        graph.add_edge(EdgeId::new(), node1, node2, "link".to_string(), HashMap::new()).unwrap();
+        assert!(!graph.has_cycles());
+
+        // Edges pointing into node3 from both sides form no directed cycle, but do close a
+        // triangle once direction is ignored
+        graph.add_edge(EdgeId::new(), node2, node3, "link".to_string(), HashMap::new()).unwrap();
+        graph.add_edge(EdgeId::new(), node1, node3, "link".to_string(), HashMap::new()).unwrap();
+        assert!(graph.has_cycles());
+    }
+
     #[test]
     fn test_source_and_sink_nodes() {
         let mut graph = Graph::new(
@@ -521,4 +951,57 @@ mod tests {
         assert_eq!(sinks.len(), 1);
         assert!(sinks.contains(&end_node));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_from_events() {
+        use crate::events::{EdgeAdded, GraphCreated, NodeAdded};
+
+        let graph_id = GraphId::new();
+        let node1 = NodeId::new();
+        let node2 = NodeId::new();
+        let edge_id = EdgeId::new();
+
+        let node_added = |node_id| {
+            GraphDomainEvent::NodeAdded(NodeAdded {
+                graph_id,
+                node_id,
+                position: crate::value_objects::Position3D::default(),
+                node_type: "task".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            })
+        };
+
+        let events = vec![
+            GraphDomainEvent::GraphCreated(GraphCreated {
+                graph_id,
+                name: "Replayed Graph".to_string(),
+                description: "Rebuilt from events".to_string(),
+                graph_type: None,
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            }),
+            node_added(node1),
+            node_added(node2),
+            GraphDomainEvent::EdgeAdded(EdgeAdded {
+                graph_id,
+                edge_id,
+                source: node1,
+                target: node2,
+                relationship: crate::components::EdgeRelationship::Association {
+                    association_type: "sequence".to_string(),
+                },
+                edge_type: "sequence".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            }),
+        ];
+
+        let graph = Graph::from_events(graph_id, events);
+
+        assert_eq!(graph.name(), "Replayed Graph");
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.edges()[&edge_id].source_id, node1);
+    }
+}
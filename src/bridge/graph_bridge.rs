@@ -2,11 +2,12 @@
 //!
 //! Provides the integration between graph domain handlers and ECS systems.
 
+use parking_lot::Mutex;
 use std::sync::Arc;
 use tokio::runtime::Handle;
 
 use crate::{
-    handlers::{GraphCommandHandler, GraphCommandHandlerImpl, InMemoryGraphRepository},
+    handlers::{CausedCommand, CausedEvent, GraphCommandHandlerImpl, InMemoryGraphRepository},
     commands::GraphCommand,
     bridge::{AsyncSyncBridge, BridgeCommand, BridgeEvent},
 };
@@ -16,6 +17,8 @@ pub struct GraphBridge {
     bridge: Arc<AsyncSyncBridge>,
     _handler: Arc<GraphCommandHandlerImpl>,
     _runtime_handle: Handle,
+    /// Events with their causation, kept until drained
+    audit_trail: Arc<Mutex<Vec<CausedEvent>>>,
 }
 
 impl GraphBridge {
@@ -28,29 +31,34 @@ impl GraphBridge {
         // Start command processor
         let bridge_clone = bridge.clone();
         let handler_clone = handler.clone();
+        let audit_trail = Arc::new(Mutex::new(Vec::new()));
+        let audit_trail_clone = audit_trail.clone();
         
         runtime_handle.spawn(async move {
             loop {
                 if let Some(command) = bridge_clone.receive_command() {
-                    match command {
-                        BridgeCommand::GraphCommand(graph_cmd) => {
-                            // Process command
-                            match handler_clone.handle_graph_command(graph_cmd).await {
-                                Ok(events) => {
-                                    // Forward events to ECS
-                                    for event in events {
-                                        let bridge_event = BridgeEvent::from(event);
-                                        if bridge_clone.send_event(bridge_event).is_err() {
-                                            break;
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("Error handling graph command: {e:?}");
+                    // Plain commands start a new causation chain
+                    let caused = match command {
+                        BridgeCommand::GraphCommand(graph_cmd) => CausedCommand::new(graph_cmd),
+                        BridgeCommand::CausedGraphCommand(caused) => caused,
+                        BridgeCommand::Shutdown => break,
+                    };
+
+                    // Process command
+                    match handler_clone.handle_caused_command(caused).await {
+                        Ok(events) => {
+                            // Forward events to ECS
+                            for caused_event in events {
+                                let bridge_event = BridgeEvent::from(caused_event.event.clone());
+                                audit_trail_clone.lock().push(caused_event);
+                                if bridge_clone.send_event(bridge_event).is_err() {
+                                    break;
                                 }
                             }
                         }
-                        BridgeCommand::Shutdown => break,
+                        Err(e) => {
+                            eprintln!("Error handling graph command: {e:?}");
+                        }
                     }
                 } else {
                     // No command available, yield
@@ -63,6 +71,7 @@ impl GraphBridge {
             bridge,
             _handler: handler,
             _runtime_handle: runtime_handle,
+            audit_trail,
         }
     }
     
@@ -76,11 +85,26 @@ impl GraphBridge {
         self.bridge.send_command(BridgeCommand::GraphCommand(command))
     }
     
+    /// Send a command triggered by an earlier event, continuing its causation chain
+    pub fn send_caused_command(
+        &self,
+        command: GraphCommand,
+        cause: &CausedEvent,
+    ) -> Result<(), crate::bridge::SendError> {
+        self.bridge
+            .send_command(BridgeCommand::CausedGraphCommand(CausedCommand::caused_by(command, cause)))
+    }
+    
     /// Receive events for ECS processing
     pub fn receive_events(&self) -> Vec<BridgeEvent> {
         self.bridge.receive_events()
     }
     
+    /// Take the events produced so far together with their correlation and causation ids
+    pub fn drain_audit_trail(&self) -> Vec<CausedEvent> {
+        std::mem::take(&mut *self.audit_trail.lock())
+    }
+    
     /// Shutdown the bridge
     pub fn shutdown(&self) -> Result<(), crate::bridge::SendError> {
         self.bridge.send_command(BridgeCommand::Shutdown)
@@ -120,4 +144,43 @@ mod tests {
             _ => panic!("Expected GraphCreated event"),
         }
     }
-} 
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_bridge_chains_causation() {
+        let runtime = tokio::runtime::Handle::current();
+        let bridge = GraphBridge::new(runtime);
+        
+        bridge
+            .send_command(GraphCommand::CreateGraph {
+                name: "Chained".to_string(),
+                description: "Test".to_string(),
+                metadata: HashMap::new(),
+            })
+            .unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        
+        let created = bridge.drain_audit_trail();
+        assert_eq!(created.len(), 1);
+        let graph_id = match &created[0].event {
+            crate::GraphDomainEvent::GraphCreated(e) => e.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+        
+        bridge
+            .send_caused_command(
+                GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "task".to_string(),
+                    metadata: HashMap::new(),
+                },
+                &created[0],
+            )
+            .unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        
+        let added = bridge.drain_audit_trail();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].correlation_id, created[0].correlation_id);
+        assert_ne!(added[0].causation_id, created[0].causation_id);
+    }
+}
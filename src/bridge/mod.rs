@@ -12,6 +12,7 @@ use crate::{
     commands::GraphCommand,
     events::*,
     domain_events::GraphDomainEvent,
+    handlers::CausedCommand,
 };
 
 pub mod graph_bridge;
@@ -25,6 +26,8 @@ const CHANNEL_CAPACITY: usize = 1000;
 #[derive(Debug, Clone)]
 pub enum BridgeCommand {
     GraphCommand(GraphCommand),
+    /// A command continuing an existing causation chain
+    CausedGraphCommand(CausedCommand),
     Shutdown,
 }
 
@@ -40,16 +43,31 @@ pub enum BridgeEvent {
     EdgeAdded(EdgeAdded),
     EdgeUpdated(EdgeUpdated),
     EdgeRemoved(EdgeRemoved),
+    HyperEdgeAdded(HyperEdgeAdded),
 }
 
 impl From<GraphDomainEvent> for BridgeEvent {
     fn from(event: GraphDomainEvent) -> Self {
         match event {
             GraphDomainEvent::GraphCreated(e) => BridgeEvent::GraphCreated(e),
+            GraphDomainEvent::GraphUpdated(e) => BridgeEvent::GraphUpdated(e),
             GraphDomainEvent::NodeAdded(e) => BridgeEvent::NodeAdded(e),
             GraphDomainEvent::NodeRemoved(e) => BridgeEvent::NodeRemoved(e),
+            GraphDomainEvent::NodeUpdated(e) => BridgeEvent::NodeUpdated(e),
+            // ECS systems already apply positions carried by NodeUpdated
+            GraphDomainEvent::NodeMoved(e) => BridgeEvent::NodeUpdated(NodeUpdated {
+                graph_id: e.graph_id,
+                node_id: e.node_id,
+                position: Some(e.position),
+                metadata: std::collections::HashMap::new(),
+                node_type: None,
+                removed_keys: Vec::new(),
+                updated_at: e.moved_at,
+            }),
             GraphDomainEvent::EdgeAdded(e) => BridgeEvent::EdgeAdded(e),
             GraphDomainEvent::EdgeRemoved(e) => BridgeEvent::EdgeRemoved(e),
+            GraphDomainEvent::EdgeUpdated(e) => BridgeEvent::EdgeUpdated(e),
+            GraphDomainEvent::HyperEdgeAdded(e) => BridgeEvent::HyperEdgeAdded(e),
         }
     }
 }
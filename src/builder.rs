@@ -0,0 +1,259 @@
+//! Fluent graph construction
+//!
+//! [`GraphBuilder`] collects nodes under local aliases and edges between aliases, then
+//! produces the command sequence that builds the graph. The commands use the generator
+//! index keys, so [`generators::execute`] or [`GraphBuilder::execute`] can run them against
+//! any handler and resolve the aliases to the IDs the handler assigns. The index keys are
+//! stripped before the commands are handled and never reach the stored metadata.
+
+use crate::{
+    commands::{GraphCommand, GraphCommandError, GraphCommandResult},
+    domain_events::GraphDomainEvent,
+    generators::{self, NODE_INDEX_KEY, SOURCE_INDEX_KEY, TARGET_INDEX_KEY},
+    handlers::GraphCommandHandler,
+    GraphId, NodeId,
+};
+use std::collections::HashMap;
+
+struct PendingNode {
+    alias: String,
+    node_type: String,
+    metadata: HashMap<String, serde_json::Value>,
+}
+
+struct PendingEdge {
+    source: String,
+    target: String,
+    edge_type: String,
+    metadata: HashMap<String, serde_json::Value>,
+}
+
+/// Builder for a graph whose nodes are referenced by local aliases
+pub struct GraphBuilder {
+    name: String,
+    description: String,
+    metadata: HashMap<String, serde_json::Value>,
+    nodes: Vec<PendingNode>,
+    edges: Vec<PendingEdge>,
+}
+
+/// A graph created by [`GraphBuilder::execute`]
+#[derive(Debug, Clone)]
+pub struct BuiltGraph {
+    /// ID assigned to the graph
+    pub graph_id: GraphId,
+    /// ID assigned to each node, by alias
+    pub nodes: HashMap<String, NodeId>,
+    /// Every event emitted while building
+    pub events: Vec<GraphDomainEvent>,
+}
+
+impl GraphBuilder {
+    /// Start a graph named `name`
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: String::new(),
+            metadata: HashMap::new(),
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Set the graph description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Set the graph metadata
+    pub fn metadata(mut self, metadata: HashMap<String, serde_json::Value>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Add a node known locally as `alias`
+    pub fn node(self, alias: impl Into<String>, node_type: impl Into<String>) -> Self {
+        self.node_with_metadata(alias, node_type, HashMap::new())
+    }
+
+    /// Add a node known locally as `alias`, with metadata
+    pub fn node_with_metadata(
+        mut self,
+        alias: impl Into<String>,
+        node_type: impl Into<String>,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        self.nodes.push(PendingNode {
+            alias: alias.into(),
+            node_type: node_type.into(),
+            metadata,
+        });
+        self
+    }
+
+    /// Connect two nodes by alias
+    pub fn edge(
+        self,
+        source: impl Into<String>,
+        target: impl Into<String>,
+        edge_type: impl Into<String>,
+    ) -> Self {
+        self.edge_with_metadata(source, target, edge_type, HashMap::new())
+    }
+
+    /// Connect two nodes by alias, with metadata
+    pub fn edge_with_metadata(
+        mut self,
+        source: impl Into<String>,
+        target: impl Into<String>,
+        edge_type: impl Into<String>,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        self.edges.push(PendingEdge {
+            source: source.into(),
+            target: target.into(),
+            edge_type: edge_type.into(),
+            metadata,
+        });
+        self
+    }
+
+    /// Commands that build the graph: `CreateGraph`, then the nodes, then the edges
+    ///
+    /// Graph and node IDs in the commands are placeholders; run them with
+    /// [`generators::execute`]. Fails with `InvalidCommand` if an alias is declared twice
+    /// or an edge references an undeclared alias.
+    pub fn build(&self) -> GraphCommandResult<Vec<GraphCommand>> {
+        let placeholder_graph = GraphId::new();
+        let mut commands = vec![GraphCommand::CreateGraph {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            metadata: self.metadata.clone(),
+        }];
+
+        let mut indices: HashMap<&str, (usize, NodeId)> = HashMap::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if indices.insert(&node.alias, (index, NodeId::new())).is_some() {
+                return Err(GraphCommandError::InvalidCommand(format!(
+                    "Node alias '{}' is declared twice",
+                    node.alias
+                )));
+            }
+            let mut metadata = node.metadata.clone();
+            metadata.insert(NODE_INDEX_KEY.to_string(), serde_json::json!(index));
+            commands.push(GraphCommand::AddNode {
+                graph_id: placeholder_graph,
+                node_type: node.node_type.clone(),
+                metadata,
+            });
+        }
+
+        for edge in &self.edges {
+            let resolve = |alias: &str| {
+                indices.get(alias).copied().ok_or_else(|| {
+                    GraphCommandError::InvalidCommand(format!("Unknown node alias '{alias}'"))
+                })
+            };
+            let (source_index, source_id) = resolve(&edge.source)?;
+            let (target_index, target_id) = resolve(&edge.target)?;
+
+            let mut metadata = edge.metadata.clone();
+            metadata.insert(SOURCE_INDEX_KEY.to_string(), serde_json::json!(source_index));
+            metadata.insert(TARGET_INDEX_KEY.to_string(), serde_json::json!(target_index));
+            commands.push(GraphCommand::AddEdge {
+                graph_id: placeholder_graph,
+                source_id,
+                target_id,
+                edge_type: edge.edge_type.clone(),
+                metadata,
+                relationship: None,
+            });
+        }
+
+        Ok(commands)
+    }
+
+    /// Build the graph through `handler`
+    pub async fn execute(
+        self,
+        handler: &dyn GraphCommandHandler,
+    ) -> GraphCommandResult<BuiltGraph> {
+        let events = generators::execute(handler, self.build()?).await?;
+
+        // Nodes are added in declaration order, so the n-th NodeAdded is the n-th alias
+        let mut graph_id = None;
+        let mut aliases = self.nodes.iter().map(|node| node.alias.clone());
+        let mut nodes = HashMap::new();
+        for event in &events {
+            match event {
+                GraphDomainEvent::GraphCreated(e) => graph_id = Some(e.graph_id),
+                GraphDomainEvent::NodeAdded(e) => {
+                    if let Some(alias) = aliases.next() {
+                        nodes.insert(alias, e.node_id);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let graph_id = graph_id.ok_or_else(|| {
+            GraphCommandError::InvalidCommand("Handler did not create the graph".to_string())
+        })?;
+
+        Ok(BuiltGraph {
+            graph_id,
+            nodes,
+            events,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::{GraphCommandHandlerImpl, GraphRepository, InMemoryGraphRepository};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_builder_emits_expected_commands() {
+        let builder = GraphBuilder::new("g")
+            .node("a", "task")
+            .node("b", "end")
+            .edge("a", "b", "sequence");
+        let commands = builder.build().unwrap();
+
+        assert_eq!(commands.len(), 4);
+        assert!(matches!(&commands[0], GraphCommand::CreateGraph { name, .. } if name == "g"));
+        let node_types: Vec<&str> = commands[1..3]
+            .iter()
+            .map(|command| match command {
+                GraphCommand::AddNode { node_type, .. } => node_type.as_str(),
+                other => panic!("Expected AddNode, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(node_types, ["task", "end"]);
+        match &commands[3] {
+            GraphCommand::AddEdge {
+                edge_type,
+                metadata,
+                ..
+            } => {
+                assert_eq!(edge_type, "sequence");
+                assert_eq!(metadata[SOURCE_INDEX_KEY], serde_json::json!(0));
+                assert_eq!(metadata[TARGET_INDEX_KEY], serde_json::json!(1));
+            }
+            other => panic!("Expected AddEdge, got {other:?}"),
+        }
+
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+        let built = builder.execute(&handler).await.unwrap();
+        let graph = repository.load(built.graph_id).await.unwrap();
+        let edge = graph.edges().values().next().unwrap();
+        assert_eq!((edge.source_id, edge.target_id), (built.nodes["a"], built.nodes["b"]));
+        assert!(graph.nodes().values().all(|node| node.metadata.is_empty()));
+
+        let dangling = GraphBuilder::new("g").node("a", "task").edge("a", "missing", "sequence");
+        assert!(matches!(dangling.build(), Err(GraphCommandError::InvalidCommand(_))));
+    }
+}
@@ -6,6 +6,15 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use crate::{GraphId, NodeId, EdgeId};
+use crate::value_objects::Position3D;
+use crate::abstraction::ConflictResolution;
+use crate::components::EdgeRelationship;
+use crate::groups::GroupId;
+use crate::queries::GraphStructure;
+
+mod undo;
+
+pub use undo::{invert, UndoStack};
 
 /// Commands for graph operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,7 +47,7 @@ pub enum GraphCommand {
         node_id: NodeId,
     },
     
-    /// Change a node's metadata by removing and re-adding
+    /// Replace a node's metadata in place, keeping its edges and hyperedges
     ChangeNodeMetadata {
         /// The graph containing the node
         graph_id: GraphId,
@@ -47,6 +56,16 @@ pub enum GraphCommand {
         /// The new metadata for the node (replaces all existing metadata)
         new_metadata: HashMap<String, serde_json::Value>,
     },
+
+    /// Set some of a node's metadata keys, keeping the others
+    MergeNodeMetadata {
+        /// The graph containing the node
+        graph_id: GraphId,
+        /// The ID of the node to change
+        node_id: NodeId,
+        /// Keys to set; existing values under these keys are overwritten
+        metadata: HashMap<String, serde_json::Value>,
+    },
     
     /// Add an edge to connect two nodes
     AddEdge {
@@ -60,6 +79,10 @@ pub enum GraphCommand {
         edge_type: String,
         /// Additional metadata about the edge
         metadata: HashMap<String, serde_json::Value>,
+        /// How the edge relates its endpoints; `None` means an association named after
+        /// `edge_type`
+        #[serde(default)]
+        relationship: Option<EdgeRelationship>,
     },
     
     /// Remove an edge from a graph
@@ -69,8 +92,142 @@ pub enum GraphCommand {
         /// The ID of the edge to remove
         edge_id: EdgeId,
     },
+
+    /// Add a hyperedge connecting a group of nodes
+    AddHyperEdge {
+        /// The graph to add the hyperedge to
+        graph_id: GraphId,
+        /// The nodes connected by the hyperedge
+        node_ids: Vec<NodeId>,
+        /// The type of hyperedge (e.g., "join", "fork")
+        edge_type: String,
+        /// Additional metadata about the hyperedge
+        metadata: HashMap<String, serde_json::Value>,
+    },
+
+    /// Add a tag to a node (case-insensitive, ignored if already present)
+    TagNode {
+        /// The graph containing the node
+        graph_id: GraphId,
+        /// The ID of the node to tag
+        node_id: NodeId,
+        /// The tag to add
+        tag: String,
+    },
+
+    /// Remove a tag from a node (case-insensitive, ignored if absent)
+    UntagNode {
+        /// The graph containing the node
+        graph_id: GraphId,
+        /// The ID of the node to untag
+        node_id: NodeId,
+        /// The tag to remove
+        tag: String,
+    },
+
+    /// Change a node's type in place, keeping its id, metadata and edges
+    RelabelNodeType {
+        /// The graph containing the node
+        graph_id: GraphId,
+        /// The ID of the node to relabel
+        node_id: NodeId,
+        /// The new type of the node
+        new_node_type: String,
+    },
+
+    /// Set the weight used by weighted algorithms, stored in the edge's `weight` metadata
+    SetEdgeWeight {
+        /// The graph containing the edge
+        graph_id: GraphId,
+        /// The ID of the edge to weight
+        edge_id: EdgeId,
+        /// The new weight (must not be negative)
+        weight: f64,
+    },
+
+    /// Set a node's position without touching its metadata
+    SetNodePosition {
+        /// The graph containing the node
+        graph_id: GraphId,
+        /// The ID of the node to move
+        node_id: NodeId,
+        /// The new position
+        position: Position3D,
+    },
+
+    /// Remove edges whose source or target node no longer exists
+    RepairGraph {
+        /// The graph to repair
+        graph_id: GraphId,
+    },
+
+    /// Gather nodes into a group that can be collapsed into one compound node
+    CreateGroup {
+        /// The graph containing the nodes
+        graph_id: GraphId,
+        /// The id of the new group
+        group_id: GroupId,
+        /// The nodes in the group (moved out of any group they were in)
+        member_nodes: Vec<NodeId>,
+    },
+
+    /// Swap the source and target of every edge; self-loops are unchanged
+    ReverseEdges {
+        /// The graph whose edges to reverse
+        graph_id: GraphId,
+    },
+
+    /// Load a whole structure into a graph at once, nodes before edges
+    ///
+    /// Node and edge ids are kept. Every edge must connect nodes of the structure or of the
+    /// graph; otherwise nothing is imported.
+    ImportStructure {
+        /// The graph to import into
+        graph_id: GraphId,
+        /// The nodes and edges to add
+        structure: GraphStructure,
+    },
+
+    /// Split a node in two, linked by a [`SPLIT_EDGE_TYPE`] edge from the first to the second
+    ///
+    /// The original node becomes the first half and the second half is a new node with the
+    /// same type and metadata. The node's edges are divided as described in [`split_moves`].
+    SplitNode {
+        /// The graph containing the node
+        graph_id: GraphId,
+        /// The node to split
+        node_id: NodeId,
+        /// Edges into the node that stay on the first half; the others move to the second
+        incoming_to_first: Vec<EdgeId>,
+        /// Edges out of the node that move to the second half; the others stay on the first
+        outgoing_to_second: Vec<EdgeId>,
+    },
+
+    /// Merge an edge's target into its source, removing the edge
+    ///
+    /// Every other edge at the target is moved to the source, and the source's metadata is
+    /// combined with the target's as described in [`contracted_metadata`].
+    ContractEdge {
+        /// The graph containing the edge
+        graph_id: GraphId,
+        /// The edge to contract; self-loops cannot be contracted
+        edge_id: EdgeId,
+        /// How the source's and target's metadata are combined
+        metadata_merge: ConflictResolution,
+    },
+
+    /// Give a graph a new name, keeping its description and metadata
+    RenameGraph {
+        /// The graph to rename
+        graph_id: GraphId,
+        /// The new name (must not be empty)
+        new_name: String,
+    },
 }
 
+/// Edge type of the link created between the halves of a split node
+pub const SPLIT_EDGE_TYPE: &str = "split";
+
 impl GraphCommand {
     /// Get the graph ID this command operates on
     pub fn graph_id(&self) -> Option<GraphId> {
@@ -81,6 +238,20 @@ impl GraphCommand {
             GraphCommand::ChangeNodeMetadata { graph_id, .. } => Some(*graph_id),
             GraphCommand::AddEdge { graph_id, .. } => Some(*graph_id),
             GraphCommand::RemoveEdge { graph_id, .. } => Some(*graph_id),
+            GraphCommand::AddHyperEdge { graph_id, .. } => Some(*graph_id),
+            GraphCommand::MergeNodeMetadata { graph_id, .. } => Some(*graph_id),
+            GraphCommand::TagNode { graph_id, .. } => Some(*graph_id),
+            GraphCommand::UntagNode { graph_id, .. } => Some(*graph_id),
+            GraphCommand::RelabelNodeType { graph_id, .. } => Some(*graph_id),
+            GraphCommand::SetEdgeWeight { graph_id, .. } => Some(*graph_id),
+            GraphCommand::SetNodePosition { graph_id, .. } => Some(*graph_id),
+            GraphCommand::RepairGraph { graph_id } => Some(*graph_id),
+            GraphCommand::CreateGroup { graph_id, .. } => Some(*graph_id),
+            GraphCommand::ReverseEdges { graph_id } => Some(*graph_id),
+            GraphCommand::ImportStructure { graph_id, .. } => Some(*graph_id),
+            GraphCommand::SplitNode { graph_id, .. } => Some(*graph_id),
+            GraphCommand::ContractEdge { graph_id, .. } => Some(*graph_id),
+            GraphCommand::RenameGraph { graph_id, .. } => Some(*graph_id),
         }
     }
 }
@@ -106,7 +277,7 @@ pub enum NodeCommand {
         node_id: NodeId,
     },
     
-    /// Change a node's metadata by removing and re-adding
+    /// Replace a node's metadata in place, keeping its edges and hyperedges
     ChangeMetadata {
         /// The graph containing the node
         graph_id: GraphId,
@@ -168,6 +339,180 @@ pub enum GraphCommandError {
         expected: Option<u64>,
         current: Option<u64>,
     },
+    /// Several validation failures found in a single command
+    ValidationFailed(Vec<String>),
+}
+
+impl GraphCommandError {
+    /// Turn accumulated validation failures into a result
+    ///
+    /// No failures is `Ok`, a single failure is reported as `InvalidCommand`, and several are
+    /// reported together as `ValidationFailed`.
+    pub fn from_failures(mut failures: Vec<String>) -> GraphCommandResult<()> {
+        match failures.len() {
+            0 => Ok(()),
+            1 => Err(GraphCommandError::InvalidCommand(failures.remove(0))),
+            _ => Err(GraphCommandError::ValidationFailed(failures)),
+        }
+    }
+}
+
+/// Validation failures for the members of a new group
+pub fn group_member_failures(member_nodes: &[NodeId]) -> Vec<String> {
+    let mut failures = Vec::new();
+    if member_nodes.is_empty() {
+        failures.push("Group must have at least one member".to_string());
+    }
+    let distinct: std::collections::HashSet<_> = member_nodes.iter().collect();
+    if distinct.len() != member_nodes.len() {
+        failures.push("Group members must be distinct".to_string());
+    }
+    failures
+}
+
+/// Validation failures for a structure being imported into a graph
+///
+/// `existing_nodes` are the nodes already in the graph; edges may connect to them as well
+/// as to the structure's own nodes.
+pub fn structure_failures(
+    structure: &GraphStructure,
+    existing_nodes: &std::collections::HashSet<NodeId>,
+) -> Vec<String> {
+    let mut failures = Vec::new();
+    let mut known = existing_nodes.clone();
+    for node in &structure.nodes {
+        if node.node_type.trim().is_empty() {
+            failures.push(format!("Node {} has an empty type", node.node_id));
+        }
+        if !known.insert(node.node_id) {
+            failures.push(format!("Node {} already exists", node.node_id));
+        }
+        failures.extend(metadata_failures(&node.metadata));
+    }
+    for edge in &structure.edges {
+        if edge.edge_type.trim().is_empty() {
+            failures.push(format!("Edge {} has an empty type", edge.edge_id));
+        }
+        for endpoint in [edge.source_id, edge.target_id] {
+            if !known.contains(&endpoint) {
+                failures.push(format!("Edge {} references missing node {endpoint}", edge.edge_id));
+            }
+        }
+        failures.extend(metadata_failures(&edge.metadata));
+    }
+    failures
+}
+
+/// Validation failures for splitting `node_id`
+///
+/// `endpoints` looks up the source and target of an edge. Edges in `incoming_to_first` must
+/// end at the node and edges in `outgoing_to_second` must start at it.
+pub fn split_failures(
+    node_id: NodeId,
+    incoming_to_first: &[EdgeId],
+    outgoing_to_second: &[EdgeId],
+    endpoints: impl Fn(EdgeId) -> Option<(NodeId, NodeId)>,
+) -> Vec<String> {
+    let mut failures = Vec::new();
+    for edge_id in incoming_to_first {
+        match endpoints(*edge_id) {
+            None => failures.push(format!("Edge {edge_id} does not exist")),
+            Some((_, target)) if target != node_id => {
+                failures.push(format!("Edge {edge_id} does not end at node {node_id}"))
+            }
+            Some(_) => {}
+        }
+    }
+    for edge_id in outgoing_to_second {
+        match endpoints(*edge_id) {
+            None => failures.push(format!("Edge {edge_id} does not exist")),
+            Some((source, _)) if source != node_id => {
+                failures.push(format!("Edge {edge_id} does not start at node {node_id}"))
+            }
+            Some(_) => {}
+        }
+    }
+    failures
+}
+
+/// Edges that move to `second_id` when splitting `node_id`, with their new endpoints
+///
+/// Edges in `outgoing_to_second` start at the second half instead, and incoming edges not
+/// listed in `incoming_to_first` end there instead; every other edge stays on the first
+/// half. `edges` lists edges as `(edge_id, source, target)`; edges away from the node are
+/// ignored.
+pub fn split_moves(
+    node_id: NodeId,
+    second_id: NodeId,
+    incoming_to_first: &[EdgeId],
+    outgoing_to_second: &[EdgeId],
+    edges: impl IntoIterator<Item = (EdgeId, NodeId, NodeId)>,
+) -> Vec<(EdgeId, NodeId, NodeId)> {
+    edges
+        .into_iter()
+        .filter_map(|(edge_id, source, target)| {
+            let incoming = target == node_id && source != node_id;
+            if outgoing_to_second.contains(&edge_id) {
+                Some((edge_id, second_id, target))
+            } else if incoming && !incoming_to_first.contains(&edge_id) {
+                Some((edge_id, source, second_id))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Metadata of the node left by contracting an edge
+///
+/// Keys of the source are never dropped. `KeepFirst` keeps the source's metadata as is,
+/// `KeepLast` overlays the target's values on it and `Merge` combines the two as graph
+/// composition does. `Fail` overlays the target's keys but rejects the contraction if any
+/// key has different values on the two nodes.
+pub fn contracted_metadata(
+    strategy: ConflictResolution,
+    source: &HashMap<String, serde_json::Value>,
+    target: &HashMap<String, serde_json::Value>,
+) -> GraphCommandResult<HashMap<String, serde_json::Value>> {
+    match strategy {
+        ConflictResolution::KeepFirst => Ok(source.clone()),
+        ConflictResolution::KeepLast => {
+            let mut merged = source.clone();
+            merged.extend(target.iter().map(|(key, value)| (key.clone(), value.clone())));
+            Ok(merged)
+        }
+        ConflictResolution::Merge => Ok(crate::abstraction::composition::merge_metadata(
+            source.clone(),
+            target.clone(),
+        )),
+        ConflictResolution::Fail => {
+            let mut conflicts: Vec<&String> = target
+                .iter()
+                .filter(|(key, value)| source.get(*key).is_some_and(|existing| existing != *value))
+                .map(|(key, _)| key)
+                .collect();
+            if !conflicts.is_empty() {
+                conflicts.sort();
+                return Err(GraphCommandError::BusinessRuleViolation(format!(
+                    "Contracted nodes disagree on metadata keys: {conflicts:?}"
+                )));
+            }
+            let mut merged = source.clone();
+            merged.extend(target.iter().map(|(key, value)| (key.clone(), value.clone())));
+            Ok(merged)
+        }
+    }
+}
+
+/// Validation failures for command metadata
+///
+/// Metadata keys must not be blank.
+pub fn metadata_failures(metadata: &HashMap<String, serde_json::Value>) -> Vec<String> {
+    if metadata.keys().any(|key| key.trim().is_empty()) {
+        vec!["Metadata keys cannot be empty".to_string()]
+    } else {
+        Vec::new()
+    }
 }
 
 impl std::fmt::Display for GraphCommandError {
@@ -183,6 +528,9 @@ impl std::fmt::Display for GraphCommandError {
             GraphCommandError::ConcurrencyConflict { expected, current } => {
                 write!(f, "Concurrency conflict: expected version {:?}, current version {:?}", expected, current)
             }
+            GraphCommandError::ValidationFailed(failures) => {
+                write!(f, "Validation failed: {}", failures.join("; "))
+            }
         }
     }
 }
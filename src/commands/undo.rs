@@ -0,0 +1,248 @@
+//! Undo and redo through inverse commands
+//!
+//! Each event maps to the command that reverses it. Events that do not carry the state
+//! needed to reverse them have no inverse.
+
+use super::GraphCommand;
+use crate::domain_events::GraphDomainEvent;
+use crate::events::RemovedEdge;
+
+/// The command that undoes an event, if the event can be reversed
+///
+/// Additions invert to removals. An `EdgeRemoved` inverts to `AddEdge` when it captured the
+/// removed edge; the restored edge receives a new id. Node removals, updates, moves and
+/// hyperedges do not record what they replaced and have no inverse.
+pub fn invert(event: &GraphDomainEvent) -> Option<GraphCommand> {
+    match event {
+        GraphDomainEvent::NodeAdded(e) => Some(GraphCommand::RemoveNode {
+            graph_id: e.graph_id,
+            node_id: e.node_id,
+        }),
+        GraphDomainEvent::EdgeAdded(e) => Some(GraphCommand::RemoveEdge {
+            graph_id: e.graph_id,
+            edge_id: e.edge_id,
+        }),
+        GraphDomainEvent::EdgeRemoved(e) => {
+            let RemovedEdge {
+                source,
+                target,
+                edge_type,
+                metadata,
+            } = e.removed.clone()?;
+            Some(GraphCommand::AddEdge {
+                graph_id: e.graph_id,
+                source_id: source,
+                target_id: target,
+                edge_type,
+                metadata,
+                relationship: None,
+            })
+        }
+        GraphDomainEvent::GraphCreated(_)
+        | GraphDomainEvent::GraphUpdated(_)
+        | GraphDomainEvent::NodeRemoved(_)
+        | GraphDomainEvent::NodeUpdated(_)
+        | GraphDomainEvent::NodeMoved(_)
+        | GraphDomainEvent::EdgeUpdated(_)
+        | GraphDomainEvent::HyperEdgeAdded(_) => None,
+    }
+}
+
+/// Undo and redo history of command batches
+///
+/// Record the events of each user action; `undo` returns the commands reversing the latest
+/// action, newest event first. Execute them and pass their events to `record_undone` so the
+/// action can be redone, and likewise pass the events of redone commands to `record_redone`.
+#[derive(Debug, Clone, Default)]
+pub struct UndoStack {
+    undo: Vec<Vec<GraphCommand>>,
+    redo: Vec<Vec<GraphCommand>>,
+}
+
+impl UndoStack {
+    /// Create an empty history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the events of a new action, discarding any redo history
+    ///
+    /// Returns false when an event has no inverse; the undo history is then cleared, since
+    /// earlier actions can no longer be reversed reliably.
+    pub fn record(&mut self, events: &[GraphDomainEvent]) -> bool {
+        self.redo.clear();
+        match Self::inverse_batch(events) {
+            Some(batch) => {
+                if !batch.is_empty() {
+                    self.undo.push(batch);
+                }
+                true
+            }
+            None => {
+                self.undo.clear();
+                false
+            }
+        }
+    }
+
+    /// Commands reversing the latest recorded action
+    pub fn undo(&mut self) -> Option<Vec<GraphCommand>> {
+        self.undo.pop()
+    }
+
+    /// Commands repeating the latest undone action
+    pub fn redo(&mut self) -> Option<Vec<GraphCommand>> {
+        self.redo.pop()
+    }
+
+    /// Record the events produced by executing the commands from `undo`
+    pub fn record_undone(&mut self, events: &[GraphDomainEvent]) {
+        match Self::inverse_batch(events) {
+            Some(batch) => self.redo.push(batch),
+            None => self.redo.clear(),
+        }
+    }
+
+    /// Record the events produced by executing the commands from `redo`
+    pub fn record_redone(&mut self, events: &[GraphDomainEvent]) {
+        match Self::inverse_batch(events) {
+            Some(batch) => self.undo.push(batch),
+            None => self.undo.clear(),
+        }
+    }
+
+    /// Whether there is an action to undo
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Whether there is an action to redo
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Inverse commands for a batch of events, newest first
+    fn inverse_batch(events: &[GraphDomainEvent]) -> Option<Vec<GraphCommand>> {
+        events.iter().rev().map(invert).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::{
+        GraphCommandHandler, GraphCommandHandlerImpl, GraphRepository, InMemoryGraphRepository,
+    };
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_invert_node_added_restores_node_count() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Editor".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+        let before = repository.load(graph_id).await.unwrap().node_count();
+
+        let mut history = UndoStack::new();
+        let added = handler
+            .handle_graph_command(GraphCommand::AddNode {
+                graph_id,
+                node_type: "task".to_string(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        assert!(history.record(&added));
+        assert_eq!(repository.load(graph_id).await.unwrap().node_count(), before + 1);
+
+        let mut undone = Vec::new();
+        for command in history.undo().unwrap() {
+            undone.extend(handler.handle_graph_command(command).await.unwrap());
+        }
+        history.record_undone(&undone);
+
+        assert_eq!(repository.load(graph_id).await.unwrap().node_count(), before);
+        assert!(!history.can_undo());
+        // Node removals cannot be reversed, so there is nothing to redo
+        assert!(!history.can_redo());
+    }
+
+    #[tokio::test]
+    async fn test_undo_and_redo_edge() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Editor".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+        let mut node_ids = Vec::new();
+        for _ in 0..2 {
+            match &handler
+                .handle_graph_command(GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "task".to_string(),
+                    metadata: HashMap::new(),
+                })
+                .await
+                .unwrap()[0]
+            {
+                GraphDomainEvent::NodeAdded(event) => node_ids.push(event.node_id),
+                _ => panic!("Expected NodeAdded event"),
+            }
+        }
+
+        let mut history = UndoStack::new();
+        let added = handler
+            .handle_graph_command(GraphCommand::AddEdge {
+                graph_id,
+                source_id: node_ids[0],
+                target_id: node_ids[1],
+                edge_type: "sequence".to_string(),
+                metadata: HashMap::new(),
+                relationship: None,
+            })
+            .await
+            .unwrap();
+        assert!(history.record(&added));
+
+        let mut undone = Vec::new();
+        for command in history.undo().unwrap() {
+            undone.extend(handler.handle_graph_command(command).await.unwrap());
+        }
+        history.record_undone(&undone);
+        assert_eq!(repository.load(graph_id).await.unwrap().edge_count(), 0);
+
+        let mut redone = Vec::new();
+        for command in history.redo().unwrap() {
+            redone.extend(handler.handle_graph_command(command).await.unwrap());
+        }
+        history.record_redone(&redone);
+
+        let graph = repository.load(graph_id).await.unwrap();
+        let edge = graph.edges().values().next().unwrap();
+        assert_eq!((edge.source_id, edge.target_id), (node_ids[0], node_ids[1]));
+        assert_eq!(edge.edge_type, "sequence");
+        assert!(history.can_undo());
+    }
+}
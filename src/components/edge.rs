@@ -63,6 +63,13 @@ pub enum EdgeRelationship {
 }
 
 impl EdgeRelationship {
+    /// Association named after an edge type, used when no relationship is given
+    pub fn association(edge_type: &str) -> Self {
+        Self::Association {
+            association_type: edge_type.to_string(),
+        }
+    }
+
     /// Get the weight associated with this relationship
     pub fn weight(&self) -> Option<f32> {
         match self {
@@ -143,6 +150,55 @@ impl Default for EdgeDirection {
     }
 }
 
+/// Arrow heads drawn on an edge
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EdgeArrow {
+    /// Plain line
+    None,
+    /// Head at the target
+    Forward,
+    /// Head at the source
+    Backward,
+    /// Heads at both ends
+    Both,
+}
+
+impl Default for EdgeArrow {
+    fn default() -> Self {
+        Self::Forward
+    }
+}
+
+impl EdgeArrow {
+    /// Arrow style for an edge
+    ///
+    /// An explicit direction wins; otherwise the relationship decides. Associations
+    /// are mutual and render double-headed, similarities are symmetric and render
+    /// without heads, and hierarchies point from parent to child.
+    pub fn for_edge(relationship: &EdgeRelationship, direction: Option<EdgeDirection>) -> Self {
+        match direction {
+            Some(EdgeDirection::Forward) => Self::Forward,
+            Some(EdgeDirection::Backward) => Self::Backward,
+            Some(EdgeDirection::Bidirectional) => Self::Both,
+            Some(EdgeDirection::Undirected) => Self::None,
+            None => match relationship {
+                EdgeRelationship::Dependency { .. } | EdgeRelationship::Flow { .. } => {
+                    Self::Forward
+                }
+                EdgeRelationship::Hierarchy { parent_to_child } => {
+                    if *parent_to_child {
+                        Self::Forward
+                    } else {
+                        Self::Backward
+                    }
+                }
+                EdgeRelationship::Association { .. } => Self::Both,
+                EdgeRelationship::Similarity { .. } => Self::None,
+            },
+        }
+    }
+}
+
 /// Edge style for rendering
 #[derive(Component, Debug, Clone, PartialEq)]
 pub enum EdgeStyle {
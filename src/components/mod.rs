@@ -16,11 +16,13 @@ pub use graph::{GraphEntity, GraphLayout, GraphMetadata, GraphStatus, GraphType,
 pub use node::{NodeCategory, NodeContent, NodeEntity, NodeMetadata, NodeStatus, NodeType};
 
 pub use edge::{
-    EdgeColor, EdgeDirection, EdgeEntity, EdgeMetadata, EdgeRelationship, EdgeStyle, EdgeType,
-    EdgeWeight,
+    EdgeArrow, EdgeColor, EdgeDirection, EdgeEntity, EdgeMetadata, EdgeRelationship, EdgeStyle,
+    EdgeType, EdgeWeight,
 };
 
-pub use visual::{BoundingBox, Color, Position3D, Size, Style, Transform3D, Visibility};
+pub use visual::{
+    BoundingBox, Color, Position3D, Size, Style, StyleTheme, Transform3D, Visibility,
+};
 
 pub use workflow::{
     RetryPolicy, StepType, WorkflowMetadata, WorkflowState, WorkflowStatus, WorkflowStep,
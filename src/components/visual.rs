@@ -3,6 +3,8 @@
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::node::NodeType;
+
 // Re-export Position3D from value_objects
 pub use crate::value_objects::Position3D;
 
@@ -96,6 +98,72 @@ impl Default for Style {
     }
 }
 
+impl Style {
+    /// Default style for a node type, taken from the default [`StyleTheme`]
+    pub fn for_node_type(node_type: &NodeType) -> Style {
+        StyleTheme::default().style_for(node_type)
+    }
+
+    /// Default style with the given shape and fill
+    fn filled(shape: Shape, fill_color: Color) -> Self {
+        Self {
+            shape,
+            fill_color,
+            ..Self::default()
+        }
+    }
+}
+
+/// Node styles per node type
+///
+/// Renderers share a theme instead of styling nodes case by case; override individual fields
+/// to customise it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StyleTheme {
+    pub start: Style,
+    pub end: Style,
+    pub process: Style,
+    pub decision: Style,
+    pub data: Style,
+    pub event: Style,
+    pub gateway: Style,
+    pub workflow_step: Style,
+    pub concept: Style,
+}
+
+impl StyleTheme {
+    /// Style used for nodes of the given type
+    pub fn style_for(&self, node_type: &NodeType) -> Style {
+        match node_type {
+            NodeType::Start => self.start.clone(),
+            NodeType::End => self.end.clone(),
+            NodeType::Process => self.process.clone(),
+            NodeType::Decision => self.decision.clone(),
+            NodeType::Data => self.data.clone(),
+            NodeType::Event => self.event.clone(),
+            NodeType::Gateway => self.gateway.clone(),
+            NodeType::WorkflowStep { .. } => self.workflow_step.clone(),
+            NodeType::Concept { .. } => self.concept.clone(),
+        }
+    }
+}
+
+impl Default for StyleTheme {
+    fn default() -> Self {
+        Self {
+            start: Style::filled(Shape::Circle, Color::rgb(0.2, 0.7, 0.3)),
+            end: Style::filled(Shape::Circle, Color::rgb(0.8, 0.2, 0.2)),
+            process: Style::filled(Shape::Rectangle, Color::rgb(0.3, 0.5, 0.9)),
+            decision: Style::filled(Shape::Diamond, Color::rgb(0.95, 0.8, 0.2)),
+            data: Style::filled(Shape::Rectangle, Color::rgb(0.7, 0.7, 0.7)),
+            event: Style::filled(Shape::Circle, Color::rgb(0.95, 0.6, 0.2)),
+            gateway: Style::filled(Shape::Diamond, Color::rgb(0.6, 0.4, 0.8)),
+            workflow_step: Style::filled(Shape::Rectangle, Color::rgb(0.5, 0.75, 0.95)),
+            concept: Style::filled(Shape::Hexagon, Color::rgb(0.2, 0.7, 0.7)),
+        }
+    }
+}
+
 /// Visibility component
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Visibility {
@@ -169,4 +237,70 @@ impl BoundingBox {
         point.y >= self.min.y && point.y <= self.max.y &&
         point.z >= self.min.z && point.z <= self.max.z
     }
-} 
\ No newline at end of file
+
+    /// Whether the two boxes overlap, touching faces included
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y &&
+        self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+
+    /// Smallest box enclosing both boxes
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min: Position3D::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            max: Position3D::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_for_node_type() {
+        let start = Style::for_node_type(&NodeType::Start);
+        let end = Style::for_node_type(&NodeType::End);
+        assert_ne!(start.fill_color, end.fill_color);
+
+        let mut theme = StyleTheme::default();
+        theme.start.fill_color = Color::BLUE;
+        assert_eq!(theme.style_for(&NodeType::Start).fill_color, Color::BLUE);
+        assert_eq!(theme.style_for(&NodeType::End), end);
+    }
+
+    #[test]
+    fn test_bounding_box_operations() {
+        let a = BoundingBox {
+            min: Position3D::new(0.0, 0.0, 0.0),
+            max: Position3D::new(2.0, 2.0, 2.0),
+        };
+        let b = BoundingBox {
+            min: Position3D::new(1.0, 1.0, 1.0),
+            max: Position3D::new(3.0, 4.0, 3.0),
+        };
+
+        assert!(a.contains(&Position3D::new(1.0, 1.5, 0.5)));
+        assert!(!a.contains(&Position3D::new(2.5, 1.0, 1.0)));
+
+        assert!(a.intersects(&b));
+        let far = BoundingBox {
+            min: Position3D::new(5.0, 5.0, 5.0),
+            max: Position3D::new(6.0, 6.0, 6.0),
+        };
+        assert!(!a.intersects(&far));
+
+        let union = a.union(&b);
+        assert_eq!(union.min, Position3D::new(0.0, 0.0, 0.0));
+        assert_eq!(union.max, Position3D::new(3.0, 4.0, 3.0));
+    }
+}
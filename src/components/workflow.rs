@@ -42,6 +42,13 @@ pub enum WorkflowStatus {
     Cancelled,
 }
 
+impl WorkflowStatus {
+    /// Whether the workflow has finished and accepts no further transitions
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
 /// Workflow step configuration
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowStep {
@@ -93,6 +100,28 @@ impl Default for RetryPolicy {
     }
 }
 
+/// Delay before retrying a step that has failed `attempt` times
+///
+/// Returns `None` once `attempt` reaches the policy's `max_attempts` or when the policy
+/// does not retry. Exponential delays grow by `factor` per attempt from `initial_ms`
+/// and saturate rather than overflow.
+pub fn next_retry(policy: &RetryPolicy, attempt: u32) -> Option<std::time::Duration> {
+    if attempt >= policy.max_attempts {
+        return None;
+    }
+
+    match policy.backoff_strategy {
+        BackoffStrategy::Fixed { delay_ms } => Some(std::time::Duration::from_millis(delay_ms)),
+        BackoffStrategy::Exponential { initial_ms, factor } => {
+            let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+            // Float-to-int casts saturate, so huge delays clamp to u64::MAX milliseconds
+            let delay_ms = initial_ms as f64 * f64::from(factor).powi(exponent);
+            Some(std::time::Duration::from_millis(delay_ms as u64))
+        }
+        BackoffStrategy::None => None,
+    }
+}
+
 /// Workflow transition between steps
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowTransition {
@@ -113,6 +142,43 @@ pub enum TransitionCondition {
     Approval { approver_role: String },
 }
 
+/// Apply a transition to a workflow state, returning the advanced state
+///
+/// Transitions are only legal while the workflow is not started or running, and must
+/// leave from the current step; a workflow that has not started yet may leave from
+/// any step. Paused workflows must be resumed first. The transition condition is not
+/// evaluated here.
+pub fn apply_transition(
+    state: &WorkflowState,
+    transition: &WorkflowTransition,
+) -> Result<WorkflowState, String> {
+    match state.status {
+        WorkflowStatus::NotStarted | WorkflowStatus::Running => {}
+        WorkflowStatus::Paused => {
+            return Err("Workflow is paused and must be resumed before transitioning".to_string())
+        }
+        status => return Err(format!("Workflow is {status:?} and cannot transition")),
+    }
+
+    if let Some(current) = state.current_step {
+        if current != transition.from_step {
+            return Err(format!(
+                "Transition leaves step {} but the workflow is at step {current}",
+                transition.from_step
+            ));
+        }
+    }
+
+    let mut next = state.clone();
+    if next.execution_path.is_empty() {
+        next.execution_path.push(transition.from_step);
+    }
+    next.execution_path.push(transition.to_step);
+    next.current_step = Some(transition.to_step);
+    next.status = WorkflowStatus::Running;
+    Ok(next)
+}
+
 /// Workflow metadata
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowMetadata {
@@ -138,4 +204,76 @@ impl Default for WorkflowMetadata {
             updated_at: now,
         }
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(from_step: NodeId, to_step: NodeId) -> WorkflowTransition {
+        WorkflowTransition {
+            from_step,
+            to_step,
+            condition: TransitionCondition::Always,
+        }
+    }
+
+    #[test]
+    fn test_apply_transition() {
+        let steps: Vec<NodeId> = (0..3).map(|_| NodeId::new()).collect();
+
+        let started =
+            apply_transition(&WorkflowState::default(), &transition(steps[0], steps[1])).unwrap();
+        assert_eq!(started.status, WorkflowStatus::Running);
+        assert_eq!(started.current_step, Some(steps[1]));
+        assert_eq!(started.execution_path, vec![steps[0], steps[1]]);
+
+        let advanced = apply_transition(&started, &transition(steps[1], steps[2])).unwrap();
+        assert_eq!(advanced.current_step, Some(steps[2]));
+        assert_eq!(advanced.execution_path, steps);
+
+        // Transitions must leave from the current step
+        assert!(apply_transition(&advanced, &transition(steps[0], steps[1])).is_err());
+
+        // Terminal workflows stay put
+        let completed = WorkflowState {
+            status: WorkflowStatus::Completed,
+            ..advanced
+        };
+        assert!(apply_transition(&completed, &transition(steps[2], steps[0])).is_err());
+    }
+
+    #[test]
+    fn test_next_retry_exponential_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            backoff_strategy: BackoffStrategy::Exponential {
+                initial_ms: 100,
+                factor: 2.0,
+            },
+        };
+
+        let delays: Vec<_> = (1..=4)
+            .map(|attempt| next_retry(&policy, attempt))
+            .collect();
+        assert_eq!(
+            delays,
+            vec![
+                Some(std::time::Duration::from_millis(100)),
+                Some(std::time::Duration::from_millis(200)),
+                Some(std::time::Duration::from_millis(400)),
+                None,
+            ]
+        );
+
+        let fixed = RetryPolicy {
+            max_attempts: 2,
+            backoff_strategy: BackoffStrategy::Fixed { delay_ms: 50 },
+        };
+        assert_eq!(
+            next_retry(&fixed, 1),
+            Some(std::time::Duration::from_millis(50))
+        );
+        assert_eq!(next_retry(&fixed, 2), None);
+    }
+}
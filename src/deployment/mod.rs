@@ -11,5 +11,5 @@ pub mod graph_adapter;
 
 pub use node_types::{DeploymentNodeType, ResourceRequirements, HealthCheck, DatabaseEngine, MessageBusType, LoadBalancingStrategy, StorageType, AccessMode};
 pub use edge_types::{DeploymentEdgeType, DependencyType};
-pub use translator::{GraphToNixTranslator, NixDeploymentSpec, ServiceSpec, StandardTranslator};
-pub use validation::{validate_deployment_graph, DeploymentError};
\ No newline at end of file
+pub use translator::{collect_health_checks, GraphToNixTranslator, NixDeploymentSpec, ServiceSpec, StandardTranslator};
+pub use validation::{check_backend_health_checks, validate_deployment_graph, DeploymentError};
\ No newline at end of file
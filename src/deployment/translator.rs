@@ -150,6 +150,19 @@ pub struct NetworkConnection {
     pub encrypted: bool,
 }
 
+/// Every service's health check, keyed by service name, for registration with an orchestrator
+pub fn collect_health_checks(spec: &NixDeploymentSpec) -> Vec<(String, HealthCheckSpec)> {
+    spec.services
+        .iter()
+        .filter_map(|service| {
+            service
+                .health_check
+                .clone()
+                .map(|health_check| (service.name.clone(), health_check))
+        })
+        .collect()
+}
+
 /// Standard implementation of the graph to Nix translator
 pub struct StandardTranslator;
 
@@ -279,12 +292,13 @@ impl StandardTranslator {
             resources,
         } = node {
             let dependencies = self.get_node_dependencies(graph, node_id)?;
+            let environment = self.interpolate_environment(name, environment, graph)?;
             
             Ok(ServiceSpec {
                 name: name.clone(),
                 command: command.clone(),
                 args: args.clone(),
-                environment: environment.clone(),
+                environment,
                 port: *port,
                 health_check: health_check.as_ref().map(|hc| HealthCheckSpec {
                     endpoint: hc.endpoint.clone(),
@@ -313,13 +327,7 @@ impl StandardTranslator {
             backup_schedule,
             resources,
         } = node {
-            let port = match engine {
-                super::node_types::DatabaseEngine::PostgreSQL => 5432,
-                super::node_types::DatabaseEngine::MySQL => 3306,
-                super::node_types::DatabaseEngine::MongoDB => 27017,
-                super::node_types::DatabaseEngine::Redis => 6379,
-                super::node_types::DatabaseEngine::SQLite => 0,
-            };
+            let port = database_port(engine);
             
             Ok(DatabaseSpec {
                 name: name.clone(),
@@ -374,12 +382,7 @@ impl StandardTranslator {
             persistence,
             ..
         } = node {
-            let ports = match bus_type {
-                super::node_types::MessageBusType::NATS => vec![4222, 6222, 8222],
-                super::node_types::MessageBusType::Kafka => vec![9092],
-                super::node_types::MessageBusType::RabbitMQ => vec![5672, 15672],
-                super::node_types::MessageBusType::Redis => vec![6379],
-            };
+            let ports = message_bus_ports(bus_type);
             
             Ok(MessageBusSpec {
                 name: name.clone(),
@@ -456,6 +459,51 @@ impl StandardTranslator {
         Ok(dependencies)
     }
     
+    /// Resolve `${node.field}` references in a service's environment against other nodes
+    ///
+    /// `node` is the name of a deployment node and `field` is `name`, `host` (nodes are
+    /// reachable under their name) or `port`. Unresolved references are errors.
+    fn interpolate_environment(
+        &self,
+        service: &str,
+        environment: &HashMap<String, String>,
+        graph: &Graph,
+    ) -> Result<HashMap<String, String>> {
+        let nodes: HashMap<String, DeploymentNodeType> = graph
+            .get_all_nodes()
+            .into_iter()
+            .filter_map(|node| serde_json::from_value::<DeploymentNodeType>(node.data).ok())
+            .map(|node_type| (node_type.name().to_string(), node_type))
+            .collect();
+
+        let mut resolved = HashMap::new();
+        for (key, value) in environment {
+            let mut output = String::new();
+            let mut rest = value.as_str();
+            while let Some(start) = rest.find("${") {
+                output.push_str(&rest[..start]);
+                let end = rest[start..].find('}').ok_or_else(|| {
+                    anyhow::anyhow!("Unterminated reference in {key} of service {service}")
+                })?;
+                let reference = &rest[start + 2..start + end];
+                let replacement = reference
+                    .split_once('.')
+                    .and_then(|(node, field)| reference_value(nodes.get(node)?, field))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Unresolved reference ${{{reference}}} in {key} of service {service}"
+                        )
+                    })?;
+                output.push_str(&replacement);
+                rest = &rest[start + end + 1..];
+            }
+            output.push_str(rest);
+            resolved.insert(key.clone(), output);
+        }
+        
+        Ok(resolved)
+    }
+    
     fn find_nats_url(&self, graph: &Graph) -> Result<String> {
         for node in graph.get_all_nodes() {
             if let Ok(node_type) = serde_json::from_value::<DeploymentNodeType>(node.data.clone()) {
@@ -545,3 +593,105 @@ impl StandardTranslator {
     }
 }
 
+/// Default port of a database engine
+fn database_port(engine: &super::node_types::DatabaseEngine) -> u16 {
+    match engine {
+        super::node_types::DatabaseEngine::PostgreSQL => 5432,
+        super::node_types::DatabaseEngine::MySQL => 3306,
+        super::node_types::DatabaseEngine::MongoDB => 27017,
+        super::node_types::DatabaseEngine::Redis => 6379,
+        super::node_types::DatabaseEngine::SQLite => 0,
+    }
+}
+
+/// Ports opened by a message bus, client port first
+fn message_bus_ports(bus_type: &super::node_types::MessageBusType) -> Vec<u16> {
+    match bus_type {
+        super::node_types::MessageBusType::NATS => vec![4222, 6222, 8222],
+        super::node_types::MessageBusType::Kafka => vec![9092],
+        super::node_types::MessageBusType::RabbitMQ => vec![5672, 15672],
+        super::node_types::MessageBusType::Redis => vec![6379],
+    }
+}
+
+/// Value of a `${node.field}` reference for a deployment node
+fn reference_value(node: &DeploymentNodeType, field: &str) -> Option<String> {
+    match field {
+        "name" | "host" => Some(node.name().to_string()),
+        "port" => match node {
+            DeploymentNodeType::Service { port, .. } => port.map(|port| port.to_string()),
+            DeploymentNodeType::Database { engine, .. } => Some(database_port(engine).to_string()),
+            DeploymentNodeType::MessageBus { bus_type, .. } => {
+                message_bus_ports(bus_type).first().map(|port| port.to_string())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deployment::graph_adapter::create_deployment_node_metadata;
+    use crate::deployment::node_types::{DatabaseEngine, ResourceRequirements};
+    use crate::{GraphId, NodeId};
+
+    fn add_deployment_node(graph: &mut Graph, node_type: DeploymentNodeType) {
+        graph
+            .add_node(
+                NodeId::new(),
+                "deployment".to_string(),
+                create_deployment_node_metadata(node_type),
+            )
+            .unwrap();
+    }
+
+    fn api_service(database_url: &str) -> DeploymentNodeType {
+        DeploymentNodeType::Service {
+            name: "api".to_string(),
+            command: "/bin/api".to_string(),
+            args: Vec::new(),
+            environment: HashMap::from([
+                ("DATABASE_URL".to_string(), database_url.to_string()),
+                ("LOG_LEVEL".to_string(), "info".to_string()),
+            ]),
+            port: Some(8080),
+            health_check: None,
+            resources: ResourceRequirements::default(),
+        }
+    }
+
+    #[test]
+    fn test_environment_references_resolve_against_nodes() {
+        let mut graph = Graph::new(GraphId::new(), "Deployment".to_string(), String::new());
+        add_deployment_node(
+            &mut graph,
+            DeploymentNodeType::Database {
+                name: "orders-db".to_string(),
+                engine: DatabaseEngine::PostgreSQL,
+                version: "16".to_string(),
+                persistent: true,
+                backup_schedule: None,
+                resources: ResourceRequirements::default(),
+            },
+        );
+        add_deployment_node(
+            &mut graph,
+            api_service("postgres://${orders-db.host}:${orders-db.port}/orders"),
+        );
+
+        let translator = StandardTranslator::new();
+        let services = translator.extract_services(&graph).unwrap();
+        assert_eq!(services.len(), 1);
+        assert_eq!(
+            services[0].environment["DATABASE_URL"],
+            "postgres://orders-db:5432/orders"
+        );
+        assert_eq!(services[0].environment["LOG_LEVEL"], "info");
+
+        let mut graph = Graph::new(GraphId::new(), "Broken".to_string(), String::new());
+        add_deployment_node(&mut graph, api_service("postgres://${missing-db.host}/orders"));
+        assert!(translator.extract_services(&graph).is_err());
+    }
+}
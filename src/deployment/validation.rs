@@ -2,6 +2,7 @@
 
 use crate::aggregate::business_graph::Graph;
 use super::{DeploymentNodeType, DeploymentEdgeType, graph_adapter::DeploymentGraphExt};
+use super::translator::{collect_health_checks, NixDeploymentSpec};
 use thiserror::Error;
 use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -31,6 +32,9 @@ pub enum DeploymentError {
     
     #[error("Storage conflict: {path} is mounted by multiple services")]
     StorageConflict { path: String },
+
+    #[error("Load balancer {load_balancer} routes to {backend}, which has no health check")]
+    MissingHealthCheck { load_balancer: String, backend: String },
 }
 
 /// Validate a deployment graph for correctness
@@ -256,6 +260,27 @@ fn check_node_configurations(graph: &Graph) -> Result<(), DeploymentError> {
     Ok(())
 }
 
+/// Check that every load balancer backend is a service exposing a health check
+pub fn check_backend_health_checks(spec: &NixDeploymentSpec) -> Result<(), DeploymentError> {
+    let checked: HashSet<String> = collect_health_checks(spec)
+        .into_iter()
+        .map(|(service, _)| service)
+        .collect();
+
+    for load_balancer in &spec.load_balancers {
+        for backend in &load_balancer.backends {
+            if !checked.contains(&backend.service) {
+                return Err(DeploymentError::MissingHealthCheck {
+                    load_balancer: load_balancer.name.clone(),
+                    backend: backend.service.clone(),
+                });
+            }
+        }
+    }
+    
+    Ok(())
+}
+
 /// Get topological order of nodes for deployment
 pub fn get_deployment_order(graph: &Graph) -> Result<Vec<String>, DeploymentError> {
     let mut in_degree: HashMap<String, usize> = HashMap::new();
@@ -310,4 +335,72 @@ pub fn get_deployment_order(graph: &Graph) -> Result<Vec<String>, DeploymentErro
     }
     
     Ok(result)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deployment::translator::{
+        BackendSpec, HealthCheckSpec, LoadBalancerSpec, NetworkTopology, ServiceSpec,
+    };
+
+    fn service(name: &str, health_check: Option<HealthCheckSpec>) -> ServiceSpec {
+        ServiceSpec {
+            name: name.to_string(),
+            command: format!("/bin/{name}"),
+            args: Vec::new(),
+            environment: HashMap::new(),
+            port: Some(8080),
+            health_check,
+            resources: None,
+            dependencies: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_backend_without_health_check_is_flagged() {
+        let health_check = HealthCheckSpec {
+            endpoint: "/health".to_string(),
+            interval_seconds: 10,
+            timeout_seconds: 2,
+            retries: 3,
+        };
+        let backend = |service: &str| BackendSpec {
+            service: service.to_string(),
+            port: 8080,
+            weight: None,
+        };
+        let mut spec = NixDeploymentSpec {
+            services: vec![service("api", Some(health_check)), service("worker", None)],
+            databases: Vec::new(),
+            agents: Vec::new(),
+            message_buses: Vec::new(),
+            load_balancers: vec![LoadBalancerSpec {
+                name: "edge".to_string(),
+                strategy: "RoundRobin".to_string(),
+                backends: vec![backend("api")],
+                health_check_interval: 10,
+            }],
+            storage_volumes: Vec::new(),
+            dependencies: HashMap::new(),
+            network_topology: NetworkTopology {
+                connections: Vec::new(),
+                exposed_ports: HashMap::new(),
+            },
+        };
+
+        let checks = collect_health_checks(&spec);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].0, "api");
+        assert!(check_backend_health_checks(&spec).is_ok());
+
+        spec.load_balancers[0].backends.push(backend("worker"));
+        match check_backend_health_checks(&spec) {
+            Err(DeploymentError::MissingHealthCheck { load_balancer, backend }) => {
+                assert_eq!(load_balancer, "edge");
+                assert_eq!(backend, "worker");
+            }
+            other => panic!("Expected MissingHealthCheck, got {other:?}"),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Domain events enum for graph domain
 
-use crate::events::{GraphCreated, NodeAdded, NodeRemoved, EdgeAdded, EdgeRemoved};
+use crate::events::{
+    EdgeAdded, EdgeRemoved, EdgeUpdated, GraphCreated, GraphUpdated, HyperEdgeAdded, NodeAdded,
+    NodeMoved, NodeRemoved, NodeUpdated,
+};
 use cim_domain::DomainEvent;
 use serde::{Deserialize, Serialize};
 
@@ -9,44 +12,87 @@ use serde::{Deserialize, Serialize};
 pub enum GraphDomainEvent {
     /// A new graph was created
     GraphCreated(GraphCreated),
+    /// A graph's name, description or metadata was changed
+    GraphUpdated(GraphUpdated),
     /// A node was added to a graph
     NodeAdded(NodeAdded),
     /// A node was removed from a graph
     NodeRemoved(NodeRemoved),
+    /// A node was changed in place
+    NodeUpdated(NodeUpdated),
+    /// A node was given a new position
+    NodeMoved(NodeMoved),
     /// An edge was added between nodes
     EdgeAdded(EdgeAdded),
     /// An edge was removed from the graph
     EdgeRemoved(EdgeRemoved),
+    /// An edge was changed in place
+    EdgeUpdated(EdgeUpdated),
+    /// A hyperedge was added over a group of nodes
+    HyperEdgeAdded(HyperEdgeAdded),
+}
+
+impl GraphDomainEvent {
+    /// When the change recorded by the event happened
+    pub fn occurred_at(&self) -> chrono::DateTime<chrono::Utc> {
+        match self {
+            Self::GraphCreated(e) => e.created_at,
+            Self::GraphUpdated(e) => e.updated_at,
+            Self::NodeAdded(e) => e.created_at,
+            Self::NodeRemoved(e) => e.removed_at,
+            Self::NodeUpdated(e) => e.updated_at,
+            Self::NodeMoved(e) => e.moved_at,
+            Self::EdgeAdded(e) => e.created_at,
+            Self::EdgeRemoved(e) => e.removed_at,
+            Self::EdgeUpdated(e) => e.updated_at,
+            Self::HyperEdgeAdded(e) => e.created_at,
+        }
+    }
 }
 
 impl DomainEvent for GraphDomainEvent {
     fn subject(&self) -> String {
         match self {
             Self::GraphCreated(e) => e.subject(),
+            Self::GraphUpdated(e) => e.subject(),
             Self::NodeAdded(e) => e.subject(),
             Self::NodeRemoved(e) => e.subject(),
+            Self::NodeUpdated(e) => e.subject(),
+            Self::NodeMoved(e) => e.subject(),
             Self::EdgeAdded(e) => e.subject(),
             Self::EdgeRemoved(e) => e.subject(),
+            Self::EdgeUpdated(e) => e.subject(),
+            Self::HyperEdgeAdded(e) => e.subject(),
         }
     }
 
     fn aggregate_id(&self) -> uuid::Uuid {
         match self {
             Self::GraphCreated(e) => e.aggregate_id(),
+            Self::GraphUpdated(e) => e.aggregate_id(),
             Self::NodeAdded(e) => e.aggregate_id(),
             Self::NodeRemoved(e) => e.aggregate_id(),
+            Self::NodeUpdated(e) => e.aggregate_id(),
+            Self::NodeMoved(e) => e.aggregate_id(),
             Self::EdgeAdded(e) => e.aggregate_id(),
             Self::EdgeRemoved(e) => e.aggregate_id(),
+            Self::EdgeUpdated(e) => e.aggregate_id(),
+            Self::HyperEdgeAdded(e) => e.aggregate_id(),
         }
     }
 
     fn event_type(&self) -> &'static str {
         match self {
             Self::GraphCreated(e) => e.event_type(),
+            Self::GraphUpdated(e) => e.event_type(),
             Self::NodeAdded(e) => e.event_type(),
             Self::NodeRemoved(e) => e.event_type(),
+            Self::NodeUpdated(e) => e.event_type(),
+            Self::NodeMoved(e) => e.event_type(),
             Self::EdgeAdded(e) => e.event_type(),
             Self::EdgeRemoved(e) => e.event_type(),
+            Self::EdgeUpdated(e) => e.event_type(),
+            Self::HyperEdgeAdded(e) => e.event_type(),
         }
     }
 }
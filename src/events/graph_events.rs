@@ -36,6 +36,9 @@ pub struct GraphUpdated {
     pub name: Option<String>,
     pub description: Option<String>,
     pub metadata: HashMap<String, serde_json::Value>,
+    /// When the graph was updated (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Graph archived event
@@ -58,6 +61,9 @@ pub struct NodeAdded {
     pub node_type: String,
     /// Additional metadata about the node
     pub metadata: HashMap<String, serde_json::Value>,
+    /// When the node was added (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Node updated event
@@ -67,6 +73,70 @@ pub struct NodeUpdated {
     pub node_id: NodeId,
     pub position: Option<Position3D>,
     pub metadata: HashMap<String, serde_json::Value>,
+    /// New type of the node, if it changed
+    #[serde(default)]
+    pub node_type: Option<String>,
+    /// Metadata keys removed from the node; `metadata` holds only added or changed keys
+    #[serde(default)]
+    pub removed_keys: Vec<String>,
+    /// When the node was updated (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl NodeUpdated {
+    /// Update replacing a node's metadata `old` with `new`, keeping its position and type
+    ///
+    /// Only keys whose value changed are carried, and keys missing from `new` are listed as
+    /// removed, so applying the update to `old` yields `new`.
+    pub fn replacing_metadata(
+        graph_id: GraphId,
+        node_id: NodeId,
+        old: &HashMap<String, serde_json::Value>,
+        new: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        let mut removed_keys: Vec<String> =
+            old.keys().filter(|key| !new.contains_key(*key)).cloned().collect();
+        removed_keys.sort();
+        let metadata = new
+            .into_iter()
+            .filter(|(key, value)| old.get(key) != Some(value))
+            .collect();
+
+        Self {
+            graph_id,
+            node_id,
+            position: None,
+            metadata,
+            node_type: None,
+            removed_keys,
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Apply the metadata part of this update to a node's metadata
+    pub fn apply_metadata(&self, metadata: &mut HashMap<String, serde_json::Value>) {
+        for key in &self.removed_keys {
+            metadata.remove(key);
+        }
+        metadata.extend(self.metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+}
+
+/// Node moved event
+///
+/// Carries presentation state only; the node's metadata is unchanged.
+#[derive(Event, Debug, Clone, Serialize, Deserialize)]
+pub struct NodeMoved {
+    /// The graph containing the node
+    pub graph_id: GraphId,
+    /// The ID of the node that was moved
+    pub node_id: NodeId,
+    /// The new position of the node
+    pub position: Position3D,
+    /// When the node was moved (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub moved_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Node removed event
@@ -76,6 +146,9 @@ pub struct NodeRemoved {
     pub graph_id: GraphId,
     /// The ID of the node that was removed
     pub node_id: NodeId,
+    /// When the node was removed (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub removed_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Edge added event
@@ -95,6 +168,9 @@ pub struct EdgeAdded {
     pub edge_type: String,
     /// Additional metadata about the edge
     pub metadata: HashMap<String, serde_json::Value>,
+    /// When the edge was added (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Edge updated event
@@ -104,6 +180,30 @@ pub struct EdgeUpdated {
     pub edge_id: EdgeId,
     pub relationship: Option<EdgeRelationship>,
     pub metadata: HashMap<String, serde_json::Value>,
+    /// New (source, target) when the edge was redirected, such as by reversal
+    #[serde(default)]
+    pub endpoints: Option<(NodeId, NodeId)>,
+    /// When the edge was updated (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Hyperedge added event
+#[derive(Event, Debug, Clone, Serialize, Deserialize)]
+pub struct HyperEdgeAdded {
+    /// The graph to which the hyperedge was added
+    pub graph_id: GraphId,
+    /// The unique identifier of the hyperedge
+    pub edge_id: EdgeId,
+    /// The nodes connected by the hyperedge
+    pub node_ids: Vec<NodeId>,
+    /// The type of hyperedge (e.g., "join", "fork")
+    pub edge_type: String,
+    /// Additional metadata about the hyperedge
+    pub metadata: HashMap<String, serde_json::Value>,
+    /// When the hyperedge was added (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Edge removed event
@@ -113,6 +213,25 @@ pub struct EdgeRemoved {
     pub graph_id: GraphId,
     /// The ID of the edge that was removed
     pub edge_id: EdgeId,
+    /// The edge as it was before removal, when the emitter captured it
+    #[serde(default)]
+    pub removed: Option<RemovedEdge>,
+    /// When the edge was removed (payloads without it default to the Unix epoch)
+    #[serde(default)]
+    pub removed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// State of an edge at the time it was removed, enough to add it back
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemovedEdge {
+    /// The source node of the edge
+    pub source: NodeId,
+    /// The target node of the edge
+    pub target: NodeId,
+    /// The type of edge
+    pub edge_type: String,
+    /// The edge's metadata
+    pub metadata: HashMap<String, serde_json::Value>,
 }
 
 // Implement DomainEvent trait for all events
@@ -186,6 +305,20 @@ impl DomainEvent for NodeUpdated {
     }
 }
 
+impl DomainEvent for NodeMoved {
+    fn aggregate_id(&self) -> Uuid {
+        self.graph_id.into()
+    }
+
+    fn event_type(&self) -> &'static str {
+        "NodeMoved"
+    }
+
+    fn subject(&self) -> String {
+        "graphs.node.moved.v1".to_string()
+    }
+}
+
 impl DomainEvent for NodeRemoved {
     fn aggregate_id(&self) -> Uuid {
         self.graph_id.into()
@@ -228,6 +361,20 @@ impl DomainEvent for EdgeUpdated {
     }
 }
 
+impl DomainEvent for HyperEdgeAdded {
+    fn aggregate_id(&self) -> Uuid {
+        self.graph_id.into()
+    }
+
+    fn event_type(&self) -> &'static str {
+        "HyperEdgeAdded"
+    }
+
+    fn subject(&self) -> String {
+        "graphs.hyperedge.added.v1".to_string()
+    }
+}
+
 impl DomainEvent for EdgeRemoved {
     fn aggregate_id(&self) -> Uuid {
         self.graph_id.into()
@@ -0,0 +1,372 @@
+//! Graph generators
+//!
+//! Generators produce command sequences that build synthetic graphs for tests and
+//! benchmarks. Node and graph IDs are assigned by the repository when commands are
+//! handled, so generated commands refer to nodes by index: each `AddNode` carries a
+//! `generator_index` metadata entry and each `AddEdge` carries `source_index` and
+//! `target_index`. The `source_id`/`target_id`/`graph_id` fields are placeholders that
+//! [`execute`] replaces with the real IDs, dropping the index entries as it goes.
+
+use crate::{
+    commands::{GraphCommand, GraphCommandError, GraphCommandResult},
+    domain_events::GraphDomainEvent,
+    handlers::GraphCommandHandler,
+    GraphId, NodeId,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+/// Metadata key identifying a generated node
+pub const NODE_INDEX_KEY: &str = "generator_index";
+/// Metadata key identifying the source node of a generated edge
+pub const SOURCE_INDEX_KEY: &str = "source_index";
+/// Metadata key identifying the target node of a generated edge
+pub const TARGET_INDEX_KEY: &str = "target_index";
+
+/// Generate an Erdős–Rényi G(n, p) graph
+///
+/// Each ordered pair of distinct nodes is connected with probability `p`.
+/// The same seed always produces the same graph.
+pub fn erdos_renyi(n: usize, p: f64, seed: u64) -> Vec<GraphCommand> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut builder = CommandBuilder::new("erdos-renyi", n);
+
+    for source in 0..n {
+        for target in 0..n {
+            if source != target && rng.gen::<f64>() < p {
+                builder.add_edge(source, target);
+            }
+        }
+    }
+
+    builder.finish()
+}
+
+/// Generate a Barabási–Albert preferential attachment graph
+///
+/// Starts from a star of `m + 1` nodes; every further node attaches to `m` distinct
+/// existing nodes chosen with probability proportional to their degree.
+/// The same seed always produces the same graph.
+pub fn barabasi_albert(n: usize, m: usize, seed: u64) -> Vec<GraphCommand> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut builder = CommandBuilder::new("barabasi-albert", n);
+
+    if m == 0 || n <= m {
+        return builder.finish();
+    }
+
+    // Initial star: node m is connected to nodes 0..m
+    let mut repeated_nodes: Vec<usize> = Vec::new();
+    for target in 0..m {
+        builder.add_edge(m, target);
+        repeated_nodes.push(target);
+        repeated_nodes.push(m);
+    }
+
+    for source in (m + 1)..n {
+        // Pick m distinct targets, weighted by degree
+        let mut targets: Vec<usize> = Vec::with_capacity(m);
+        while targets.len() < m {
+            let candidate = repeated_nodes[rng.gen_range(0..repeated_nodes.len())];
+            if !targets.contains(&candidate) {
+                targets.push(candidate);
+            }
+        }
+
+        for &target in &targets {
+            builder.add_edge(source, target);
+            repeated_nodes.push(target);
+            repeated_nodes.push(source);
+        }
+    }
+
+    builder.finish()
+}
+
+/// Generate a `rows` × `cols` lattice
+///
+/// Each node is connected to its right and lower neighbours, and carries its grid
+/// coordinates as a `position` metadata entry (x = column, y = row).
+pub fn grid_2d(rows: usize, cols: usize) -> Vec<GraphCommand> {
+    let mut builder = CommandBuilder::new("grid", rows * cols);
+    let index = |row: usize, col: usize| row * cols + col;
+
+    for row in 0..rows {
+        for col in 0..cols {
+            builder.node_metadata_mut(index(row, col)).insert(
+                "position".to_string(),
+                serde_json::json!({ "x": col as f64, "y": row as f64, "z": 0.0 }),
+            );
+
+            if col + 1 < cols {
+                builder.add_edge(index(row, col), index(row, col + 1));
+            }
+            if row + 1 < rows {
+                builder.add_edge(index(row, col), index(row + 1, col));
+            }
+        }
+    }
+
+    builder.finish()
+}
+
+/// Handle generated commands, replacing placeholder IDs with the IDs assigned by the handler
+///
+/// The command sequence must start with `CreateGraph`. The index entries are removed from
+/// the metadata before each command is handled, so they never reach the graph. Returns all
+/// emitted events.
+pub async fn execute(
+    handler: &dyn GraphCommandHandler,
+    commands: Vec<GraphCommand>,
+) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+    let mut graph_id: Option<GraphId> = None;
+    let mut node_ids: HashMap<u64, NodeId> = HashMap::new();
+    let mut all_events = Vec::new();
+
+    let require_graph = |graph_id: Option<GraphId>| {
+        graph_id.ok_or_else(|| {
+            GraphCommandError::InvalidCommand(
+                "Generated commands must start with CreateGraph".to_string(),
+            )
+        })
+    };
+
+    for command in commands {
+        let mut node_index = None;
+        let command = match command {
+            GraphCommand::AddNode {
+                node_type,
+                mut metadata,
+                ..
+            } => {
+                node_index = take_index(&mut metadata, NODE_INDEX_KEY);
+                GraphCommand::AddNode {
+                    graph_id: require_graph(graph_id)?,
+                    node_type,
+                    metadata,
+                }
+            }
+            GraphCommand::AddEdge {
+                edge_type,
+                mut metadata,
+                relationship,
+                ..
+            } => {
+                let mut resolve = |key: &str| {
+                    take_index(&mut metadata, key)
+                        .and_then(|index| node_ids.get(&index).copied())
+                        .ok_or_else(|| {
+                            GraphCommandError::InvalidCommand(format!(
+                                "Generated edge has no resolvable {key}"
+                            ))
+                        })
+                };
+                let source_id = resolve(SOURCE_INDEX_KEY)?;
+                let target_id = resolve(TARGET_INDEX_KEY)?;
+                GraphCommand::AddEdge {
+                    graph_id: require_graph(graph_id)?,
+                    source_id,
+                    target_id,
+                    edge_type,
+                    metadata,
+                    relationship,
+                }
+            }
+            other => other,
+        };
+
+        let events = handler.handle_graph_command(command).await?;
+        for event in &events {
+            match event {
+                GraphDomainEvent::GraphCreated(e) => graph_id = Some(e.graph_id),
+                GraphDomainEvent::NodeAdded(e) => {
+                    if let Some(index) = node_index.take() {
+                        node_ids.insert(index, e.node_id);
+                    }
+                }
+                _ => {}
+            }
+        }
+        all_events.extend(events);
+    }
+
+    Ok(all_events)
+}
+
+/// Remove an index entry from command metadata, returning the index
+fn take_index(metadata: &mut HashMap<String, serde_json::Value>, key: &str) -> Option<u64> {
+    metadata.remove(key).and_then(|value| value.as_u64())
+}
+
+/// Accumulates the commands for a generated graph
+struct CommandBuilder {
+    placeholder_graph: GraphId,
+    placeholder_nodes: Vec<NodeId>,
+    commands: Vec<GraphCommand>,
+}
+
+impl CommandBuilder {
+    /// Start a graph with `n` nodes
+    fn new(kind: &str, n: usize) -> Self {
+        let placeholder_graph = GraphId::new();
+        let mut commands = vec![GraphCommand::CreateGraph {
+            name: format!("{kind} ({n} nodes)"),
+            description: format!("Generated {kind} graph"),
+            metadata: HashMap::from([("generator".to_string(), serde_json::json!(kind))]),
+        }];
+
+        let placeholder_nodes: Vec<NodeId> = (0..n).map(|_| NodeId::new()).collect();
+        for index in 0..n {
+            commands.push(GraphCommand::AddNode {
+                graph_id: placeholder_graph,
+                node_type: "generated".to_string(),
+                metadata: HashMap::from([(NODE_INDEX_KEY.to_string(), serde_json::json!(index))]),
+            });
+        }
+
+        Self {
+            placeholder_graph,
+            placeholder_nodes,
+            commands,
+        }
+    }
+
+    /// Metadata of a generated node
+    fn node_metadata_mut(&mut self, index: usize) -> &mut HashMap<String, serde_json::Value> {
+        // The CreateGraph command precedes the AddNode commands
+        match &mut self.commands[index + 1] {
+            GraphCommand::AddNode { metadata, .. } => metadata,
+            _ => unreachable!("generated nodes follow CreateGraph"),
+        }
+    }
+
+    /// Connect two generated nodes by index
+    fn add_edge(&mut self, source: usize, target: usize) {
+        self.commands.push(GraphCommand::AddEdge {
+            graph_id: self.placeholder_graph,
+            source_id: self.placeholder_nodes[source],
+            target_id: self.placeholder_nodes[target],
+            edge_type: "generated".to_string(),
+            metadata: HashMap::from([
+                (SOURCE_INDEX_KEY.to_string(), serde_json::json!(source)),
+                (TARGET_INDEX_KEY.to_string(), serde_json::json!(target)),
+            ]),
+            relationship: None,
+        });
+    }
+
+    fn finish(self) -> Vec<GraphCommand> {
+        self.commands
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::{GraphCommandHandlerImpl, GraphRepository, InMemoryGraphRepository};
+    use std::sync::Arc;
+
+    fn index_of(metadata: &HashMap<String, serde_json::Value>, key: &str) -> Option<u64> {
+        metadata.get(key).and_then(|value| value.as_u64())
+    }
+
+    /// Edges of a generated graph as (source index, target index) pairs
+    fn edge_indices(commands: &[GraphCommand]) -> Vec<(u64, u64)> {
+        commands
+            .iter()
+            .filter_map(|command| match command {
+                GraphCommand::AddEdge { metadata, .. } => Some((
+                    index_of(metadata, SOURCE_INDEX_KEY).unwrap(),
+                    index_of(metadata, TARGET_INDEX_KEY).unwrap(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn node_count(commands: &[GraphCommand]) -> usize {
+        commands
+            .iter()
+            .filter(|command| matches!(command, GraphCommand::AddNode { .. }))
+            .count()
+    }
+
+    #[test]
+    fn test_erdos_renyi_is_reproducible() {
+        let first = erdos_renyi(100, 0.5, 42);
+        let second = erdos_renyi(100, 0.5, 42);
+
+        assert_eq!(node_count(&first), 100);
+        assert_eq!(edge_indices(&first), edge_indices(&second));
+        assert!(!edge_indices(&first).is_empty());
+
+        let other_seed = erdos_renyi(100, 0.5, 43);
+        assert_ne!(edge_indices(&first), edge_indices(&other_seed));
+    }
+
+    #[test]
+    fn test_barabasi_albert_edge_count() {
+        let commands = barabasi_albert(50, 3, 7);
+
+        assert_eq!(node_count(&commands), 50);
+        // Initial star has m edges, every later node adds m more
+        assert_eq!(edge_indices(&commands).len(), 3 + 3 * (50 - 4));
+        assert_eq!(edge_indices(&commands), edge_indices(&barabasi_albert(50, 3, 7)));
+    }
+
+    #[test]
+    fn test_grid_2d() {
+        let commands = grid_2d(3, 3);
+        let edges = edge_indices(&commands);
+
+        assert_eq!(node_count(&commands), 9);
+        assert_eq!(edges.len(), 12);
+
+        let degree = |node: u64| {
+            edges
+                .iter()
+                .filter(|(source, target)| *source == node || *target == node)
+                .count()
+        };
+        for corner in [0, 2, 6, 8] {
+            assert_eq!(degree(corner), 2);
+        }
+        assert_eq!(degree(4), 4);
+
+        // Node 5 sits at row 1, column 2
+        let position = commands
+            .iter()
+            .find_map(|command| match command {
+                GraphCommand::AddNode { metadata, .. }
+                    if index_of(metadata, NODE_INDEX_KEY) == Some(5) =>
+                {
+                    metadata.get("position").cloned()
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(position["x"], 2.0);
+        assert_eq!(position["y"], 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_execute_generated_commands() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let events = execute(&handler, barabasi_albert(20, 2, 1)).await.unwrap();
+
+        let graph_id = match &events[0] {
+            GraphDomainEvent::GraphCreated(e) => e.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+        let graph = repository.load(graph_id).await.unwrap();
+        assert_eq!(graph.node_count(), 20);
+        assert_eq!(graph.edge_count(), 2 + 2 * (20 - 3));
+
+        // The index entries are only used to wire up the commands
+        assert!(graph.nodes().values().all(|node| node.metadata.is_empty()));
+        assert!(graph.edges().values().all(|edge| edge.metadata.is_empty()));
+    }
+}
@@ -0,0 +1,179 @@
+//! Node groups
+//!
+//! A group gathers nodes that can be collapsed into a single compound node. Membership is
+//! stored in node metadata under the reserved [`GROUP_KEY`], so a node belongs to at most one
+//! group and adding it to another group moves it.
+
+use crate::queries::{EdgeInfo, GraphStructure, NodeInfo};
+use crate::NodeId;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Metadata key holding the id of a node's group
+pub const GROUP_KEY: &str = "group";
+
+/// Node type of the compound node standing in for a collapsed group
+pub const GROUP_NODE_TYPE: &str = "group";
+
+/// Identifier of a node group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GroupId(Uuid);
+
+impl GroupId {
+    /// Create a new random group id
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for GroupId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for GroupId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Group stored in node metadata, if any
+pub fn node_group(metadata: &HashMap<String, serde_json::Value>) -> Option<GroupId> {
+    metadata
+        .get(GROUP_KEY)
+        .and_then(|value| value.as_str())
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .map(GroupId)
+}
+
+/// Metadata entry assigning a node to `group_id`
+pub fn group_metadata(group_id: GroupId) -> HashMap<String, serde_json::Value> {
+    HashMap::from([(GROUP_KEY.to_string(), serde_json::json!(group_id.to_string()))])
+}
+
+/// Replace the members of `group_id` with a single compound node
+///
+/// Edges between members are dropped and edges crossing the group boundary are reattached
+/// to the compound node, keeping their ids. Returns `None` if the group has no members.
+pub fn collapse_group(structure: &GraphStructure, group_id: GroupId) -> Option<GraphStructure> {
+    let members: HashSet<NodeId> = structure
+        .nodes
+        .iter()
+        .filter(|node| node_group(&node.metadata) == Some(group_id))
+        .map(|node| node.node_id)
+        .collect();
+    let first = structure
+        .nodes
+        .iter()
+        .find(|node| members.contains(&node.node_id))?;
+
+    let group_node = NodeInfo::new(NodeId::new(), first.graph_id, GROUP_NODE_TYPE)
+        .with_metadata(group_metadata(group_id))
+        .with_created_at(first.created_at);
+    let outside = |node_id: &NodeId| {
+        if members.contains(node_id) {
+            group_node.node_id
+        } else {
+            *node_id
+        }
+    };
+
+    let edges: Vec<EdgeInfo> = structure
+        .edges
+        .iter()
+        .filter(|edge| !(members.contains(&edge.source_id) && members.contains(&edge.target_id)))
+        .map(|edge| EdgeInfo {
+            source_id: outside(&edge.source_id),
+            target_id: outside(&edge.target_id),
+            ..edge.clone()
+        })
+        .collect();
+
+    let mut adjacency_list: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+    for edge in &edges {
+        adjacency_list
+            .entry(edge.source_id)
+            .or_default()
+            .push(edge.target_id);
+    }
+
+    let mut nodes: Vec<NodeInfo> = structure
+        .nodes
+        .iter()
+        .filter(|node| !members.contains(&node.node_id))
+        .cloned()
+        .collect();
+    nodes.push(group_node);
+
+    Some(GraphStructure {
+        nodes,
+        edges,
+        adjacency_list,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EdgeId, GraphId};
+
+    #[test]
+    fn test_collapse_group_keeps_external_edges() {
+        let graph_id = GraphId::new();
+        let group_id = GroupId::new();
+        let node = |grouped: bool| {
+            let node = NodeInfo::new(NodeId::new(), graph_id, "task");
+            if grouped {
+                node.with_metadata(group_metadata(group_id))
+            } else {
+                node
+            }
+        };
+        let edge = |source: &NodeInfo, target: &NodeInfo| {
+            EdgeInfo::new(EdgeId::new(), graph_id, source.node_id, target.node_id, "sequence")
+        };
+
+        let (a, b, c) = (node(true), node(true), node(true));
+        let (before, after) = (node(false), node(false));
+        let edges = vec![
+            edge(&before, &a),
+            edge(&a, &b),
+            edge(&b, &c),
+            edge(&c, &after),
+            edge(&before, &after),
+        ];
+        let structure = GraphStructure {
+            nodes: vec![before.clone(), a, b, c, after.clone()],
+            edges,
+            adjacency_list: HashMap::new(),
+        };
+
+        let collapsed = collapse_group(&structure, group_id).unwrap();
+        assert_eq!(collapsed.nodes.len(), 3);
+        let group_node = collapsed
+            .nodes
+            .iter()
+            .find(|node| node.node_type == GROUP_NODE_TYPE)
+            .unwrap();
+        assert_eq!(node_group(&group_node.metadata), Some(group_id));
+
+        let connections: HashSet<(NodeId, NodeId)> = collapsed
+            .edges
+            .iter()
+            .map(|edge| (edge.source_id, edge.target_id))
+            .collect();
+        assert_eq!(
+            connections,
+            HashSet::from([
+                (before.node_id, group_node.node_id),
+                (group_node.node_id, after.node_id),
+                (before.node_id, after.node_id),
+            ])
+        );
+        assert_eq!(collapsed.adjacency_list[&before.node_id].len(), 2);
+
+        assert!(collapse_group(&structure, GroupId::new()).is_none());
+    }
+}
@@ -69,6 +69,12 @@ impl AbstractGraphEventHandler {
                 self.repository.save_graph(&graph).await?;
             }
 
+            GraphDomainEvent::GraphUpdated(e) => {
+                // Graph implementations keep the name they were created with; only check the
+                // graph exists
+                self.load_or_error(e.graph_id).await?;
+            }
+
             GraphDomainEvent::NodeAdded(e) => {
                 let mut graph = self.load_or_error(e.graph_id).await?;
 
@@ -99,6 +105,30 @@ impl AbstractGraphEventHandler {
                 self.repository.save_graph(&graph).await?;
             }
 
+            GraphDomainEvent::NodeUpdated(e) => {
+                // Only metadata is stored again; type changes are not kept by implementations
+                let mut graph = self.load_or_error(e.graph_id).await?;
+
+                let mut node = graph
+                    .get_node(e.node_id)
+                    .map_err(|err| format!("Failed to update node: {err:?}"))?;
+                e.apply_metadata(&mut node.metadata);
+                graph
+                    .replace_node_metadata(e.node_id, node.metadata)
+                    .map_err(|err| format!("Failed to update node: {err:?}"))?;
+
+                self.repository.save_graph(&graph).await?;
+            }
+
+            GraphDomainEvent::NodeMoved(e) => {
+                // Positions are presentation state; only check the node exists
+                let graph = self.load_or_error(e.graph_id).await?;
+
+                graph
+                    .get_node(e.node_id)
+                    .map_err(|err| format!("Failed to move node: {err:?}"))?;
+            }
+
             GraphDomainEvent::EdgeAdded(e) => {
                 let mut graph = self.load_or_error(e.graph_id).await?;
 
@@ -123,6 +153,30 @@ impl AbstractGraphEventHandler {
 
                 self.repository.save_graph(&graph).await?;
             }
+
+            GraphDomainEvent::EdgeUpdated(e) => {
+                // Graph implementations cannot change edges in place; only check the edge exists
+                let graph = self.load_or_error(e.graph_id).await?;
+
+                if !graph.contains_edge(e.edge_id) {
+                    return Err(format!("Failed to update edge: {:?}", e.edge_id));
+                }
+            }
+
+            GraphDomainEvent::HyperEdgeAdded(e) => {
+                let mut graph = self.load_or_error(e.graph_id).await?;
+
+                graph
+                    .add_hyperedge(
+                        e.edge_id,
+                        e.node_ids.clone(),
+                        e.edge_type.clone(),
+                        e.metadata.clone(),
+                    )
+                    .map_err(|err| format!("Failed to add hyperedge: {err:?}"))?;
+
+                self.repository.save_graph(&graph).await?;
+            }
         }
 
         Ok(())
@@ -251,6 +305,7 @@ mod tests {
             position: Position3D::new(1.0, 2.0, 3.0),
             node_type: "test_node".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
         handler.handle_event(&add_event).await.unwrap();
 
@@ -262,7 +317,11 @@ mod tests {
         assert_eq!(node_data.position.x, 1.0);
 
         // Remove node
-        let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id });
+        let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved {
+            graph_id,
+            node_id,
+            removed_at: chrono::Utc::now(),
+        });
         handler.handle_event(&remove_event).await.unwrap();
 
         // Verify node was not actually removed (AbstractGraph doesn't support removal)
@@ -297,6 +356,7 @@ mod tests {
             position: Position3D::default(),
             node_type: "node1".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
         handler.handle_event(&add_node1).await.unwrap();
 
@@ -306,6 +366,7 @@ mod tests {
             position: Position3D::default(),
             node_type: "node2".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
         handler.handle_event(&add_node2).await.unwrap();
 
@@ -320,6 +381,7 @@ mod tests {
             },
             edge_type: "test_edge".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
         handler.handle_event(&add_edge).await.unwrap();
 
@@ -346,6 +408,7 @@ mod tests {
             position: Position3D::default(),
             node_type: "test".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
 
         let result = handler.handle_event(&add_event).await;
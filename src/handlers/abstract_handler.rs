@@ -3,10 +3,17 @@
 use crate::{
     abstraction::{EdgeData, GraphType, NodeData, Position3D},
     aggregate::abstract_graph::AbstractGraph,
-    commands::{GraphCommand, GraphCommandError, GraphCommandResult},
+    commands::{
+        contracted_metadata, group_member_failures, metadata_failures, split_failures,
+        split_moves, structure_failures, GraphCommand, GraphCommandError, GraphCommandResult,
+        SPLIT_EDGE_TYPE,
+    },
     domain_events::GraphDomainEvent,
-    events::{EdgeAdded, EdgeRemoved, GraphCreated, NodeAdded, NodeRemoved},
-    EdgeId, GraphId, NodeId,
+    events::{
+        EdgeAdded, EdgeRemoved, EdgeUpdated, GraphCreated, GraphUpdated, HyperEdgeAdded,
+        NodeAdded, NodeMoved, NodeRemoved, NodeUpdated,
+    },
+    groups, tags, EdgeId, GraphId, NodeId,
 };
 use async_trait::async_trait;
 use cim_domain::CommandEnvelope;
@@ -45,6 +52,36 @@ impl AbstractGraphCommandHandler {
         Self { repository }
     }
 
+    /// Replace a node's metadata, emitting a NodeUpdated with the changed and removed keys
+    async fn replace_node_metadata(
+        &self,
+        graph_id: GraphId,
+        node_id: NodeId,
+        new_metadata: std::collections::HashMap<String, serde_json::Value>,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        // Load graph
+        let mut graph = self.repository.load(graph_id).await?;
+
+        // Get old node data
+        let old_data = graph.get_node(node_id)?;
+
+        // Update node in place, keeping its hyperedges
+        graph.replace_node_metadata(node_id, new_metadata.clone())?;
+
+        // Save graph
+        self.repository.save(&graph).await?;
+
+        // Generate event
+        let event = GraphDomainEvent::NodeUpdated(NodeUpdated::replacing_metadata(
+            graph_id,
+            node_id,
+            &old_data.metadata,
+            new_metadata,
+        ));
+
+        Ok(vec![event])
+    }
+
     /// Process a graph command
     pub async fn process_graph_command(
         &self,
@@ -60,12 +97,13 @@ impl AbstractGraphCommandHandler {
                 let graph_id = self.repository.next_graph_id().await?;
                 let created_at = chrono::Utc::now();
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if name.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Graph name cannot be empty".to_string(),
-                    ));
+                    failures.push("Graph name cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Determine graph type from metadata
                 let graph_type = metadata
@@ -76,6 +114,7 @@ impl AbstractGraphCommandHandler {
                         "concept" => Some(GraphType::new_concept(graph_id, &name)),
                         "workflow" => Some(GraphType::new_workflow(graph_id, &name)),
                         "ipld" => Some(GraphType::new_ipld(graph_id)),
+                        "generic" => Some(GraphType::new_generic(graph_id, &name)),
                         _ => None,
                     })
                     .unwrap_or_else(|| GraphType::new_context(graph_id, &name));
@@ -108,12 +147,13 @@ impl AbstractGraphCommandHandler {
                 let mut graph = self.repository.load(graph_id).await?;
                 let node_id = self.repository.next_node_id().await?;
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if node_type.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Node type cannot be empty".to_string(),
-                    ));
+                    failures.push("Node type cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Extract position from metadata
                 let position = metadata
@@ -146,6 +186,7 @@ impl AbstractGraphCommandHandler {
                     position: crate::value_objects::Position3D::default(),
                     node_type,
                     metadata,
+                    created_at: chrono::Utc::now(),
                 });
 
                 Ok(vec![event])
@@ -162,7 +203,11 @@ impl AbstractGraphCommandHandler {
                 self.repository.save(&graph).await?;
 
                 // Generate event
-                let event = GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id });
+                let event = GraphDomainEvent::NodeRemoved(NodeRemoved {
+                    graph_id,
+                    node_id,
+                    removed_at: chrono::Utc::now(),
+                });
 
                 Ok(vec![event])
             }
@@ -173,17 +218,19 @@ impl AbstractGraphCommandHandler {
                 target_id,
                 edge_type,
                 metadata,
+                relationship,
             } => {
                 // Load graph
                 let mut graph = self.repository.load(graph_id).await?;
                 let edge_id = self.repository.next_edge_id().await?;
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if edge_type.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Edge type cannot be empty".to_string(),
-                    ));
+                    failures.push("Edge type cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Create edge data
                 let edge_data = EdgeData {
@@ -203,11 +250,12 @@ impl AbstractGraphCommandHandler {
                     edge_id,
                     source: source_id,
                     target: target_id,
-                    relationship: crate::components::EdgeRelationship::Association {
-                        association_type: edge_type.clone(),
-                    },
+                    relationship: relationship.unwrap_or_else(|| {
+                        crate::components::EdgeRelationship::association(&edge_type)
+                    }),
                     edge_type,
                     metadata,
+                    created_at: chrono::Utc::now(),
                 });
 
                 Ok(vec![event])
@@ -224,47 +272,506 @@ impl AbstractGraphCommandHandler {
                 self.repository.save(&graph).await?;
 
                 // Generate event
-                let event = GraphDomainEvent::EdgeRemoved(EdgeRemoved { graph_id, edge_id });
+                let event = GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+                    graph_id,
+                    edge_id,
+                    removed: None,
+                    removed_at: chrono::Utc::now(),
+                });
 
                 Ok(vec![event])
             }
 
-            GraphCommand::ChangeNodeMetadata {
+            GraphCommand::SetEdgeWeight {
+                graph_id,
+                edge_id,
+                weight,
+            } => {
+                // Validate input
+                if weight.is_nan() || weight < 0.0 {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Edge weight must be non-negative, got {weight}"
+                    )));
+                }
+
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Store the weight in the edge's metadata
+                let (mut edge, source, target) = graph.get_edge(edge_id)?;
+                edge.metadata.insert("weight".to_string(), serde_json::json!(weight));
+                graph.add_edge(edge_id, source, target, edge)?;
+
+                // Save graph
+                self.repository.save(&graph).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+                    graph_id,
+                    edge_id,
+                    relationship: None,
+                    metadata: std::collections::HashMap::from([(
+                        "weight".to_string(),
+                        serde_json::json!(weight),
+                    )]),
+                    endpoints: None,
+                    updated_at: chrono::Utc::now(),
+                });
+
+                Ok(vec![event])
+            }
+
+            GraphCommand::SetNodePosition {
                 graph_id,
                 node_id,
-                new_metadata,
+                position,
             } => {
+                // Validate input
+                if ![position.x, position.y, position.z].iter().all(|v| v.is_finite()) {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Node position must be finite, got {position:?}"
+                    )));
+                }
+
+                // Load graph and check the node exists
+                let graph = self.repository.load(graph_id).await?;
+                graph.get_node(node_id)?;
+
+                // Generate event
+                let event = GraphDomainEvent::NodeMoved(NodeMoved {
+                    graph_id,
+                    node_id,
+                    position,
+                    moved_at: chrono::Utc::now(),
+                });
+
+                Ok(vec![event])
+            }
+
+            GraphCommand::RepairGraph { graph_id } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Remove every edge left pointing at a missing node
+                let orphaned = graph.orphaned_edges();
+                if orphaned.is_empty() {
+                    return Ok(Vec::new());
+                }
+                for edge_id in &orphaned {
+                    graph.remove_edge(*edge_id)?;
+                }
+
+                // Save graph
+                self.repository.save(&graph).await?;
+
+                // Generate events
+                Ok(orphaned
+                    .into_iter()
+                    .map(|edge_id| GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+                        graph_id,
+                        edge_id,
+                        removed: None,
+                        removed_at: chrono::Utc::now(),
+                    }))
+                    .collect())
+            }
+
+            GraphCommand::CreateGroup {
+                graph_id,
+                group_id,
+                member_nodes,
+            } => {
+                // Validate input
+                GraphCommandError::from_failures(group_member_failures(&member_nodes))?;
+
                 // Load graph
                 let mut graph = self.repository.load(graph_id).await?;
 
-                // Get old node data
-                let old_data = graph.get_node(node_id)?;
+                // Record membership in each member's metadata
+                let membership = groups::group_metadata(group_id);
+                for node_id in &member_nodes {
+                    let mut metadata = graph.get_node(*node_id)?.metadata;
+                    metadata.extend(membership.clone());
+                    graph.replace_node_metadata(*node_id, metadata)?;
+                }
+
+                // Save graph
+                self.repository.save(&graph).await?;
+
+                // Generate events
+                Ok(member_nodes
+                    .into_iter()
+                    .map(|node_id| {
+                        GraphDomainEvent::NodeUpdated(NodeUpdated {
+                            graph_id,
+                            node_id,
+                            position: None,
+                            metadata: membership.clone(),
+                            node_type: None,
+                            removed_keys: Vec::new(),
+                            updated_at: chrono::Utc::now(),
+                        })
+                    })
+                    .collect())
+            }
+
+            GraphCommand::ReverseEdges { graph_id } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
 
-                // Create new node data with updated metadata
-                let new_data = NodeData {
-                    node_type: old_data.node_type.clone(),
-                    position: old_data.position,
-                    metadata: new_metadata.clone(),
+                // Flip every edge except self-loops
+                let reversed: Vec<(EdgeId, NodeId, NodeId)> = graph
+                    .list_edges()
+                    .into_iter()
+                    .filter(|(_, _, source, target)| source != target)
+                    .map(|(edge_id, _, source, target)| (edge_id, target, source))
+                    .collect();
+                for (edge_id, source, target) in &reversed {
+                    graph.reconnect_edge(*edge_id, *source, *target)?;
+                }
+
+                // Save graph
+                self.repository.save(&graph).await?;
+
+                // Generate events
+                Ok(reversed
+                    .into_iter()
+                    .map(|(edge_id, source, target)| {
+                        GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+                            graph_id,
+                            edge_id,
+                            relationship: None,
+                            metadata: std::collections::HashMap::new(),
+                            endpoints: Some((source, target)),
+                            updated_at: chrono::Utc::now(),
+                        })
+                    })
+                    .collect())
+            }
+
+            GraphCommand::SplitNode {
+                graph_id,
+                node_id,
+                incoming_to_first,
+                outgoing_to_second,
+            } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+                let node = graph.get_node(node_id)?;
+
+                // Validate input, reporting every failure together
+                GraphCommandError::from_failures(split_failures(
+                    node_id,
+                    &incoming_to_first,
+                    &outgoing_to_second,
+                    |edge_id| graph.get_edge_endpoints(edge_id).ok(),
+                ))?;
+
+                // Add the second half and its link, and move the edges it takes over
+                let second_id = self.repository.next_node_id().await?;
+                let link_edge_id = self.repository.next_edge_id().await?;
+                let edges = graph.list_edges().into_iter();
+                let moved = split_moves(
+                    node_id,
+                    second_id,
+                    &incoming_to_first,
+                    &outgoing_to_second,
+                    edges.map(|(edge_id, _, source, target)| (edge_id, source, target)),
+                );
+                graph.add_node(second_id, node.clone())?;
+                graph.add_edge(
+                    link_edge_id,
+                    node_id,
+                    second_id,
+                    EdgeData {
+                        edge_type: SPLIT_EDGE_TYPE.to_string(),
+                        metadata: std::collections::HashMap::new(),
+                    },
+                )?;
+                for (edge_id, source, target) in &moved {
+                    graph.reconnect_edge(*edge_id, *source, *target)?;
+                }
+
+                // Save graph
+                self.repository.save(&graph).await?;
+
+                // Generate events
+                let second = NodeAdded {
+                    graph_id,
+                    node_id: second_id,
+                    position: node.position,
+                    node_type: node.node_type,
+                    metadata: node.metadata,
+                    created_at: chrono::Utc::now(),
                 };
+                Ok(super::split_events(
+                    graph_id,
+                    node_id,
+                    second,
+                    &moved,
+                    link_edge_id,
+                    chrono::Utc::now(),
+                ))
+            }
 
-                // Update node (remove old, add new)
-                graph.remove_node(node_id)?;
-                graph.add_node(node_id, new_data)?;
+            GraphCommand::ContractEdge {
+                graph_id,
+                edge_id,
+                metadata_merge,
+            } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+                let (kept, merged) = graph.get_edge_endpoints(edge_id)?;
+                if kept == merged {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Edge {edge_id} is a self-loop and cannot be contracted"
+                    )));
+                }
+
+                // Combine the endpoints' metadata
+                let kept_metadata = graph.get_node(kept)?.metadata;
+                let metadata = contracted_metadata(
+                    metadata_merge,
+                    &kept_metadata,
+                    &graph.get_node(merged)?.metadata,
+                )?;
+
+                // Move the merged node's other edges onto the kept node, then drop it
+                let rewire = |node_id: NodeId| if node_id == merged { kept } else { node_id };
+                let moved: Vec<(EdgeId, NodeId, NodeId)> = graph
+                    .list_edges()
+                    .into_iter()
+                    .filter(|(id, _, source, target)| {
+                        *id != edge_id && (*source == merged || *target == merged)
+                    })
+                    .map(|(id, _, source, target)| (id, rewire(source), rewire(target)))
+                    .collect();
+                for (id, source, target) in &moved {
+                    graph.reconnect_edge(*id, *source, *target)?;
+                }
+                graph.remove_edge(edge_id)?;
+                graph.remove_node(merged)?;
+                let changed = (metadata != kept_metadata).then_some(metadata);
+                if let Some(metadata) = &changed {
+                    graph.replace_node_metadata(kept, metadata.clone())?;
+                }
 
                 // Save graph
                 self.repository.save(&graph).await?;
 
                 // Generate events
-                let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id });
-                let add_event = GraphDomainEvent::NodeAdded(NodeAdded {
+                let contracted = GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+                    graph_id,
+                    edge_id,
+                    removed: None,
+                    removed_at: chrono::Utc::now(),
+                });
+                Ok(super::contract_events(
+                    graph_id,
+                    contracted,
+                    &moved,
+                    merged,
+                    kept,
+                    changed,
+                    chrono::Utc::now(),
+                ))
+            }
+
+            GraphCommand::ImportStructure {
+                graph_id,
+                structure,
+            } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Validate the whole structure before touching the graph
+                let existing = graph.list_nodes().into_iter().map(|(id, _)| id).collect();
+                GraphCommandError::from_failures(structure_failures(&structure, &existing))?;
+
+                // Add nodes, then the edges between them
+                for node in &structure.nodes {
+                    let node_data = NodeData {
+                        node_type: node.node_type.clone(),
+                        position: node
+                            .position_3d
+                            .or_else(|| node.position_2d.map(Position3D::from))
+                            .unwrap_or_default(),
+                        metadata: node.metadata.clone(),
+                    };
+                    graph.add_node(node.node_id, node_data)?;
+                }
+                for edge in &structure.edges {
+                    let edge_data = EdgeData {
+                        edge_type: edge.edge_type.clone(),
+                        metadata: edge.metadata.clone(),
+                    };
+                    graph.add_edge(edge.edge_id, edge.source_id, edge.target_id, edge_data)?;
+                }
+
+                // Save graph once for the whole import
+                self.repository.save(&graph).await?;
+
+                Ok(super::import_events(graph_id, &structure, chrono::Utc::now()))
+            }
+
+            GraphCommand::ChangeNodeMetadata {
+                graph_id,
+                node_id,
+                new_metadata,
+            } => {
+                self.replace_node_metadata(graph_id, node_id, new_metadata).await
+            }
+
+            GraphCommand::MergeNodeMetadata {
+                graph_id,
+                node_id,
+                metadata,
+            } => {
+                let graph = self.repository.load(graph_id).await?;
+                let mut merged = graph.get_node(node_id)?.metadata;
+                merged.extend(metadata);
+                self.replace_node_metadata(graph_id, node_id, merged).await
+            }
+
+            GraphCommand::TagNode {
+                graph_id,
+                node_id,
+                tag,
+            } => {
+                let graph = self.repository.load(graph_id).await?;
+                let node = graph.get_node(node_id)?;
+
+                // Already tagged nodes produce no events
+                match tags::with_tag(&node.metadata, &tag)? {
+                    Some(new_metadata) => {
+                        self.replace_node_metadata(graph_id, node_id, new_metadata).await
+                    }
+                    None => Ok(Vec::new()),
+                }
+            }
+
+            GraphCommand::UntagNode {
+                graph_id,
+                node_id,
+                tag,
+            } => {
+                let graph = self.repository.load(graph_id).await?;
+                let node = graph.get_node(node_id)?;
+
+                match tags::without_tag(&node.metadata, &tag)? {
+                    Some(new_metadata) => {
+                        self.replace_node_metadata(graph_id, node_id, new_metadata).await
+                    }
+                    None => Ok(Vec::new()),
+                }
+            }
+
+            GraphCommand::RelabelNodeType {
+                graph_id,
+                node_id,
+                new_node_type,
+            } => {
+                // Validate input
+                if new_node_type.trim().is_empty() {
+                    return Err(GraphCommandError::InvalidCommand(
+                        "Node type cannot be empty".to_string(),
+                    ));
+                }
+
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Store the node again under its new type
+                let mut node = graph.get_node(node_id)?;
+                node.node_type = new_node_type.clone();
+                graph.add_node(node_id, node)?;
+
+                // Save graph
+                self.repository.save(&graph).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::NodeUpdated(NodeUpdated {
                     graph_id,
                     node_id,
-                    position: crate::value_objects::Position3D::default(),
-                    node_type: old_data.node_type,
-                    metadata: new_metadata,
+                    position: None,
+                    metadata: std::collections::HashMap::new(),
+                    node_type: Some(new_node_type),
+                    removed_keys: Vec::new(),
+                    updated_at: chrono::Utc::now(),
                 });
 
-                Ok(vec![remove_event, add_event])
+                Ok(vec![event])
+            }
+
+            GraphCommand::RenameGraph { graph_id, new_name } => {
+                // Validate input
+                if new_name.trim().is_empty() {
+                    return Err(GraphCommandError::InvalidCommand(
+                        "Graph name cannot be empty".to_string(),
+                    ));
+                }
+
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Rename graph
+                graph.rename(new_name.clone())?;
+
+                // Save graph
+                self.repository.save(&graph).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::GraphUpdated(GraphUpdated {
+                    graph_id,
+                    name: Some(new_name),
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                    updated_at: chrono::Utc::now(),
+                });
+
+                Ok(vec![event])
+            }
+
+            GraphCommand::AddHyperEdge {
+                graph_id,
+                node_ids,
+                edge_type,
+                metadata,
+            } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+                let edge_id = self.repository.next_edge_id().await?;
+
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
+                if edge_type.trim().is_empty() {
+                    failures.push("Edge type cannot be empty".to_string());
+                }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
+
+                // Add hyperedge to graph
+                graph.add_hyperedge(
+                    edge_id,
+                    node_ids.clone(),
+                    edge_type.clone(),
+                    metadata.clone(),
+                )?;
+
+                // Save graph
+                self.repository.save(&graph).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::HyperEdgeAdded(HyperEdgeAdded {
+                    graph_id,
+                    edge_id,
+                    node_ids,
+                    edge_type,
+                    metadata,
+                    created_at: chrono::Utc::now(),
+                });
+
+                Ok(vec![event])
             }
         }
     }
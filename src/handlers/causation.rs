@@ -0,0 +1,88 @@
+//! Correlation and causation tracking across command → event → command flows
+//!
+//! Every command and event gets its own id. Events carry the id of the command that
+//! produced them as their causation id, and commands issued in reaction to an event
+//! carry that event's id. All messages in one flow share the correlation id of the
+//! command that started it, so the chain can be reconstructed for auditing. The events of
+//! a single command also share a correlation group, so consumers can apply them as one unit.
+
+use crate::{commands::GraphCommand, domain_events::GraphDomainEvent};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A graph command with its place in a causation chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CausedCommand {
+    /// Unique id of this command
+    pub command_id: Uuid,
+    /// Id shared by every message in the flow
+    pub correlation_id: Uuid,
+    /// Id of the event that triggered this command, if any
+    pub causation_id: Option<Uuid>,
+    /// The command to handle
+    pub command: GraphCommand,
+}
+
+impl CausedCommand {
+    /// Start a new flow; the command is its own correlation root
+    pub fn new(command: GraphCommand) -> Self {
+        let command_id = Uuid::new_v4();
+        Self {
+            command_id,
+            correlation_id: command_id,
+            causation_id: None,
+            command,
+        }
+    }
+
+    /// Continue the flow of `cause` with a command triggered by that event
+    pub fn caused_by(command: GraphCommand, cause: &CausedEvent) -> Self {
+        Self {
+            command_id: Uuid::new_v4(),
+            correlation_id: cause.correlation_id,
+            causation_id: Some(cause.event_id),
+            command,
+        }
+    }
+}
+
+/// A graph event with its place in a causation chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CausedEvent {
+    /// Unique id of this event
+    pub event_id: Uuid,
+    /// Id shared by every message in the flow
+    pub correlation_id: Uuid,
+    /// Id of the command that produced this event
+    pub causation_id: Uuid,
+    /// Id shared by the events produced together by one command
+    #[serde(default)]
+    pub correlation_group: Uuid,
+    /// The domain event
+    pub event: GraphDomainEvent,
+}
+
+impl CausedEvent {
+    /// Stamp the events produced by `command` with their causation and a fresh correlation group
+    pub fn from_command(command: &CausedCommand, events: Vec<GraphDomainEvent>) -> Vec<Self> {
+        Self::in_group(command, Uuid::new_v4(), events)
+    }
+
+    /// Stamp the events produced by `command` with their causation and `correlation_group`
+    pub fn in_group(
+        command: &CausedCommand,
+        correlation_group: Uuid,
+        events: Vec<GraphDomainEvent>,
+    ) -> Vec<Self> {
+        events
+            .into_iter()
+            .map(|event| Self {
+                event_id: Uuid::new_v4(),
+                correlation_id: command.correlation_id,
+                causation_id: command.command_id,
+                correlation_group,
+                event,
+            })
+            .collect()
+    }
+}
@@ -13,21 +13,49 @@ use cim_domain::{
 };
 use std::sync::Arc;
 use tracing::{info, error};
+use uuid::Uuid;
 
 /// Trait for publishing graph events with correlation
+///
+/// This crate only provides [`NoOpEventPublisher`]. Publishing to NATS needs an
+/// implementation over the event store or client of `cim-infrastructure`, which is not part
+/// of this crate; applications pass one to `GraphCommandHandlerImpl::with_event_publisher`.
 #[async_trait::async_trait]
 pub trait GraphEventPublisher: Send + Sync {
     /// Publish graph events with correlation metadata
+    ///
+    /// `events` were produced together by one command and share `correlation_group`, so
+    /// consumers can apply them as one unit.
     async fn publish_events(
         &self,
         graph_id: &str,
         events: Vec<GraphDomainEvent>,
         correlation_id: String,
         causation_id: Option<String>,
+        correlation_group: Uuid,
         user_id: String,
     ) -> Result<(), GraphCommandError>;
 }
 
+/// Publisher that discards events, used when no event bus is configured
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOpEventPublisher;
+
+#[async_trait::async_trait]
+impl GraphEventPublisher for NoOpEventPublisher {
+    async fn publish_events(
+        &self,
+        _graph_id: &str,
+        _events: Vec<GraphDomainEvent>,
+        _correlation_id: String,
+        _causation_id: Option<String>,
+        _correlation_group: Uuid,
+        _user_id: String,
+    ) -> Result<(), GraphCommandError> {
+        Ok(())
+    }
+}
+
 /// Graph command handler that publishes events with correlation
 pub struct EventPublishingGraphHandler {
     inner: UnifiedGraphCommandHandler,
@@ -74,6 +102,7 @@ impl EventPublishingGraphHandler {
                     events.clone(),
                     envelope.correlation_id().to_string(),
                     Some(envelope.id.to_string()),
+                    Uuid::new_v4(),
                     envelope.issued_by.clone(),
                 )
                 .await?;
@@ -154,6 +183,7 @@ mod tests {
             _events: Vec<GraphDomainEvent>,
             _correlation_id: String,
             _causation_id: Option<String>,
+            _correlation_group: Uuid,
             _user_id: String,
         ) -> Result<(), GraphCommandError> {
             Ok(())
@@ -5,20 +5,31 @@
 
 mod abstract_event_handler;
 mod abstract_handler;
+mod causation;
 mod event_publishing_handler;
 mod unified_handler;
 
 pub use abstract_event_handler::{AbstractGraphEventHandler, AbstractGraphEventRepository};
 pub use abstract_handler::*;
-pub use event_publishing_handler::EventPublishingGraphHandler;
+pub use causation::{CausedCommand, CausedEvent};
+pub use event_publishing_handler::{
+    EventPublishingGraphHandler, GraphEventPublisher, NoOpEventPublisher,
+};
 pub use unified_handler::{UnifiedGraphCommandHandler, UnifiedGraphRepository};
 
 use crate::{
-    aggregate::Graph,
-    commands::{EdgeCommand, GraphCommand, GraphCommandError, GraphCommandResult, NodeCommand},
+    aggregate::{Graph, GraphEdge},
+    commands::{
+        contracted_metadata, group_member_failures, metadata_failures, split_failures,
+        split_moves, structure_failures, EdgeCommand, GraphCommand, GraphCommandError,
+        GraphCommandResult, NodeCommand, SPLIT_EDGE_TYPE,
+    },
     domain_events::GraphDomainEvent,
-    events::{EdgeAdded, EdgeRemoved, GraphCreated, NodeAdded, NodeRemoved},
-    EdgeId, GraphId, NodeId,
+    events::{
+        EdgeAdded, EdgeRemoved, EdgeUpdated, GraphCreated, GraphUpdated, HyperEdgeAdded,
+        NodeAdded, NodeMoved, NodeRemoved, NodeUpdated, RemovedEdge,
+    },
+    groups, queries::GraphStructure, tags, EdgeId, GraphId, NodeId,
 };
 use async_trait::async_trait;
 use cim_domain::{
@@ -26,6 +37,7 @@ use cim_domain::{
     EntityId,
 };
 use std::sync::Arc;
+use uuid::Uuid;
 
 /// Trait for handling graph commands
 #[async_trait]
@@ -47,6 +59,20 @@ pub trait GraphCommandHandler: Send + Sync {
         &self,
         command: EdgeCommand,
     ) -> GraphCommandResult<Vec<GraphDomainEvent>>;
+
+    /// Validate a graph command and return the events it would produce
+    ///
+    /// Nothing is saved or published, so repeated dry runs leave the graph unchanged.
+    /// Handlers that cannot run commands without saving keep the default, which rejects
+    /// every command.
+    async fn validate_only(
+        &self,
+        _command: GraphCommand,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        Err(GraphCommandError::InvalidCommand(
+            "Dry runs are not supported by this handler".to_string(),
+        ))
+    }
 }
 
 /// Repository trait for loading and saving graph aggregates
@@ -71,15 +97,249 @@ pub trait GraphRepository: Send + Sync {
     async fn next_edge_id(&self) -> GraphCommandResult<EdgeId>;
 }
 
+/// Name given to a graph created by importing a structure into an unknown graph id
+const IMPORTED_GRAPH_NAME: &str = "Imported graph";
+
+/// Events for splitting `first`: the new node, an update per moved edge, then the link
+///
+/// `moved` holds each edge moved to the new node with its new endpoints.
+fn split_events(
+    graph_id: GraphId,
+    first: NodeId,
+    second: NodeAdded,
+    moved: &[(EdgeId, NodeId, NodeId)],
+    link_edge_id: EdgeId,
+    at: chrono::DateTime<chrono::Utc>,
+) -> Vec<GraphDomainEvent> {
+    let second_id = second.node_id;
+    let mut events = vec![GraphDomainEvent::NodeAdded(second)];
+    events.extend(moved.iter().map(|(edge_id, source, target)| {
+        GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+            graph_id,
+            edge_id: *edge_id,
+            relationship: None,
+            metadata: std::collections::HashMap::new(),
+            endpoints: Some((*source, *target)),
+            updated_at: at,
+        })
+    }));
+    events.push(GraphDomainEvent::EdgeAdded(EdgeAdded {
+        graph_id,
+        edge_id: link_edge_id,
+        source: first,
+        target: second_id,
+        relationship: crate::components::EdgeRelationship::association(SPLIT_EDGE_TYPE),
+        edge_type: SPLIT_EDGE_TYPE.to_string(),
+        metadata: std::collections::HashMap::new(),
+        created_at: at,
+    }));
+    events
+}
+
+/// Events for contracting an edge into `kept`: the edge's `contracted` removal, an update
+/// per moved edge, the removal of `merged`, then `kept`'s metadata if it changed
+///
+/// `moved` holds each moved edge with its new endpoints.
+fn contract_events(
+    graph_id: GraphId,
+    contracted: GraphDomainEvent,
+    moved: &[(EdgeId, NodeId, NodeId)],
+    merged: NodeId,
+    kept: NodeId,
+    kept_metadata: Option<std::collections::HashMap<String, serde_json::Value>>,
+    at: chrono::DateTime<chrono::Utc>,
+) -> Vec<GraphDomainEvent> {
+    let mut events = vec![contracted];
+    events.extend(moved.iter().map(|(edge_id, source, target)| {
+        GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+            graph_id,
+            edge_id: *edge_id,
+            relationship: None,
+            metadata: std::collections::HashMap::new(),
+            endpoints: Some((*source, *target)),
+            updated_at: at,
+        })
+    }));
+    events.push(GraphDomainEvent::NodeRemoved(NodeRemoved {
+        graph_id,
+        node_id: merged,
+        removed_at: at,
+    }));
+    // Source keys are never dropped, so an update carrying the merged map is exact
+    if let Some(metadata) = kept_metadata {
+        events.push(GraphDomainEvent::NodeUpdated(NodeUpdated {
+            graph_id,
+            node_id: kept,
+            position: None,
+            metadata,
+            node_type: None,
+            removed_keys: Vec::new(),
+            updated_at: at,
+        }));
+    }
+    events
+}
+
+/// NodeAdded events followed by EdgeAdded events for an imported structure
+fn import_events(
+    graph_id: GraphId,
+    structure: &GraphStructure,
+    at: chrono::DateTime<chrono::Utc>,
+) -> Vec<GraphDomainEvent> {
+    let nodes = structure.nodes.iter().map(|node| {
+        GraphDomainEvent::NodeAdded(NodeAdded {
+            graph_id,
+            node_id: node.node_id,
+            position: node
+                .position_3d
+                .or_else(|| node.position_2d.map(crate::value_objects::Position3D::from))
+                .unwrap_or_default(),
+            node_type: node.node_type.clone(),
+            metadata: node.metadata.clone(),
+            created_at: at,
+        })
+    });
+    let edges = structure.edges.iter().map(|edge| {
+        GraphDomainEvent::EdgeAdded(EdgeAdded {
+            graph_id,
+            edge_id: edge.edge_id,
+            source: edge.source_id,
+            target: edge.target_id,
+            relationship: crate::components::EdgeRelationship::association(&edge.edge_type),
+            edge_type: edge.edge_type.clone(),
+            metadata: edge.metadata.clone(),
+            created_at: at,
+        })
+    });
+    nodes.chain(edges).collect()
+}
+
 /// Implementation of the graph command handler
 pub struct GraphCommandHandlerImpl {
     repository: Arc<dyn GraphRepository>,
+    event_publisher: Arc<dyn GraphEventPublisher>,
 }
 
 impl GraphCommandHandlerImpl {
-    /// Create a new graph command handler
+    /// Create a new graph command handler that does not publish events
     pub fn new(repository: Arc<dyn GraphRepository>) -> Self {
-        Self { repository }
+        Self::with_event_publisher(repository, Arc::new(NoOpEventPublisher))
+    }
+
+    /// Create a graph command handler that publishes produced events
+    pub fn with_event_publisher(
+        repository: Arc<dyn GraphRepository>,
+        event_publisher: Arc<dyn GraphEventPublisher>,
+    ) -> Self {
+        Self {
+            repository,
+            event_publisher,
+        }
+    }
+
+    /// Process a command and publish its events with the envelope's correlation
+    ///
+    /// The envelope's command id is used as the causation id.
+    pub async fn process_and_publish(
+        &self,
+        envelope: &CommandEnvelope<GraphCommand>,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        let command = envelope.command.clone();
+        let graph_id = command.graph_id();
+        let events = self
+            .process_graph_command(command, envelope, chrono::Utc::now())
+            .await?;
+
+        self.publish(
+            graph_id,
+            &events,
+            envelope.correlation_id().to_string(),
+            envelope.id.to_string(),
+            Uuid::new_v4(),
+            envelope.issued_by.clone(),
+        )
+        .await?;
+
+        Ok(events)
+    }
+
+    /// Handle a command that is part of a causation chain
+    ///
+    /// The produced events are published and returned with the command's id as their
+    /// causation id and the command's correlation id.
+    pub async fn handle_caused_command(
+        &self,
+        caused: CausedCommand,
+    ) -> GraphCommandResult<Vec<CausedEvent>> {
+        let graph_id = caused.command.graph_id();
+        let envelope = CommandEnvelope::new(caused.command.clone(), "graph-handler".to_string());
+        let events = self
+            .process_graph_command(caused.command.clone(), &envelope, chrono::Utc::now())
+            .await?;
+
+        // The published and returned events share one correlation group
+        let correlation_group = Uuid::new_v4();
+        self.publish(
+            graph_id,
+            &events,
+            caused.correlation_id.to_string(),
+            caused.command_id.to_string(),
+            correlation_group,
+            envelope.issued_by.clone(),
+        )
+        .await?;
+
+        Ok(CausedEvent::in_group(&caused, correlation_group, events))
+    }
+
+    /// Save a graph changed by a command, recording the command's time as its last change
+    ///
+    /// The command's events carry the same time, so replaying them reproduces the
+    /// snapshot's `last_modified`.
+    async fn save_at(
+        &self,
+        graph: &mut Graph,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> GraphCommandResult<()> {
+        graph.set_last_modified(at);
+        self.repository.save(graph).await
+    }
+
+    /// Publish the events of one command under a shared correlation group
+    async fn publish(
+        &self,
+        graph_id: Option<GraphId>,
+        events: &[GraphDomainEvent],
+        correlation_id: String,
+        causation_id: String,
+        correlation_group: Uuid,
+        issued_by: String,
+    ) -> GraphCommandResult<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        // CreateGraph has no graph id until the GraphCreated event is produced
+        let aggregate_id = match (graph_id, &events[0]) {
+            (Some(graph_id), _) => graph_id.to_string(),
+            (None, GraphDomainEvent::GraphCreated(event)) => event.graph_id.to_string(),
+            (None, _) => {
+                return Err(GraphCommandError::InvalidCommand(
+                    "Expected GraphCreated event".to_string(),
+                ))
+            }
+        };
+
+        self.event_publisher
+            .publish_events(
+                &aggregate_id,
+                events.to_vec(),
+                correlation_id,
+                Some(causation_id),
+                correlation_group,
+                issued_by,
+            )
+            .await
     }
 
     /// Process a graph command and return events with correlation
@@ -87,6 +347,7 @@ impl GraphCommandHandlerImpl {
         &self,
         command: GraphCommand,
         _envelope: &CommandEnvelope<GraphCommand>,
+        at: chrono::DateTime<chrono::Utc>,
     ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
         match command {
             GraphCommand::CreateGraph {
@@ -95,17 +356,22 @@ impl GraphCommandHandlerImpl {
                 metadata,
             } => {
                 let graph_id = self.repository.next_graph_id().await?;
-                let created_at = chrono::Utc::now();
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if name.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Graph name cannot be empty".to_string(),
-                    ));
+                    failures.push("Graph name cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Create new graph aggregate
-                let graph = Graph::new(graph_id, name.clone(), description.clone());
+                let graph = Graph::with_metadata(
+                    graph_id,
+                    name.clone(),
+                    description.clone(),
+                    metadata.clone(),
+                );
 
                 // Save graph
                 self.repository.save(&graph).await?;
@@ -117,7 +383,7 @@ impl GraphCommandHandlerImpl {
                     description,
                     graph_type: None,
                     metadata,
-                    created_at,
+                    created_at: graph.created_at(),
                 });
 
                 Ok(vec![event])
@@ -132,18 +398,19 @@ impl GraphCommandHandlerImpl {
                 let mut graph = self.repository.load(graph_id).await?;
                 let node_id = self.repository.next_node_id().await?;
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if node_type.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Node type cannot be empty".to_string(),
-                    ));
+                    failures.push("Node type cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Add node to graph
                 graph.add_node(node_id, node_type.clone(), metadata.clone())?;
 
                 // Save graph
-                self.repository.save(&graph).await?;
+                self.save_at(&mut graph, at).await?;
 
                 // Generate event
                 let event = GraphDomainEvent::NodeAdded(NodeAdded {
@@ -152,6 +419,7 @@ impl GraphCommandHandlerImpl {
                     position: crate::value_objects::Position3D::default(),
                     node_type,
                     metadata,
+                    created_at: at,
                 });
 
                 Ok(vec![event])
@@ -161,16 +429,32 @@ impl GraphCommandHandlerImpl {
                 // Load graph
                 let mut graph = self.repository.load(graph_id).await?;
 
+                // Incident edges are removed with the node
+                let incident_edges: Vec<GraphEdge> = graph
+                    .edges()
+                    .values()
+                    .filter(|edge| edge.source_id == node_id || edge.target_id == node_id)
+                    .cloned()
+                    .collect();
+
                 // Remove node from graph
                 graph.remove_node(node_id)?;
 
                 // Save graph
-                self.repository.save(&graph).await?;
-
-                // Generate event
-                let event = GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id });
+                self.save_at(&mut graph, at).await?;
+
+                // Generate events, edges first so projections never hold dangling edges
+                let mut events: Vec<GraphDomainEvent> = incident_edges
+                    .iter()
+                    .map(|edge| Self::edge_removed(graph_id, edge, at))
+                    .collect();
+                events.push(GraphDomainEvent::NodeRemoved(NodeRemoved {
+                    graph_id,
+                    node_id,
+                    removed_at: at,
+                }));
 
-                Ok(vec![event])
+                Ok(events)
             }
 
             GraphCommand::ChangeNodeMetadata {
@@ -178,33 +462,119 @@ impl GraphCommandHandlerImpl {
                 node_id,
                 new_metadata,
             } => {
-                // Load graph
-                let mut graph = self.repository.load(graph_id).await?;
+                self.replace_node_metadata(graph_id, node_id, new_metadata, at).await
+            }
+
+            GraphCommand::MergeNodeMetadata {
+                graph_id,
+                node_id,
+                metadata,
+            } => {
+                let graph = self.repository.load(graph_id).await?;
+                let node = graph
+                    .nodes()
+                    .get(&node_id)
+                    .ok_or(GraphCommandError::NodeNotFound(node_id))?;
+
+                let mut merged = node.metadata.clone();
+                merged.extend(metadata);
+                self.replace_node_metadata(graph_id, node_id, merged, at).await
+            }
+
+            GraphCommand::TagNode {
+                graph_id,
+                node_id,
+                tag,
+            } => {
+                let graph = self.repository.load(graph_id).await?;
+                let node = graph
+                    .nodes()
+                    .get(&node_id)
+                    .ok_or(GraphCommandError::NodeNotFound(node_id))?;
+
+                // Already tagged nodes produce no events
+                match tags::with_tag(&node.metadata, &tag)? {
+                    Some(new_metadata) => {
+                        self.replace_node_metadata(graph_id, node_id, new_metadata, at).await
+                    }
+                    None => Ok(Vec::new()),
+                }
+            }
 
-                // Get old node data before removing it
-                let old_node = graph
+            GraphCommand::UntagNode {
+                graph_id,
+                node_id,
+                tag,
+            } => {
+                let graph = self.repository.load(graph_id).await?;
+                let node = graph
                     .nodes()
                     .get(&node_id)
-                    .ok_or(GraphCommandError::NodeNotFound(node_id))?
-                    .clone();
+                    .ok_or(GraphCommandError::NodeNotFound(node_id))?;
+
+                match tags::without_tag(&node.metadata, &tag)? {
+                    Some(new_metadata) => {
+                        self.replace_node_metadata(graph_id, node_id, new_metadata, at).await
+                    }
+                    None => Ok(Vec::new()),
+                }
+            }
+
+            GraphCommand::RelabelNodeType {
+                graph_id,
+                node_id,
+                new_node_type,
+            } => {
+                // Validate input
+                if new_node_type.trim().is_empty() {
+                    return Err(GraphCommandError::InvalidCommand(
+                        "Node type cannot be empty".to_string(),
+                    ));
+                }
+
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
 
-                // Change node metadata in graph (remove old, add new)
-                graph.change_node_metadata(node_id, new_metadata.clone())?;
+                // Relabel the node in place
+                graph.relabel_node(node_id, new_node_type.clone())?;
 
                 // Save graph
-                self.repository.save(&graph).await?;
+                self.save_at(&mut graph, at).await?;
 
-                // Generate events - remove old node, then add new node with changed metadata
-                let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id });
-                let add_event = GraphDomainEvent::NodeAdded(NodeAdded {
+                // Generate event
+                let event = GraphDomainEvent::NodeUpdated(NodeUpdated {
                     graph_id,
                     node_id,
-                    position: crate::value_objects::Position3D::default(),
-                    node_type: old_node.node_type,
-                    metadata: new_metadata,
+                    position: None,
+                    metadata: std::collections::HashMap::new(),
+                    node_type: Some(new_node_type),
+                    removed_keys: Vec::new(),
+                    updated_at: at,
+                });
+
+                Ok(vec![event])
+            }
+
+            GraphCommand::RenameGraph { graph_id, new_name } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Rename it, rejecting an empty name
+                graph.rename(new_name.clone())?;
+
+                // Save graph
+                self.save_at(&mut graph, at).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::GraphUpdated(GraphUpdated {
+                    graph_id,
+                    name: Some(new_name),
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                    updated_at: at,
                 });
 
-                Ok(vec![remove_event, add_event])
+                Ok(vec![event])
             }
 
             GraphCommand::AddEdge {
@@ -213,17 +583,19 @@ impl GraphCommandHandlerImpl {
                 target_id,
                 edge_type,
                 metadata,
+                relationship,
             } => {
                 // Load graph
                 let mut graph = self.repository.load(graph_id).await?;
                 let edge_id = self.repository.next_edge_id().await?;
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if edge_type.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Edge type cannot be empty".to_string(),
-                    ));
+                    failures.push("Edge type cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Add edge to graph
                 graph.add_edge(
@@ -235,7 +607,7 @@ impl GraphCommandHandlerImpl {
                 )?;
 
                 // Save graph
-                self.repository.save(&graph).await?;
+                self.save_at(&mut graph, at).await?;
 
                 // Generate event
                 let event = GraphDomainEvent::EdgeAdded(EdgeAdded {
@@ -243,11 +615,12 @@ impl GraphCommandHandlerImpl {
                     edge_id,
                     source: source_id,
                     target: target_id,
-                    relationship: crate::components::EdgeRelationship::Association {
-                        association_type: edge_type.clone(),
-                    },
+                    relationship: relationship.unwrap_or_else(|| {
+                        crate::components::EdgeRelationship::association(&edge_type)
+                    }),
                     edge_type,
                     metadata,
+                    created_at: at,
                 });
 
                 Ok(vec![event])
@@ -256,113 +629,540 @@ impl GraphCommandHandlerImpl {
             GraphCommand::RemoveEdge { graph_id, edge_id } => {
                 // Load graph
                 let mut graph = self.repository.load(graph_id).await?;
+                let edge = graph
+                    .edges()
+                    .get(&edge_id)
+                    .cloned()
+                    .ok_or(GraphCommandError::EdgeNotFound(edge_id))?;
 
                 // Remove edge from graph
                 graph.remove_edge(edge_id)?;
 
                 // Save graph
-                self.repository.save(&graph).await?;
+                self.save_at(&mut graph, at).await?;
+
+                // Generate event
+                Ok(vec![Self::edge_removed(graph_id, &edge, at)])
+            }
+
+            GraphCommand::SetEdgeWeight {
+                graph_id,
+                edge_id,
+                weight,
+            } => {
+                // Validate input
+                if weight.is_nan() || weight < 0.0 {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Edge weight must be non-negative, got {weight}"
+                    )));
+                }
+
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Store the weight on the edge
+                graph.set_edge_weight(edge_id, weight)?;
+
+                // Save graph
+                self.save_at(&mut graph, at).await?;
 
                 // Generate event
-                let event = GraphDomainEvent::EdgeRemoved(EdgeRemoved { graph_id, edge_id });
+                let event = GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+                    graph_id,
+                    edge_id,
+                    relationship: None,
+                    metadata: std::collections::HashMap::from([(
+                        "weight".to_string(),
+                        serde_json::json!(weight),
+                    )]),
+                    endpoints: None,
+                    updated_at: at,
+                });
 
                 Ok(vec![event])
             }
-        }
-    }
-}
 
-// Implement the Command trait for GraphCommand
-impl Command for GraphCommand {
-    type Aggregate = Graph;
+            GraphCommand::SetNodePosition {
+                graph_id,
+                node_id,
+                position,
+            } => {
+                // Validate input
+                if ![position.x, position.y, position.z].iter().all(|v| v.is_finite()) {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Node position must be finite, got {position:?}"
+                    )));
+                }
 
-    fn aggregate_id(&self) -> Option<EntityId<Self::Aggregate>> {
-        None // Graph commands don't have a pre-existing aggregate ID for creation
-    }
-}
+                // Positions are presentation state and are not stored on the aggregate
+                let graph = self.repository.load(graph_id).await?;
+                if !graph.nodes().contains_key(&node_id) {
+                    return Err(GraphCommandError::NodeNotFound(node_id));
+                }
 
-// Implement CommandHandler for GraphCommand
-impl CommandHandler<GraphCommand> for GraphCommandHandlerImpl {
-    fn handle(&mut self, envelope: CommandEnvelope<GraphCommand>) -> CommandAcknowledgment {
-        // Extract the command
-        let command = envelope.command.clone();
-        let command_id = envelope.id;
-        let correlation_id = envelope.correlation_id().clone();
+                // Generate event
+                let event = GraphDomainEvent::NodeMoved(NodeMoved {
+                    graph_id,
+                    node_id,
+                    position,
+                    moved_at: at,
+                });
 
-        // Process the command synchronously (blocking on async)
-        let runtime = tokio::runtime::Handle::current();
-        let result =
-            runtime.block_on(async { self.process_graph_command(command, &envelope).await });
+                Ok(vec![event])
+            }
 
-        match result {
-            Ok(_events) => {
-                // Events are published by EventPublishingGraphHandler wrapper
-                CommandAcknowledgment {
-                    command_id,
-                    correlation_id,
-                    status: CommandStatus::Accepted,
-                    reason: None,
+            GraphCommand::RepairGraph { graph_id } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Remove every edge left pointing at a missing node
+                let orphaned: Vec<GraphEdge> = graph
+                    .orphaned_edges()
+                    .into_iter()
+                    .filter_map(|edge_id| graph.edges().get(&edge_id).cloned())
+                    .collect();
+                if orphaned.is_empty() {
+                    return Ok(Vec::new());
+                }
+                for edge in &orphaned {
+                    graph.remove_edge(edge.id)?;
                 }
-            }
-            Err(error) => CommandAcknowledgment {
-                command_id,
-                correlation_id,
-                status: CommandStatus::Rejected,
-                reason: Some(error.to_string()),
-            },
-        }
-    }
-}
 
-// Keep the original GraphCommandHandler implementation for backward compatibility
-#[async_trait]
-impl GraphCommandHandler for GraphCommandHandlerImpl {
-    async fn handle_graph_command(
-        &self,
-        command: GraphCommand,
-    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
-        // Create a command envelope for the new flow
-        let envelope = CommandEnvelope::new(command.clone(), "graph-handler".to_string());
-        self.process_graph_command(command, &envelope).await
-    }
+                // Save graph
+                self.save_at(&mut graph, at).await?;
 
-    async fn handle_node_command(
-        &self,
-        command: NodeCommand,
-    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
-        match command {
-            NodeCommand::Add {
+                // Generate events
+                Ok(orphaned
+                    .iter()
+                    .map(|edge| Self::edge_removed(graph_id, edge, at))
+                    .collect())
+            }
+
+            GraphCommand::CreateGroup {
                 graph_id,
-                node_type,
-                metadata,
+                group_id,
+                member_nodes,
             } => {
-                let graph_command = GraphCommand::AddNode {
-                    graph_id,
-                    node_type,
-                    metadata,
-                };
-                self.handle_graph_command(graph_command).await
+                // Validate input
+                GraphCommandError::from_failures(group_member_failures(&member_nodes))?;
+
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Record membership in each member's metadata
+                let membership = groups::group_metadata(group_id);
+                for node_id in &member_nodes {
+                    let mut metadata = graph
+                        .nodes()
+                        .get(node_id)
+                        .ok_or(GraphCommandError::NodeNotFound(*node_id))?
+                        .metadata
+                        .clone();
+                    metadata.extend(membership.clone());
+                    graph.change_node_metadata(*node_id, metadata)?;
+                }
+
+                // Save graph
+                self.save_at(&mut graph, at).await?;
+
+                // Generate events
+                Ok(member_nodes
+                    .into_iter()
+                    .map(|node_id| {
+                        GraphDomainEvent::NodeUpdated(NodeUpdated {
+                            graph_id,
+                            node_id,
+                            position: None,
+                            metadata: membership.clone(),
+                            node_type: None,
+                            removed_keys: Vec::new(),
+                            updated_at: at,
+                        })
+                    })
+                    .collect())
             }
 
-            NodeCommand::Remove { graph_id, node_id } => {
-                let graph_command = GraphCommand::RemoveNode { graph_id, node_id };
-                self.handle_graph_command(graph_command).await
+            GraphCommand::ReverseEdges { graph_id } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+
+                // Flip every edge except self-loops
+                let events: Vec<GraphDomainEvent> = graph
+                    .reverse_edges()
+                    .into_iter()
+                    .map(|edge| {
+                        GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+                            graph_id,
+                            edge_id: edge.id,
+                            relationship: None,
+                            metadata: std::collections::HashMap::new(),
+                            endpoints: Some((edge.source_id, edge.target_id)),
+                            updated_at: at,
+                        })
+                    })
+                    .collect();
+
+                // Save graph
+                self.save_at(&mut graph, at).await?;
+
+                Ok(events)
             }
 
-            NodeCommand::ChangeMetadata {
+            GraphCommand::SplitNode {
                 graph_id,
                 node_id,
-                new_metadata,
+                incoming_to_first,
+                outgoing_to_second,
             } => {
-                let graph_command = GraphCommand::ChangeNodeMetadata {
-                    graph_id,
-                    node_id,
-                    new_metadata,
-                };
-                self.handle_graph_command(graph_command).await
-            }
-        }
-    }
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+                let node = graph
+                    .nodes()
+                    .get(&node_id)
+                    .cloned()
+                    .ok_or(GraphCommandError::NodeNotFound(node_id))?;
+
+                // Validate input, reporting every failure together
+                GraphCommandError::from_failures(split_failures(
+                    node_id,
+                    &incoming_to_first,
+                    &outgoing_to_second,
+                    |edge_id| {
+                        graph.edges().get(&edge_id).map(|edge| (edge.source_id, edge.target_id))
+                    },
+                ))?;
+
+                // Add the second half and move the edges assigned to it
+                let second_id = self.repository.next_node_id().await?;
+                let link_edge_id = self.repository.next_edge_id().await?;
+                graph.add_node(second_id, node.node_type.clone(), node.metadata.clone())?;
+                let moved = split_moves(
+                    node_id,
+                    second_id,
+                    &incoming_to_first,
+                    &outgoing_to_second,
+                    graph.edges().values().map(|edge| (edge.id, edge.source_id, edge.target_id)),
+                );
+                for (edge_id, source_id, target_id) in &moved {
+                    graph.reconnect_edge(*edge_id, *source_id, *target_id)?;
+                }
+                graph.add_edge(
+                    link_edge_id,
+                    node_id,
+                    second_id,
+                    SPLIT_EDGE_TYPE.to_string(),
+                    std::collections::HashMap::new(),
+                )?;
+
+                // Save graph
+                self.save_at(&mut graph, at).await?;
+
+                // Generate events
+                let second = NodeAdded {
+                    graph_id,
+                    node_id: second_id,
+                    position: crate::value_objects::Position3D::default(),
+                    node_type: node.node_type,
+                    metadata: node.metadata,
+                    created_at: at,
+                };
+                Ok(split_events(graph_id, node_id, second, &moved, link_edge_id, at))
+            }
+
+            GraphCommand::ContractEdge {
+                graph_id,
+                edge_id,
+                metadata_merge,
+            } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+                let edge = graph
+                    .edges()
+                    .get(&edge_id)
+                    .cloned()
+                    .ok_or(GraphCommandError::EdgeNotFound(edge_id))?;
+                let (kept, merged) = (edge.source_id, edge.target_id);
+                if kept == merged {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Edge {edge_id} is a self-loop and cannot be contracted"
+                    )));
+                }
+
+                // Combine the endpoints' metadata
+                let metadata_of = |node_id: NodeId| {
+                    graph
+                        .nodes()
+                        .get(&node_id)
+                        .map(|node| node.metadata.clone())
+                        .ok_or(GraphCommandError::NodeNotFound(node_id))
+                };
+                let kept_metadata = metadata_of(kept)?;
+                let metadata =
+                    contracted_metadata(metadata_merge, &kept_metadata, &metadata_of(merged)?)?;
+
+                // Contract the edge, moving the merged node's other edges onto the kept node
+                let moved: Vec<(EdgeId, NodeId, NodeId)> = graph
+                    .contract_edge(edge_id, metadata.clone())?
+                    .into_iter()
+                    .map(|id| (id, graph.edges()[&id].source_id, graph.edges()[&id].target_id))
+                    .collect();
+
+                // Save graph
+                self.save_at(&mut graph, at).await?;
+
+                // Generate events
+                let contracted = Self::edge_removed(graph_id, &edge, at);
+                let changed = (metadata != kept_metadata).then_some(metadata);
+                Ok(contract_events(graph_id, contracted, &moved, merged, kept, changed, at))
+            }
+
+            GraphCommand::ImportStructure {
+                graph_id,
+                structure,
+            } => {
+                // Load graph, starting a new one under the given id if there is none
+                let mut events = Vec::new();
+                let mut graph = if self.repository.exists(graph_id).await? {
+                    self.repository.load(graph_id).await?
+                } else {
+                    let graph =
+                        Graph::new(graph_id, IMPORTED_GRAPH_NAME.to_string(), String::new());
+                    events.push(GraphDomainEvent::GraphCreated(GraphCreated {
+                        graph_id,
+                        name: IMPORTED_GRAPH_NAME.to_string(),
+                        description: String::new(),
+                        graph_type: None,
+                        metadata: std::collections::HashMap::new(),
+                        created_at: graph.created_at(),
+                    }));
+                    graph
+                };
+
+                // Validate the whole structure before touching the graph
+                let existing = graph.nodes().keys().copied().collect();
+                GraphCommandError::from_failures(structure_failures(&structure, &existing))?;
+
+                // Add nodes, then the edges between them
+                for node in &structure.nodes {
+                    graph.add_node(node.node_id, node.node_type.clone(), node.metadata.clone())?;
+                }
+                for edge in &structure.edges {
+                    graph.add_edge(
+                        edge.edge_id,
+                        edge.source_id,
+                        edge.target_id,
+                        edge.edge_type.clone(),
+                        edge.metadata.clone(),
+                    )?;
+                }
+
+                // Save graph once for the whole import
+                self.save_at(&mut graph, at).await?;
+
+                events.extend(import_events(graph_id, &structure, at));
+                Ok(events)
+            }
+
+            GraphCommand::AddHyperEdge {
+                graph_id,
+                node_ids,
+                edge_type,
+                metadata,
+            } => {
+                // Load graph
+                let mut graph = self.repository.load(graph_id).await?;
+                let edge_id = self.repository.next_edge_id().await?;
+
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
+                if edge_type.trim().is_empty() {
+                    failures.push("Edge type cannot be empty".to_string());
+                }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
+
+                // Add hyperedge to graph
+                graph.add_hyperedge(
+                    edge_id,
+                    node_ids.clone(),
+                    edge_type.clone(),
+                    metadata.clone(),
+                )?;
+
+                // Save graph
+                self.save_at(&mut graph, at).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::HyperEdgeAdded(HyperEdgeAdded {
+                    graph_id,
+                    edge_id,
+                    node_ids,
+                    edge_type,
+                    metadata,
+                    created_at: at,
+                });
+
+                Ok(vec![event])
+            }
+        }
+    }
+
+    /// Replace a node's metadata, emitting a NodeUpdated with the changed and removed keys
+    ///
+    /// The node stays in place, so its edges and hyperedges are untouched.
+    async fn replace_node_metadata(
+        &self,
+        graph_id: GraphId,
+        node_id: NodeId,
+        new_metadata: std::collections::HashMap<String, serde_json::Value>,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        // Load graph
+        let mut graph = self.repository.load(graph_id).await?;
+
+        // Get old node data before replacing its metadata
+        let old_node = graph
+            .nodes()
+            .get(&node_id)
+            .ok_or(GraphCommandError::NodeNotFound(node_id))?
+            .clone();
+
+        // Change node metadata in graph
+        graph.change_node_metadata(node_id, new_metadata.clone())?;
+
+        // Save graph
+        self.save_at(&mut graph, at).await?;
+
+        // Generate event
+        let event = GraphDomainEvent::NodeUpdated(NodeUpdated {
+            updated_at: at,
+            ..NodeUpdated::replacing_metadata(graph_id, node_id, &old_node.metadata, new_metadata)
+        });
+
+        Ok(vec![event])
+    }
+
+    /// EdgeRemoved event carrying the removed edge's state, so the removal can be undone
+    fn edge_removed(
+        graph_id: GraphId,
+        edge: &GraphEdge,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> GraphDomainEvent {
+        GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+            graph_id,
+            edge_id: edge.id,
+            removed: Some(RemovedEdge {
+                source: edge.source_id,
+                target: edge.target_id,
+                edge_type: edge.edge_type.clone(),
+                metadata: edge.metadata.clone(),
+            }),
+            removed_at: at,
+        })
+    }
+}
+
+// Implement the Command trait for GraphCommand
+impl Command for GraphCommand {
+    type Aggregate = Graph;
+
+    fn aggregate_id(&self) -> Option<EntityId<Self::Aggregate>> {
+        None // Graph commands don't have a pre-existing aggregate ID for creation
+    }
+}
+
+// Implement CommandHandler for GraphCommand
+impl CommandHandler<GraphCommand> for GraphCommandHandlerImpl {
+    fn handle(&mut self, envelope: CommandEnvelope<GraphCommand>) -> CommandAcknowledgment {
+        let command_id = envelope.id;
+        let correlation_id = envelope.correlation_id().clone();
+
+        // Process the command synchronously (blocking on async)
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async { self.process_and_publish(&envelope).await });
+
+        match result {
+            Ok(_events) => {
+                // Events were published with the envelope's correlation
+                CommandAcknowledgment {
+                    command_id,
+                    correlation_id,
+                    status: CommandStatus::Accepted,
+                    reason: None,
+                }
+            }
+            Err(error) => CommandAcknowledgment {
+                command_id,
+                correlation_id,
+                status: CommandStatus::Rejected,
+                reason: Some(error.to_string()),
+            },
+        }
+    }
+}
+
+// Keep the original GraphCommandHandler implementation for backward compatibility
+#[async_trait]
+impl GraphCommandHandler for GraphCommandHandlerImpl {
+    async fn handle_graph_command(
+        &self,
+        command: GraphCommand,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        // Create a command envelope for the new flow
+        let envelope = CommandEnvelope::new(command, "graph-handler".to_string());
+        self.process_and_publish(&envelope).await
+    }
+
+    async fn validate_only(
+        &self,
+        command: GraphCommand,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        let dry_run = GraphCommandHandlerImpl::new(Arc::new(DryRunGraphRepository {
+            inner: self.repository.clone(),
+        }));
+        let envelope = CommandEnvelope::new(command.clone(), "graph-handler".to_string());
+        dry_run
+            .process_graph_command(command, &envelope, chrono::Utc::now())
+            .await
+    }
+
+    async fn handle_node_command(
+        &self,
+        command: NodeCommand,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        match command {
+            NodeCommand::Add {
+                graph_id,
+                node_type,
+                metadata,
+            } => {
+                let graph_command = GraphCommand::AddNode {
+                    graph_id,
+                    node_type,
+                    metadata,
+                };
+                self.handle_graph_command(graph_command).await
+            }
+
+            NodeCommand::Remove { graph_id, node_id } => {
+                let graph_command = GraphCommand::RemoveNode { graph_id, node_id };
+                self.handle_graph_command(graph_command).await
+            }
+
+            NodeCommand::ChangeMetadata {
+                graph_id,
+                node_id,
+                new_metadata,
+            } => {
+                let graph_command = GraphCommand::ChangeNodeMetadata {
+                    graph_id,
+                    node_id,
+                    new_metadata,
+                };
+                self.handle_graph_command(graph_command).await
+            }
+        }
+    }
 
     async fn handle_edge_command(
         &self,
@@ -382,6 +1182,7 @@ impl GraphCommandHandler for GraphCommandHandlerImpl {
                     target_id,
                     edge_type,
                     metadata,
+                    relationship: None,
                 };
                 self.handle_graph_command(graph_command).await
             }
@@ -394,6 +1195,38 @@ impl GraphCommandHandler for GraphCommandHandlerImpl {
     }
 }
 
+/// Repository that reads through to another repository and discards saves
+struct DryRunGraphRepository {
+    inner: Arc<dyn GraphRepository>,
+}
+
+#[async_trait]
+impl GraphRepository for DryRunGraphRepository {
+    async fn load(&self, graph_id: GraphId) -> GraphCommandResult<Graph> {
+        self.inner.load(graph_id).await
+    }
+
+    async fn save(&self, _graph: &Graph) -> GraphCommandResult<()> {
+        Ok(())
+    }
+
+    async fn exists(&self, graph_id: GraphId) -> GraphCommandResult<bool> {
+        self.inner.exists(graph_id).await
+    }
+
+    async fn next_graph_id(&self) -> GraphCommandResult<GraphId> {
+        self.inner.next_graph_id().await
+    }
+
+    async fn next_node_id(&self) -> GraphCommandResult<NodeId> {
+        self.inner.next_node_id().await
+    }
+
+    async fn next_edge_id(&self) -> GraphCommandResult<EdgeId> {
+        self.inner.next_edge_id().await
+    }
+}
+
 /// In-memory implementation of graph repository for testing
 pub struct InMemoryGraphRepository {
     graphs: std::sync::Mutex<std::collections::HashMap<GraphId, Graph>>,
@@ -412,6 +1245,25 @@ impl InMemoryGraphRepository {
             graphs: std::sync::Mutex::new(std::collections::HashMap::new()),
         }
     }
+
+    /// Rebuild a graph from its event history and store it as the current snapshot
+    pub fn load_from_events(
+        &self,
+        graph_id: GraphId,
+        events: Vec<GraphDomainEvent>,
+    ) -> GraphCommandResult<Graph> {
+        let created = events.iter().any(|event| {
+            matches!(event, GraphDomainEvent::GraphCreated(e) if e.graph_id == graph_id)
+        });
+        if !created {
+            return Err(GraphCommandError::GraphNotFound(graph_id));
+        }
+
+        let graph = Graph::from_events(graph_id, events);
+        let mut graphs = self.graphs.lock().unwrap();
+        graphs.insert(graph_id, graph.clone());
+        Ok(graph)
+    }
 }
 
 #[async_trait]
@@ -451,6 +1303,7 @@ impl GraphRepository for InMemoryGraphRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::abstraction::ConflictResolution;
     use std::collections::HashMap;
 
     /// Test Coverage
@@ -511,6 +1364,31 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_validation_reports_every_failure() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository);
+
+        let command = GraphCommand::CreateGraph {
+            name: " ".to_string(),
+            description: "A test graph".to_string(),
+            metadata: HashMap::from([("".to_string(), serde_json::json!(1))]),
+        };
+
+        match handler.handle_graph_command(command).await.unwrap_err() {
+            GraphCommandError::ValidationFailed(failures) => {
+                assert_eq!(
+                    failures,
+                    vec![
+                        "Graph name cannot be empty".to_string(),
+                        "Metadata keys cannot be empty".to_string(),
+                    ]
+                );
+            }
+            other => panic!("Expected ValidationFailed error, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_add_node_command() {
         let repository = Arc::new(InMemoryGraphRepository::new());
@@ -634,4 +1512,1248 @@ mod tests {
             _ => panic!("Expected GraphNotFound error"),
         }
     }
+
+    #[tokio::test]
+    async fn test_load_from_events_matches_snapshot() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let mut events = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Test Graph".to_string(),
+                description: "A test graph".to_string(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &events[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        for node_type in ["start", "end"] {
+            events.extend(
+                handler
+                    .handle_graph_command(GraphCommand::AddNode {
+                        graph_id,
+                        node_type: node_type.to_string(),
+                        metadata: HashMap::new(),
+                    })
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        let snapshot = repository.load(graph_id).await.unwrap();
+        let replay_repository = InMemoryGraphRepository::new();
+        let replayed = replay_repository.load_from_events(graph_id, events).unwrap();
+
+        assert_eq!(replayed.name(), snapshot.name());
+        assert_eq!(replayed.node_count(), snapshot.node_count());
+        assert_eq!(replayed.edge_count(), snapshot.edge_count());
+        assert!(replay_repository.exists(graph_id).await.unwrap());
+
+        // A history without a GraphCreated event cannot be loaded
+        let result = replay_repository.load_from_events(GraphId::new(), Vec::new());
+        assert!(matches!(result, Err(GraphCommandError::GraphNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_replay_keeps_edges_of_changed_node() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let mut events = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Replay".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &events[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let mut node_ids = Vec::new();
+        for _ in 0..2 {
+            let added = handler
+                .handle_graph_command(GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "task".to_string(),
+                    metadata: HashMap::new(),
+                })
+                .await
+                .unwrap();
+            match &added[0] {
+                GraphDomainEvent::NodeAdded(event) => node_ids.push(event.node_id),
+                _ => panic!("Expected NodeAdded event"),
+            }
+            events.extend(added);
+        }
+        for command in [
+            GraphCommand::AddEdge {
+                graph_id,
+                source_id: node_ids[0],
+                target_id: node_ids[1],
+                edge_type: "sequence".to_string(),
+                metadata: HashMap::new(),
+                relationship: None,
+            },
+            GraphCommand::ChangeNodeMetadata {
+                graph_id,
+                node_id: node_ids[0],
+                new_metadata: HashMap::from([("owner".to_string(), serde_json::json!("ops"))]),
+            },
+        ] {
+            events.extend(handler.handle_graph_command(command).await.unwrap());
+        }
+
+        let snapshot = repository.load(graph_id).await.unwrap();
+        let replayed = Graph::from_events(graph_id, events);
+
+        assert_eq!(replayed.edge_count(), 1);
+        for (edge_id, edge) in snapshot.edges() {
+            let replayed_edge = &replayed.edges()[edge_id];
+            assert_eq!(
+                (replayed_edge.source_id, replayed_edge.target_id),
+                (edge.source_id, edge.target_id)
+            );
+        }
+        assert_eq!(replayed.node_count(), snapshot.node_count());
+        for (node_id, node) in snapshot.nodes() {
+            let replayed_node = &replayed.nodes()[node_id];
+            assert_eq!(replayed_node.node_type, node.node_type);
+            assert_eq!(replayed_node.metadata, node.metadata);
+        }
+        assert_eq!(replayed.last_modified(), snapshot.last_modified());
+    }
+
+    #[tokio::test]
+    async fn test_replay_keeps_hyperedges_of_changed_node() {
+        use crate::projections::{EdgeListProjection, GraphProjection};
+
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let mut events = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Replay".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &events[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let mut node_ids = Vec::new();
+        for _ in 0..2 {
+            let added = handler
+                .handle_graph_command(GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "task".to_string(),
+                    metadata: HashMap::from([("stale".to_string(), serde_json::json!(true))]),
+                })
+                .await
+                .unwrap();
+            match &added[0] {
+                GraphDomainEvent::NodeAdded(event) => node_ids.push(event.node_id),
+                _ => panic!("Expected NodeAdded event"),
+            }
+            events.extend(added);
+        }
+        for command in [
+            GraphCommand::AddHyperEdge {
+                graph_id,
+                node_ids: node_ids.clone(),
+                edge_type: "join".to_string(),
+                metadata: HashMap::new(),
+            },
+            GraphCommand::ChangeNodeMetadata {
+                graph_id,
+                node_id: node_ids[0],
+                new_metadata: HashMap::from([("owner".to_string(), serde_json::json!("ops"))]),
+            },
+            GraphCommand::TagNode {
+                graph_id,
+                node_id: node_ids[1],
+                tag: "urgent".to_string(),
+            },
+        ] {
+            events.extend(handler.handle_graph_command(command).await.unwrap());
+        }
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, GraphDomainEvent::NodeRemoved(_))));
+
+        let snapshot = repository.load(graph_id).await.unwrap();
+        let replayed = Graph::from_events(graph_id, events.clone());
+
+        // The two-node hyperedge survives both metadata changes
+        assert_eq!(snapshot.hyperedges().len(), 1);
+        assert_eq!(replayed.hyperedges().len(), 1);
+        for node_id in &node_ids {
+            assert_eq!(replayed.get_hyperedges_for_node(*node_id).len(), 1);
+            assert_eq!(replayed.nodes()[node_id].metadata, snapshot.nodes()[node_id].metadata);
+        }
+        assert!(!replayed.nodes()[&node_ids[0]].metadata.contains_key("stale"));
+
+        let mut projection = EdgeListProjection::new();
+        projection.handle_graph_events(events).await.unwrap();
+        for node_id in &node_ids {
+            assert_eq!(projection.get_hyperedges_for_node(node_id).len(), 1);
+        }
+    }
+
+    /// Aggregate id, events, correlation id, causation id and correlation group of a publish
+    type PublishedBatch = (String, Vec<GraphDomainEvent>, String, Option<String>, Uuid);
+
+    /// Records every publish call
+    #[derive(Default)]
+    struct SpyEventPublisher {
+        published: std::sync::Mutex<Vec<PublishedBatch>>,
+    }
+
+    #[async_trait]
+    impl GraphEventPublisher for SpyEventPublisher {
+        async fn publish_events(
+            &self,
+            graph_id: &str,
+            events: Vec<GraphDomainEvent>,
+            correlation_id: String,
+            causation_id: Option<String>,
+            correlation_group: Uuid,
+            _user_id: String,
+        ) -> Result<(), GraphCommandError> {
+            self.published.lock().unwrap().push((
+                graph_id.to_string(),
+                events,
+                correlation_id,
+                causation_id,
+                correlation_group,
+            ));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_events_are_published_with_correlation() {
+        let publisher = Arc::new(SpyEventPublisher::default());
+        let handler = GraphCommandHandlerImpl::with_event_publisher(
+            Arc::new(InMemoryGraphRepository::new()),
+            publisher.clone(),
+        );
+
+        let envelope = CommandEnvelope::new(
+            GraphCommand::CreateGraph {
+                name: "Published".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            },
+            "tester".to_string(),
+        );
+        let events = handler.process_and_publish(&envelope).await.unwrap();
+
+        let published = publisher.published.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        let (graph_id, published_events, correlation_id, causation_id, _) = &published[0];
+        match &events[0] {
+            GraphDomainEvent::GraphCreated(e) => assert_eq!(*graph_id, e.graph_id.to_string()),
+            _ => panic!("Expected GraphCreated event"),
+        }
+        assert_eq!(published_events.len(), events.len());
+        assert_eq!(*correlation_id, envelope.correlation_id().to_string());
+        assert_eq!(causation_id.as_deref(), Some(envelope.id.to_string().as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_causation_chains_through_commands_and_events() {
+        let publisher = Arc::new(SpyEventPublisher::default());
+        let handler = GraphCommandHandlerImpl::with_event_publisher(
+            Arc::new(InMemoryGraphRepository::new()),
+            publisher.clone(),
+        );
+
+        let create = CausedCommand::new(GraphCommand::CreateGraph {
+            name: "Audited".to_string(),
+            description: String::new(),
+            metadata: HashMap::new(),
+        });
+        let created = handler.handle_caused_command(create.clone()).await.unwrap();
+        assert_eq!(created[0].causation_id, create.command_id);
+        assert_eq!(created[0].correlation_id, create.command_id);
+
+        let graph_id = match &created[0].event {
+            GraphDomainEvent::GraphCreated(e) => e.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        // A command reacting to the event continues the same flow
+        let add_node = CausedCommand::caused_by(
+            GraphCommand::AddNode {
+                graph_id,
+                node_type: "task".to_string(),
+                metadata: HashMap::new(),
+            },
+            &created[0],
+        );
+        assert_eq!(add_node.causation_id, Some(created[0].event_id));
+
+        let added = handler.handle_caused_command(add_node.clone()).await.unwrap();
+        assert_eq!(added[0].causation_id, add_node.command_id);
+        assert_eq!(added[0].correlation_id, create.command_id);
+
+        // The published causation matches the returned one
+        let published = publisher.published.lock().unwrap();
+        assert_eq!(published[1].3, Some(add_node.command_id.to_string()));
+        assert_eq!(published[1].2, create.command_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_published_events_share_correlation_group() {
+        let publisher = Arc::new(SpyEventPublisher::default());
+        let handler = GraphCommandHandlerImpl::with_event_publisher(
+            Arc::new(InMemoryGraphRepository::new()),
+            publisher.clone(),
+        );
+
+        let built = crate::builder::GraphBuilder::new("Grouped")
+            .node("a", "task")
+            .node("b", "task")
+            .edge("a", "b", "sequence")
+            .execute(&handler)
+            .await
+            .unwrap();
+
+        // Removing a node also removes its edge; both are published in the returned group
+        let removed = handler
+            .handle_caused_command(CausedCommand::new(GraphCommand::RemoveNode {
+                graph_id: built.graph_id,
+                node_id: built.nodes["a"],
+            }))
+            .await
+            .unwrap();
+        assert_eq!(removed.len(), 2);
+
+        let published = publisher.published.lock().unwrap();
+        let (_, events, _, _, correlation_group) = published.last().unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(removed.iter().all(|e| e.correlation_group == *correlation_group));
+
+        // Each command is published under its own group
+        let groups: std::collections::HashSet<_> = published.iter().map(|batch| batch.4).collect();
+        assert_eq!(groups.len(), published.len());
+    }
+
+    #[tokio::test]
+    async fn test_merge_node_metadata_keeps_existing_keys() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Palette".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(e) => e.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+        let added = handler
+            .handle_graph_command(GraphCommand::AddNode {
+                graph_id,
+                node_type: "swatch".to_string(),
+                metadata: HashMap::from([("name".to_string(), serde_json::json!("Primary"))]),
+            })
+            .await
+            .unwrap();
+        let node_id = match &added[0] {
+            GraphDomainEvent::NodeAdded(e) => e.node_id,
+            _ => panic!("Expected NodeAdded event"),
+        };
+
+        let events = handler
+            .handle_graph_command(GraphCommand::MergeNodeMetadata {
+                graph_id,
+                node_id,
+                metadata: HashMap::from([("color".to_string(), serde_json::json!("red"))]),
+            })
+            .await
+            .unwrap();
+        let expected = HashMap::from([
+            ("name".to_string(), serde_json::json!("Primary")),
+            ("color".to_string(), serde_json::json!("red")),
+        ]);
+        match &events[..] {
+            [GraphDomainEvent::NodeUpdated(e)] => {
+                let added = HashMap::from([("color".to_string(), serde_json::json!("red"))]);
+                assert_eq!(e.metadata, added);
+                assert!(e.removed_keys.is_empty());
+            }
+            _ => panic!("Expected a single NodeUpdated event"),
+        }
+        let graph = repository.load(graph_id).await.unwrap();
+        assert_eq!(graph.nodes()[&node_id].metadata, expected);
+    }
+
+    #[tokio::test]
+    async fn test_remove_node_events_share_correlation_group() {
+        let handler = GraphCommandHandlerImpl::new(Arc::new(InMemoryGraphRepository::new()));
+        let created = handler
+            .handle_caused_command(CausedCommand::new(GraphCommand::CreateGraph {
+                name: "Grouped".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            }))
+            .await
+            .unwrap();
+        let graph_id = match &created[0].event {
+            GraphDomainEvent::GraphCreated(e) => e.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+        let mut node_ids = Vec::new();
+        let mut added = Vec::new();
+        for _ in 0..2 {
+            let events = handler
+                .handle_caused_command(CausedCommand::new(GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "task".to_string(),
+                    metadata: HashMap::new(),
+                }))
+                .await
+                .unwrap();
+            match &events[0].event {
+                GraphDomainEvent::NodeAdded(e) => node_ids.push(e.node_id),
+                _ => panic!("Expected NodeAdded event"),
+            }
+            added.extend(events);
+        }
+        handler
+            .handle_caused_command(CausedCommand::new(GraphCommand::AddEdge {
+                graph_id,
+                source_id: node_ids[0],
+                target_id: node_ids[1],
+                edge_type: "sequence".to_string(),
+                metadata: HashMap::new(),
+                relationship: None,
+            }))
+            .await
+            .unwrap();
+
+        // Removing a node also removes its edge, in the same group
+        let removed = handler
+            .handle_caused_command(CausedCommand::new(GraphCommand::RemoveNode {
+                graph_id,
+                node_id: node_ids[0],
+            }))
+            .await
+            .unwrap();
+        assert_eq!(removed.len(), 2);
+        assert_eq!(removed[0].correlation_group, removed[1].correlation_group);
+        assert_ne!(removed[0].correlation_group, added[0].correlation_group);
+        assert_ne!(removed[0].event_id, removed[1].event_id);
+    }
+
+    #[tokio::test]
+    async fn test_split_node_moves_outgoing_edges_to_second_half() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+        let built = crate::builder::GraphBuilder::new("Pipeline")
+            .node("source", "task")
+            .node("stage", "task")
+            .node("sink", "task")
+            .edge("source", "stage", "feeds")
+            .edge("stage", "sink", "feeds")
+            .execute(&handler)
+            .await
+            .unwrap();
+        let (graph_id, stage) = (built.graph_id, built.nodes["stage"]);
+        let graph = repository.load(graph_id).await.unwrap();
+        let edge_into = |target: NodeId| {
+            graph.edges().values().find(|edge| edge.target_id == target).unwrap().id
+        };
+        let (incoming, outgoing) = (edge_into(stage), edge_into(built.nodes["sink"]));
+
+        let events = handler
+            .handle_graph_command(GraphCommand::SplitNode {
+                graph_id,
+                node_id: stage,
+                incoming_to_first: vec![incoming],
+                outgoing_to_second: vec![outgoing],
+            })
+            .await
+            .unwrap();
+        let second = match &events[0] {
+            GraphDomainEvent::NodeAdded(e) => e.node_id,
+            _ => panic!("Expected NodeAdded event"),
+        };
+        assert!(matches!(&events[1], GraphDomainEvent::EdgeUpdated(e)
+            if e.endpoints == Some((second, built.nodes["sink"]))));
+        assert!(matches!(&events[2], GraphDomainEvent::EdgeAdded(e)
+            if (e.source, e.target) == (stage, second) && e.edge_type == SPLIT_EDGE_TYPE));
+
+        let graph = repository.load(graph_id).await.unwrap();
+        assert_eq!(graph.edges()[&incoming].target_id, stage);
+        assert_eq!(graph.edges()[&outgoing].source_id, second);
+        assert_eq!(graph.nodes()[&second].node_type, "task");
+
+        // An edge listed on the wrong side is rejected
+        let result = handler
+            .handle_graph_command(GraphCommand::SplitNode {
+                graph_id,
+                node_id: stage,
+                incoming_to_first: vec![],
+                outgoing_to_second: vec![incoming],
+            })
+            .await;
+        assert!(matches!(result, Err(GraphCommandError::InvalidCommand(_))));
+    }
+
+    #[tokio::test]
+    async fn test_split_node_moves_unlisted_incoming_edges_to_second_half() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+        let built = crate::builder::GraphBuilder::new("Fan-in")
+            .node("left", "task")
+            .node("right", "task")
+            .node("stage", "task")
+            .edge("left", "stage", "feeds")
+            .edge("right", "stage", "feeds")
+            .execute(&handler)
+            .await
+            .unwrap();
+        let (graph_id, stage) = (built.graph_id, built.nodes["stage"]);
+        let graph = repository.load(graph_id).await.unwrap();
+        let edge_from = |source: NodeId| {
+            graph.edges().values().find(|edge| edge.source_id == source).unwrap().id
+        };
+        let (kept, moved) = (edge_from(built.nodes["left"]), edge_from(built.nodes["right"]));
+
+        let events = handler
+            .handle_graph_command(GraphCommand::SplitNode {
+                graph_id,
+                node_id: stage,
+                incoming_to_first: vec![kept],
+                outgoing_to_second: vec![],
+            })
+            .await
+            .unwrap();
+        let second = match &events[0] {
+            GraphDomainEvent::NodeAdded(e) => e.node_id,
+            _ => panic!("Expected NodeAdded event"),
+        };
+        assert_eq!(events.len(), 3);
+        assert!(matches!(&events[1], GraphDomainEvent::EdgeUpdated(e)
+            if e.edge_id == moved && e.endpoints == Some((built.nodes["right"], second))));
+
+        let graph = repository.load(graph_id).await.unwrap();
+        assert_eq!(graph.edges()[&kept].target_id, stage);
+        assert_eq!(graph.edges()[&moved].target_id, second);
+    }
+
+    #[tokio::test]
+    async fn test_contract_edge_in_path_leaves_two_nodes() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+        let built = crate::builder::GraphBuilder::new("Path")
+            .node_with_metadata(
+                "a",
+                "task",
+                HashMap::from([("owner".to_string(), serde_json::json!("ops"))]),
+            )
+            .node_with_metadata(
+                "b",
+                "task",
+                HashMap::from([("label".to_string(), serde_json::json!("B"))]),
+            )
+            .node("c", "task")
+            .edge("a", "b", "next")
+            .edge("b", "c", "next")
+            .execute(&handler)
+            .await
+            .unwrap();
+        let (graph_id, a, b, c) =
+            (built.graph_id, built.nodes["a"], built.nodes["b"], built.nodes["c"]);
+        let graph = repository.load(graph_id).await.unwrap();
+        let edge_from = |source: NodeId| {
+            graph.edges().values().find(|edge| edge.source_id == source).unwrap().id
+        };
+        let (contracted, surviving) = (edge_from(a), edge_from(b));
+
+        let events = handler
+            .handle_graph_command(GraphCommand::ContractEdge {
+                graph_id,
+                edge_id: contracted,
+                metadata_merge: ConflictResolution::Merge,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(&events[0], GraphDomainEvent::EdgeRemoved(e) if e.edge_id == contracted));
+        assert!(matches!(&events[1], GraphDomainEvent::EdgeUpdated(e)
+            if e.edge_id == surviving && e.endpoints == Some((a, c))));
+        assert!(matches!(&events[2], GraphDomainEvent::NodeRemoved(e) if e.node_id == b));
+
+        let graph = repository.load(graph_id).await.unwrap();
+        assert_eq!(graph.nodes().len(), 2);
+        assert_eq!(graph.edges().len(), 1);
+        let edge = &graph.edges()[&surviving];
+        assert_eq!((edge.source_id, edge.target_id), (a, c));
+        assert_eq!(graph.nodes()[&a].metadata["owner"], serde_json::json!("ops"));
+        assert_eq!(graph.nodes()[&a].metadata["label"], serde_json::json!("B"));
+    }
+
+    #[tokio::test]
+    async fn test_set_edge_weight_updates_weighted_adjacency() {
+        use crate::projections::{EdgeListProjection, GraphProjection};
+
+        let handler = GraphCommandHandlerImpl::new(Arc::new(InMemoryGraphRepository::new()));
+        let mut projection = EdgeListProjection::new();
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Weighted".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let mut node_ids = Vec::new();
+        for _ in 0..2 {
+            let events = handler
+                .handle_graph_command(GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "task".to_string(),
+                    metadata: HashMap::new(),
+                })
+                .await
+                .unwrap();
+            match &events[0] {
+                GraphDomainEvent::NodeAdded(event) => node_ids.push(event.node_id),
+                _ => panic!("Expected NodeAdded event"),
+            }
+        }
+
+        let events = handler
+            .handle_graph_command(GraphCommand::AddEdge {
+                graph_id,
+                source_id: node_ids[0],
+                target_id: node_ids[1],
+                edge_type: "sequence".to_string(),
+                metadata: HashMap::new(),
+                relationship: None,
+            })
+            .await
+            .unwrap();
+        let edge_id = match &events[0] {
+            GraphDomainEvent::EdgeAdded(event) => event.edge_id,
+            _ => panic!("Expected EdgeAdded event"),
+        };
+        projection.handle_graph_events(events).await.unwrap();
+        assert_eq!(projection.get_edge_weight(&edge_id), Some(1.0));
+
+        let events = handler
+            .handle_graph_command(GraphCommand::SetEdgeWeight {
+                graph_id,
+                edge_id,
+                weight: 2.5,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(events[0], GraphDomainEvent::EdgeUpdated(_)));
+        projection.handle_graph_events(events).await.unwrap();
+
+        let adjacency = projection.get_weighted_adjacency_list(&graph_id);
+        assert_eq!(adjacency[&node_ids[0]], vec![(node_ids[1], 2.5)]);
+
+        // Negative weights are rejected
+        let result = handler
+            .handle_graph_command(GraphCommand::SetEdgeWeight {
+                graph_id,
+                edge_id,
+                weight: -1.0,
+            })
+            .await;
+        assert!(matches!(result, Err(GraphCommandError::InvalidCommand(_))));
+        assert_eq!(projection.get_edge_weight(&edge_id), Some(2.5));
+    }
+
+    #[tokio::test]
+    async fn test_add_edge_with_explicit_relationship() {
+        use crate::components::EdgeRelationship;
+
+        let handler = GraphCommandHandlerImpl::new(Arc::new(InMemoryGraphRepository::new()));
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Dependencies".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let mut node_ids = Vec::new();
+        for _ in 0..2 {
+            let events = handler
+                .handle_graph_command(GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "module".to_string(),
+                    metadata: HashMap::new(),
+                })
+                .await
+                .unwrap();
+            match &events[0] {
+                GraphDomainEvent::NodeAdded(event) => node_ids.push(event.node_id),
+                _ => panic!("Expected NodeAdded event"),
+            }
+        }
+
+        let dependency = EdgeRelationship::Dependency {
+            dependency_type: "import".to_string(),
+            strength: 0.8,
+        };
+        let mut relationships = Vec::new();
+        for relationship in [Some(dependency.clone()), None] {
+            let events = handler
+                .handle_graph_command(GraphCommand::AddEdge {
+                    graph_id,
+                    source_id: node_ids[0],
+                    target_id: node_ids[1],
+                    edge_type: "uses".to_string(),
+                    metadata: HashMap::new(),
+                    relationship,
+                })
+                .await
+                .unwrap();
+            match &events[0] {
+                GraphDomainEvent::EdgeAdded(event) => {
+                    relationships.push(event.relationship.clone())
+                }
+                _ => panic!("Expected EdgeAdded event"),
+            }
+        }
+
+        assert_eq!(relationships[0], dependency);
+        // Without an explicit relationship the edge stays an association
+        assert_eq!(relationships[1], EdgeRelationship::association("uses"));
+    }
+
+    #[tokio::test]
+    async fn test_import_structure_saves_once() {
+        use crate::queries::{EdgeInfo, NodeInfo};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Repository counting the saves made through it
+        struct CountingRepository {
+            inner: InMemoryGraphRepository,
+            saves: AtomicUsize,
+        }
+
+        #[async_trait]
+        impl GraphRepository for CountingRepository {
+            async fn load(&self, graph_id: GraphId) -> GraphCommandResult<Graph> {
+                self.inner.load(graph_id).await
+            }
+            async fn save(&self, graph: &Graph) -> GraphCommandResult<()> {
+                self.saves.fetch_add(1, Ordering::SeqCst);
+                self.inner.save(graph).await
+            }
+            async fn exists(&self, graph_id: GraphId) -> GraphCommandResult<bool> {
+                self.inner.exists(graph_id).await
+            }
+            async fn next_graph_id(&self) -> GraphCommandResult<GraphId> {
+                self.inner.next_graph_id().await
+            }
+            async fn next_node_id(&self) -> GraphCommandResult<NodeId> {
+                self.inner.next_node_id().await
+            }
+            async fn next_edge_id(&self) -> GraphCommandResult<EdgeId> {
+                self.inner.next_edge_id().await
+            }
+        }
+
+        let repository = Arc::new(CountingRepository {
+            inner: InMemoryGraphRepository::new(),
+            saves: AtomicUsize::new(0),
+        });
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+        let events = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Pipeline".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let GraphDomainEvent::GraphCreated(created) = &events[0] else {
+            panic!("Expected GraphCreated event");
+        };
+        let graph_id = created.graph_id;
+        repository.saves.store(0, Ordering::SeqCst);
+
+        // A chain of five stages
+        let nodes: Vec<NodeInfo> = (0..5)
+            .map(|_| NodeInfo::new(NodeId::new(), graph_id, "stage"))
+            .collect();
+        let edges: Vec<EdgeInfo> = nodes
+            .windows(2)
+            .map(|pair| {
+                EdgeInfo::new(EdgeId::new(), graph_id, pair[0].node_id, pair[1].node_id, "next")
+            })
+            .collect();
+        let structure = GraphStructure {
+            nodes: nodes.clone(),
+            edges,
+            adjacency_list: HashMap::new(),
+        };
+
+        let events = handler
+            .handle_graph_command(GraphCommand::ImportStructure {
+                graph_id,
+                structure,
+            })
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 9);
+        assert!(events[..5].iter().all(|e| matches!(e, GraphDomainEvent::NodeAdded(_))));
+        assert!(events[5..].iter().all(|e| matches!(e, GraphDomainEvent::EdgeAdded(_))));
+        assert_eq!(repository.saves.load(Ordering::SeqCst), 1);
+
+        // An edge to a node in neither the structure nor the graph rejects the whole import
+        let fresh = NodeInfo::new(NodeId::new(), graph_id, "stage");
+        let dangling = GraphStructure {
+            edges: vec![EdgeInfo::new(
+                EdgeId::new(),
+                graph_id,
+                fresh.node_id,
+                NodeId::new(),
+                "next",
+            )],
+            nodes: vec![fresh],
+            adjacency_list: HashMap::new(),
+        };
+        let result = handler
+            .handle_graph_command(GraphCommand::ImportStructure {
+                graph_id,
+                structure: dangling,
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(GraphCommandError::InvalidCommand(ref message)) if message.contains("missing node")
+        ));
+        assert_eq!(repository.saves.load(Ordering::SeqCst), 1);
+        assert_eq!(repository.inner.load(graph_id).await.unwrap().node_count(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_reverse_edges_flips_projection() {
+        use crate::projections::{EdgeListProjection, GraphProjection};
+
+        let handler = GraphCommandHandlerImpl::new(Arc::new(InMemoryGraphRepository::new()));
+        let mut projection = EdgeListProjection::new();
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Transposed".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let mut node_ids = Vec::new();
+        for _ in 0..2 {
+            let events = handler
+                .handle_graph_command(GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "task".to_string(),
+                    metadata: HashMap::new(),
+                })
+                .await
+                .unwrap();
+            match &events[0] {
+                GraphDomainEvent::NodeAdded(event) => node_ids.push(event.node_id),
+                _ => panic!("Expected NodeAdded event"),
+            }
+        }
+
+        let mut edge_ids = Vec::new();
+        for (source_id, target_id) in [(node_ids[0], node_ids[1]), (node_ids[0], node_ids[0])] {
+            let events = handler
+                .handle_graph_command(GraphCommand::AddEdge {
+                    graph_id,
+                    source_id,
+                    target_id,
+                    edge_type: "sequence".to_string(),
+                    metadata: HashMap::new(),
+                    relationship: None,
+                })
+                .await
+                .unwrap();
+            match &events[0] {
+                GraphDomainEvent::EdgeAdded(event) => edge_ids.push(event.edge_id),
+                _ => panic!("Expected EdgeAdded event"),
+            }
+            projection.handle_graph_events(events).await.unwrap();
+        }
+
+        let events = handler
+            .handle_graph_command(GraphCommand::ReverseEdges { graph_id })
+            .await
+            .unwrap();
+        // The self-loop is left alone
+        assert_eq!(events.len(), 1);
+        projection.handle_graph_events(events).await.unwrap();
+
+        let edge = projection.get_edge(&edge_ids[0]).unwrap();
+        assert_eq!((edge.source_id, edge.target_id), (node_ids[1], node_ids[0]));
+        assert_eq!(projection.get_outgoing_edges(&node_ids[1]).len(), 1);
+        assert_eq!(projection.get_incoming_edges(&node_ids[1]).len(), 0);
+        let self_loop = projection.get_edge(&edge_ids[1]).unwrap();
+        assert_eq!((self_loop.source_id, self_loop.target_id), (node_ids[0], node_ids[0]));
+    }
+
+    #[tokio::test]
+    async fn test_remove_node_removes_incident_edges() {
+        let handler = GraphCommandHandlerImpl::new(Arc::new(InMemoryGraphRepository::new()));
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Cascade".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let mut node_ids = Vec::new();
+        for _ in 0..3 {
+            let events = handler
+                .handle_graph_command(GraphCommand::AddNode {
+                    graph_id,
+                    node_type: "task".to_string(),
+                    metadata: HashMap::new(),
+                })
+                .await
+                .unwrap();
+            match &events[0] {
+                GraphDomainEvent::NodeAdded(event) => node_ids.push(event.node_id),
+                _ => panic!("Expected NodeAdded event"),
+            }
+        }
+
+        // 0 -> 1 -> 2
+        for (source, target) in [(0, 1), (1, 2)] {
+            handler
+                .handle_graph_command(GraphCommand::AddEdge {
+                    graph_id,
+                    source_id: node_ids[source],
+                    target_id: node_ids[target],
+                    edge_type: "sequence".to_string(),
+                    metadata: HashMap::new(),
+                    relationship: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let events = handler
+            .handle_graph_command(GraphCommand::RemoveNode {
+                graph_id,
+                node_id: node_ids[1],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], GraphDomainEvent::EdgeRemoved(_)));
+        assert!(matches!(events[1], GraphDomainEvent::EdgeRemoved(_)));
+        assert!(matches!(events[2], GraphDomainEvent::NodeRemoved(_)));
+    }
+
+    #[tokio::test]
+    async fn test_repair_graph_removes_orphaned_edges() {
+        let graph_id = GraphId::new();
+        let node_id = NodeId::new();
+        let orphaned_edge = EdgeId::new();
+
+        // Replayed history can leave an edge pointing at a node that was never added
+        let graph = Graph::from_events(
+            graph_id,
+            vec![
+                GraphDomainEvent::GraphCreated(GraphCreated {
+                    graph_id,
+                    name: "Migrated".to_string(),
+                    description: String::new(),
+                    graph_type: None,
+                    metadata: HashMap::new(),
+                    created_at: chrono::Utc::now(),
+                }),
+                GraphDomainEvent::NodeAdded(NodeAdded {
+                    graph_id,
+                    node_id,
+                    position: crate::value_objects::Position3D::default(),
+                    node_type: "task".to_string(),
+                    metadata: HashMap::new(),
+                    created_at: chrono::Utc::now(),
+                }),
+                GraphDomainEvent::EdgeAdded(EdgeAdded {
+                    graph_id,
+                    edge_id: orphaned_edge,
+                    source: node_id,
+                    target: NodeId::new(),
+                    relationship: crate::components::EdgeRelationship::Association {
+                        association_type: "link".to_string(),
+                    },
+                    edge_type: "link".to_string(),
+                    metadata: HashMap::new(),
+                    created_at: chrono::Utc::now(),
+                }),
+            ],
+        );
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        repository.save(&graph).await.unwrap();
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let events = handler
+            .handle_graph_command(GraphCommand::RepairGraph { graph_id })
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            GraphDomainEvent::EdgeRemoved(event) => assert_eq!(event.edge_id, orphaned_edge),
+            other => panic!("Expected EdgeRemoved event, got {other:?}"),
+        }
+
+        let events = handler
+            .handle_graph_command(GraphCommand::RepairGraph { graph_id })
+            .await
+            .unwrap();
+        assert!(events.is_empty());
+        assert_eq!(repository.load(graph_id).await.unwrap().edge_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_set_node_position_keeps_metadata() {
+        use crate::projections::{GraphProjection, NodeListProjection};
+        use crate::value_objects::Position3D;
+
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+        let mut projection = NodeListProjection::new();
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Layout".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let metadata = HashMap::from([
+            ("name".to_string(), serde_json::json!("Review")),
+            ("owner".to_string(), serde_json::json!("ops")),
+        ]);
+        let events = handler
+            .handle_graph_command(GraphCommand::AddNode {
+                graph_id,
+                node_type: "task".to_string(),
+                metadata: metadata.clone(),
+            })
+            .await
+            .unwrap();
+        let node_id = match &events[0] {
+            GraphDomainEvent::NodeAdded(event) => event.node_id,
+            _ => panic!("Expected NodeAdded event"),
+        };
+        projection.handle_graph_events(events).await.unwrap();
+
+        let position = Position3D::new(250.0, 120.0, 0.0);
+        let events = handler
+            .handle_graph_command(GraphCommand::SetNodePosition {
+                graph_id,
+                node_id,
+                position,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(events[0], GraphDomainEvent::NodeMoved(_)));
+        projection.handle_graph_events(events).await.unwrap();
+
+        let node = projection.get_node(&node_id).unwrap();
+        assert_eq!(node.position_3d, Some(position));
+        assert_eq!(node.metadata, metadata);
+        let graph = repository.load(graph_id).await.unwrap();
+        assert_eq!(graph.nodes()[&node_id].metadata, metadata);
+    }
+
+    #[tokio::test]
+    async fn test_events_carry_command_time() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Timing".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        // A saved change records the same time on the event and the aggregate
+        let events = handler
+            .handle_graph_command(GraphCommand::AddNode {
+                graph_id,
+                node_type: "task".to_string(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let (node_id, added_at) = match &events[0] {
+            GraphDomainEvent::NodeAdded(event) => (event.node_id, event.created_at),
+            _ => panic!("Expected NodeAdded event"),
+        };
+        assert_eq!(repository.load(graph_id).await.unwrap().last_modified(), added_at);
+
+        // A move is not saved, so it keeps its own time and leaves the aggregate alone
+        let before = chrono::Utc::now();
+        let events = handler
+            .handle_graph_command(GraphCommand::SetNodePosition {
+                graph_id,
+                node_id,
+                position: Position3D::new(1.0, 2.0, 0.0),
+            })
+            .await
+            .unwrap();
+        match &events[0] {
+            GraphDomainEvent::NodeMoved(event) => assert!(event.moved_at >= before),
+            _ => panic!("Expected NodeMoved event"),
+        }
+        assert_eq!(repository.load(graph_id).await.unwrap().last_modified(), added_at);
+    }
+
+    #[tokio::test]
+    async fn test_validate_only_leaves_repository_unchanged() {
+        let repository = Arc::new(InMemoryGraphRepository::new());
+        let handler = GraphCommandHandlerImpl::new(repository.clone());
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Draft".to_string(),
+                description: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let events = handler
+            .validate_only(GraphCommand::AddNode {
+                graph_id,
+                node_type: "task".to_string(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], GraphDomainEvent::NodeAdded(_)));
+        assert_eq!(repository.load(graph_id).await.unwrap().node_count(), 0);
+
+        // Validation failures surface without touching the repository either
+        let result = handler
+            .validate_only(GraphCommand::AddNode {
+                graph_id,
+                node_type: String::new(),
+                metadata: HashMap::new(),
+            })
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rename_graph_keeps_description() {
+        use crate::projections::{
+            EdgeListProjection, GraphProjection, GraphSummaryProjection, NodeListProjection,
+        };
+        use crate::queries::{GraphQueryHandler, GraphQueryHandlerImpl};
+
+        let handler = GraphCommandHandlerImpl::new(Arc::new(InMemoryGraphRepository::new()));
+        let mut summary = GraphSummaryProjection::new();
+
+        let created = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Draft".to_string(),
+                description: "Order fulfilment".to_string(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &created[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+        summary.handle_graph_events(created).await.unwrap();
+
+        let blank = handler
+            .handle_graph_command(GraphCommand::RenameGraph {
+                graph_id,
+                new_name: "  ".to_string(),
+            })
+            .await;
+        assert!(matches!(blank, Err(GraphCommandError::InvalidCommand(_))));
+
+        let renamed = handler
+            .handle_graph_command(GraphCommand::RenameGraph {
+                graph_id,
+                new_name: "Fulfilment".to_string(),
+            })
+            .await
+            .unwrap();
+        summary.handle_graph_events(renamed).await.unwrap();
+
+        let queries = GraphQueryHandlerImpl::with_projections(
+            summary,
+            NodeListProjection::new(),
+            EdgeListProjection::new(),
+        );
+        let info = queries.get_graph(graph_id).await.unwrap();
+        assert_eq!(info.name, "Fulfilment");
+        assert_eq!(info.description, "Order fulfilment");
+    }
 }
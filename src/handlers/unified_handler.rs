@@ -3,16 +3,26 @@
 use crate::{
     abstraction::{EdgeData, GraphType, NodeData, Position3D},
     aggregate::abstract_graph::AbstractGraph,
-    commands::{EdgeCommand, GraphCommand, GraphCommandError, GraphCommandResult, NodeCommand},
+    commands::{
+        contracted_metadata, group_member_failures, metadata_failures, split_failures,
+        split_moves, structure_failures, EdgeCommand, GraphCommand, GraphCommandError,
+        GraphCommandResult, NodeCommand, SPLIT_EDGE_TYPE,
+    },
     domain_events::GraphDomainEvent,
-    events::{EdgeAdded, EdgeRemoved, GraphCreated, NodeAdded, NodeRemoved},
+    events::{
+        EdgeAdded, EdgeRemoved, EdgeUpdated, GraphCreated, GraphUpdated, HyperEdgeAdded,
+        NodeAdded, NodeMoved, NodeRemoved, NodeUpdated,
+    },
     handlers::GraphCommandHandler,
-    EdgeId, GraphId, NodeId,
+    groups, tags, EdgeId, GraphId, NodeId,
 };
 use async_trait::async_trait;
 use cim_domain::{CommandAcknowledgment, CommandEnvelope, CommandHandler, CommandStatus};
 use std::sync::Arc;
 
+/// Graph kinds accepted under the `graph_type` metadata key
+const GRAPH_KINDS: [&str; 5] = ["context", "concept", "workflow", "ipld", "generic"];
+
 /// Unified repository that can handle both concrete and abstract graphs
 #[async_trait]
 pub trait UnifiedGraphRepository: Send + Sync {
@@ -42,6 +52,46 @@ pub trait UnifiedGraphRepository: Send + Sync {
     async fn get_graph_type(&self, graph_id: GraphId) -> GraphCommandResult<Option<String>>;
 }
 
+/// Repository that reads through to another repository and discards saves
+struct DryRunUnifiedRepository {
+    inner: Arc<dyn UnifiedGraphRepository>,
+}
+
+#[async_trait]
+impl UnifiedGraphRepository for DryRunUnifiedRepository {
+    async fn load_graph(
+        &self,
+        graph_id: GraphId,
+        graph_type: Option<&str>,
+    ) -> GraphCommandResult<AbstractGraph> {
+        self.inner.load_graph(graph_id, graph_type).await
+    }
+
+    async fn save_graph(&self, _graph: &AbstractGraph) -> GraphCommandResult<()> {
+        Ok(())
+    }
+
+    async fn exists(&self, graph_id: GraphId) -> GraphCommandResult<bool> {
+        self.inner.exists(graph_id).await
+    }
+
+    async fn next_graph_id(&self) -> GraphCommandResult<GraphId> {
+        self.inner.next_graph_id().await
+    }
+
+    async fn next_node_id(&self) -> GraphCommandResult<NodeId> {
+        self.inner.next_node_id().await
+    }
+
+    async fn next_edge_id(&self) -> GraphCommandResult<EdgeId> {
+        self.inner.next_edge_id().await
+    }
+
+    async fn get_graph_type(&self, graph_id: GraphId) -> GraphCommandResult<Option<String>> {
+        self.inner.get_graph_type(graph_id).await
+    }
+}
+
 /// Unified graph command handler that works with all graph types
 pub struct UnifiedGraphCommandHandler {
     repository: Arc<dyn UnifiedGraphRepository>,
@@ -59,13 +109,15 @@ impl UnifiedGraphCommandHandler {
         graph_id: Option<GraphId>,
         metadata: &std::collections::HashMap<String, serde_json::Value>,
     ) -> GraphCommandResult<String> {
-        // First check metadata
-        if let Some(graph_type) = metadata
-            .get("graph_type")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-        {
-            return Ok(graph_type);
+        // First check metadata; a graph type that is given must name a known kind
+        if let Some(value) = metadata.get("graph_type") {
+            return match value.as_str() {
+                Some(graph_type) if GRAPH_KINDS.contains(&graph_type) => Ok(graph_type.to_string()),
+                _ => Err(GraphCommandError::InvalidCommand(format!(
+                    "Unknown graph type {value}, expected one of: {}",
+                    GRAPH_KINDS.join(", ")
+                ))),
+            };
         }
 
         // If we have a graph_id, check existing graph
@@ -79,6 +131,42 @@ impl UnifiedGraphCommandHandler {
         Ok("context".to_string())
     }
 
+    /// Replace a node's metadata, emitting a NodeUpdated with the changed and removed keys
+    async fn replace_node_metadata(
+        &self,
+        graph_id: GraphId,
+        node_id: NodeId,
+        new_metadata: std::collections::HashMap<String, serde_json::Value>,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        // Load graph
+        let graph_type_str = self
+            .determine_graph_type(Some(graph_id), &new_metadata)
+            .await?;
+        let mut graph = self
+            .repository
+            .load_graph(graph_id, Some(&graph_type_str))
+            .await?;
+
+        // Get old node data
+        let old_data = graph.get_node(node_id)?;
+
+        // Update node in place, keeping its hyperedges
+        graph.replace_node_metadata(node_id, new_metadata.clone())?;
+
+        // Save graph
+        self.repository.save_graph(&graph).await?;
+
+        // Generate event
+        let event = GraphDomainEvent::NodeUpdated(NodeUpdated::replacing_metadata(
+            graph_id,
+            node_id,
+            &old_data.metadata,
+            new_metadata,
+        ));
+
+        Ok(vec![event])
+    }
+
     /// Process a graph command
     pub async fn process_graph_command(
         &self,
@@ -94,12 +182,13 @@ impl UnifiedGraphCommandHandler {
                 let graph_id = self.repository.next_graph_id().await?;
                 let created_at = chrono::Utc::now();
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if name.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Graph name cannot be empty".to_string(),
-                    ));
+                    failures.push("Graph name cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Determine graph type
                 let graph_type_str = self.determine_graph_type(None, &metadata).await?;
@@ -110,7 +199,8 @@ impl UnifiedGraphCommandHandler {
                     "concept" => GraphType::new_concept(graph_id, &name),
                     "workflow" => GraphType::new_workflow(graph_id, &name),
                     "ipld" => GraphType::new_ipld(graph_id),
-                    _ => GraphType::new_context(graph_id, &name), // Default
+                    "generic" => GraphType::new_generic(graph_id, &name),
+                    other => unreachable!("determine_graph_type returned unknown kind {other}"),
                 };
 
                 // Create new abstract graph
@@ -145,12 +235,13 @@ impl UnifiedGraphCommandHandler {
                     .await?;
                 let node_id = self.repository.next_node_id().await?;
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if node_type.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Node type cannot be empty".to_string(),
-                    ));
+                    failures.push("Node type cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Extract position from metadata
                 let position = metadata
@@ -185,6 +276,7 @@ impl UnifiedGraphCommandHandler {
                     ),
                     node_type,
                     metadata,
+                    created_at: chrono::Utc::now(),
                 });
 
                 Ok(vec![event])
@@ -207,7 +299,11 @@ impl UnifiedGraphCommandHandler {
                 self.repository.save_graph(&graph).await?;
 
                 // Generate event
-                let event = GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id });
+                let event = GraphDomainEvent::NodeRemoved(NodeRemoved {
+                    graph_id,
+                    node_id,
+                    removed_at: chrono::Utc::now(),
+                });
 
                 Ok(vec![event])
             }
@@ -218,6 +314,7 @@ impl UnifiedGraphCommandHandler {
                 target_id,
                 edge_type,
                 metadata,
+                relationship,
             } => {
                 // Load graph
                 let graph_type_str = self.determine_graph_type(Some(graph_id), &metadata).await?;
@@ -227,12 +324,13 @@ impl UnifiedGraphCommandHandler {
                     .await?;
                 let edge_id = self.repository.next_edge_id().await?;
 
-                // Validate input
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
                 if edge_type.trim().is_empty() {
-                    return Err(GraphCommandError::InvalidCommand(
-                        "Edge type cannot be empty".to_string(),
-                    ));
+                    failures.push("Edge type cannot be empty".to_string());
                 }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
 
                 // Create edge data
                 let edge_data = EdgeData {
@@ -252,11 +350,12 @@ impl UnifiedGraphCommandHandler {
                     edge_id,
                     source: source_id,
                     target: target_id,
-                    relationship: crate::components::EdgeRelationship::Association {
-                        association_type: edge_type.clone(),
-                    },
+                    relationship: relationship.unwrap_or_else(|| {
+                        crate::components::EdgeRelationship::association(&edge_type)
+                    }),
                     edge_type,
                     metadata,
+                    created_at: chrono::Utc::now(),
                 });
 
                 Ok(vec![event])
@@ -279,57 +378,589 @@ impl UnifiedGraphCommandHandler {
                 self.repository.save_graph(&graph).await?;
 
                 // Generate event
-                let event = GraphDomainEvent::EdgeRemoved(EdgeRemoved { graph_id, edge_id });
+                let event = GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+                    graph_id,
+                    edge_id,
+                    removed: None,
+                    removed_at: chrono::Utc::now(),
+                });
+
+                Ok(vec![event])
+            }
+
+            GraphCommand::SetEdgeWeight {
+                graph_id,
+                edge_id,
+                weight,
+            } => {
+                // Validate input
+                if weight.is_nan() || weight < 0.0 {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Edge weight must be non-negative, got {weight}"
+                    )));
+                }
+
+                // Load graph
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+
+                // Store the weight in the edge's metadata
+                let (mut edge, source, target) = graph.get_edge(edge_id)?;
+                edge.metadata.insert("weight".to_string(), serde_json::json!(weight));
+                graph.add_edge(edge_id, source, target, edge)?;
+
+                // Save graph
+                self.repository.save_graph(&graph).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+                    graph_id,
+                    edge_id,
+                    relationship: None,
+                    metadata: std::collections::HashMap::from([(
+                        "weight".to_string(),
+                        serde_json::json!(weight),
+                    )]),
+                    endpoints: None,
+                    updated_at: chrono::Utc::now(),
+                });
 
                 Ok(vec![event])
             }
 
-            GraphCommand::ChangeNodeMetadata {
+            GraphCommand::SetNodePosition {
                 graph_id,
                 node_id,
-                new_metadata,
+                position,
+            } => {
+                // Validate input
+                if ![position.x, position.y, position.z].iter().all(|v| v.is_finite()) {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Node position must be finite, got {position:?}"
+                    )));
+                }
+
+                // Load graph and check the node exists
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+                graph.get_node(node_id)?;
+
+                // Generate event
+                let event = GraphDomainEvent::NodeMoved(NodeMoved {
+                    graph_id,
+                    node_id,
+                    position,
+                    moved_at: chrono::Utc::now(),
+                });
+
+                Ok(vec![event])
+            }
+
+            GraphCommand::RepairGraph { graph_id } => {
+                // Load graph
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+
+                // Remove every edge left pointing at a missing node
+                let orphaned = graph.orphaned_edges();
+                if orphaned.is_empty() {
+                    return Ok(Vec::new());
+                }
+                for edge_id in &orphaned {
+                    graph.remove_edge(*edge_id)?;
+                }
+
+                // Save graph
+                self.repository.save_graph(&graph).await?;
+
+                // Generate events
+                Ok(orphaned
+                    .into_iter()
+                    .map(|edge_id| GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+                        graph_id,
+                        edge_id,
+                        removed: None,
+                        removed_at: chrono::Utc::now(),
+                    }))
+                    .collect())
+            }
+
+            GraphCommand::CreateGroup {
+                graph_id,
+                group_id,
+                member_nodes,
+            } => {
+                // Validate input
+                GraphCommandError::from_failures(group_member_failures(&member_nodes))?;
+
+                // Load graph
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+
+                // Record membership in each member's metadata
+                let membership = groups::group_metadata(group_id);
+                for node_id in &member_nodes {
+                    let mut metadata = graph.get_node(*node_id)?.metadata;
+                    metadata.extend(membership.clone());
+                    graph.replace_node_metadata(*node_id, metadata)?;
+                }
+
+                // Save graph
+                self.repository.save_graph(&graph).await?;
+
+                // Generate events
+                Ok(member_nodes
+                    .into_iter()
+                    .map(|node_id| {
+                        GraphDomainEvent::NodeUpdated(NodeUpdated {
+                            graph_id,
+                            node_id,
+                            position: None,
+                            metadata: membership.clone(),
+                            node_type: None,
+                            removed_keys: Vec::new(),
+                            updated_at: chrono::Utc::now(),
+                        })
+                    })
+                    .collect())
+            }
+
+            GraphCommand::ReverseEdges { graph_id } => {
+                // Load graph
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+
+                // Flip every edge except self-loops
+                let reversed: Vec<(EdgeId, NodeId, NodeId)> = graph
+                    .list_edges()
+                    .into_iter()
+                    .filter(|(_, _, source, target)| source != target)
+                    .map(|(edge_id, _, source, target)| (edge_id, target, source))
+                    .collect();
+                for (edge_id, source, target) in &reversed {
+                    graph.reconnect_edge(*edge_id, *source, *target)?;
+                }
+
+                // Save graph
+                self.repository.save_graph(&graph).await?;
+
+                // Generate events
+                Ok(reversed
+                    .into_iter()
+                    .map(|(edge_id, source, target)| {
+                        GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+                            graph_id,
+                            edge_id,
+                            relationship: None,
+                            metadata: std::collections::HashMap::new(),
+                            endpoints: Some((source, target)),
+                            updated_at: chrono::Utc::now(),
+                        })
+                    })
+                    .collect())
+            }
+
+            GraphCommand::SplitNode {
+                graph_id,
+                node_id,
+                incoming_to_first,
+                outgoing_to_second,
             } => {
                 // Load graph
                 let graph_type_str = self
-                    .determine_graph_type(Some(graph_id), &new_metadata)
+                    .determine_graph_type(Some(graph_id), &Default::default())
                     .await?;
                 let mut graph = self
                     .repository
                     .load_graph(graph_id, Some(&graph_type_str))
                     .await?;
+                let node = graph.get_node(node_id)?;
+
+                // Validate input, reporting every failure together
+                GraphCommandError::from_failures(split_failures(
+                    node_id,
+                    &incoming_to_first,
+                    &outgoing_to_second,
+                    |edge_id| graph.get_edge_endpoints(edge_id).ok(),
+                ))?;
+
+                // Add the second half and its link, and move the edges it takes over
+                let second_id = self.repository.next_node_id().await?;
+                let link_edge_id = self.repository.next_edge_id().await?;
+                let edges = graph.list_edges().into_iter();
+                let moved = split_moves(
+                    node_id,
+                    second_id,
+                    &incoming_to_first,
+                    &outgoing_to_second,
+                    edges.map(|(edge_id, _, source, target)| (edge_id, source, target)),
+                );
+                graph.add_node(second_id, node.clone())?;
+                graph.add_edge(
+                    link_edge_id,
+                    node_id,
+                    second_id,
+                    EdgeData {
+                        edge_type: SPLIT_EDGE_TYPE.to_string(),
+                        metadata: std::collections::HashMap::new(),
+                    },
+                )?;
+                for (edge_id, source, target) in &moved {
+                    graph.reconnect_edge(*edge_id, *source, *target)?;
+                }
 
-                // Get old node data
-                let old_data = graph.get_node(node_id)?;
+                // Save graph
+                self.repository.save_graph(&graph).await?;
 
-                // Create new node data with updated metadata
-                let new_data = NodeData {
-                    node_type: old_data.node_type.clone(),
-                    position: old_data.position,
-                    metadata: new_metadata.clone(),
+                // Generate events
+                let second = NodeAdded {
+                    graph_id,
+                    node_id: second_id,
+                    position: node.position,
+                    node_type: node.node_type,
+                    metadata: node.metadata,
+                    created_at: chrono::Utc::now(),
                 };
+                Ok(super::split_events(
+                    graph_id,
+                    node_id,
+                    second,
+                    &moved,
+                    link_edge_id,
+                    chrono::Utc::now(),
+                ))
+            }
 
-                // Update node (remove old, add new)
-                graph.remove_node(node_id)?;
-                graph.add_node(node_id, new_data)?;
+            GraphCommand::ContractEdge {
+                graph_id,
+                edge_id,
+                metadata_merge,
+            } => {
+                // Load graph
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+                let (kept, merged) = graph.get_edge_endpoints(edge_id)?;
+                if kept == merged {
+                    return Err(GraphCommandError::InvalidCommand(format!(
+                        "Edge {edge_id} is a self-loop and cannot be contracted"
+                    )));
+                }
+
+                // Combine the endpoints' metadata
+                let kept_metadata = graph.get_node(kept)?.metadata;
+                let metadata = contracted_metadata(
+                    metadata_merge,
+                    &kept_metadata,
+                    &graph.get_node(merged)?.metadata,
+                )?;
+
+                // Move the merged node's other edges onto the kept node, then drop it
+                let rewire = |node_id: NodeId| if node_id == merged { kept } else { node_id };
+                let moved: Vec<(EdgeId, NodeId, NodeId)> = graph
+                    .list_edges()
+                    .into_iter()
+                    .filter(|(id, _, source, target)| {
+                        *id != edge_id && (*source == merged || *target == merged)
+                    })
+                    .map(|(id, _, source, target)| (id, rewire(source), rewire(target)))
+                    .collect();
+                for (id, source, target) in &moved {
+                    graph.reconnect_edge(*id, *source, *target)?;
+                }
+                graph.remove_edge(edge_id)?;
+                graph.remove_node(merged)?;
+                let changed = (metadata != kept_metadata).then_some(metadata);
+                if let Some(metadata) = &changed {
+                    graph.replace_node_metadata(kept, metadata.clone())?;
+                }
 
                 // Save graph
                 self.repository.save_graph(&graph).await?;
 
                 // Generate events
-                let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id });
-                let add_event = GraphDomainEvent::NodeAdded(NodeAdded {
+                let contracted = GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+                    graph_id,
+                    edge_id,
+                    removed: None,
+                    removed_at: chrono::Utc::now(),
+                });
+                Ok(super::contract_events(
+                    graph_id,
+                    contracted,
+                    &moved,
+                    merged,
+                    kept,
+                    changed,
+                    chrono::Utc::now(),
+                ))
+            }
+
+            GraphCommand::ImportStructure {
+                graph_id,
+                structure,
+            } => {
+                // Load graph
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+
+                // Validate the whole structure before touching the graph
+                let existing = graph.list_nodes().into_iter().map(|(id, _)| id).collect();
+                GraphCommandError::from_failures(structure_failures(&structure, &existing))?;
+
+                // Add nodes, then the edges between them
+                for node in &structure.nodes {
+                    let node_data = NodeData {
+                        node_type: node.node_type.clone(),
+                        position: node
+                            .position_3d
+                            .or_else(|| node.position_2d.map(Position3D::from))
+                            .unwrap_or_default(),
+                        metadata: node.metadata.clone(),
+                    };
+                    graph.add_node(node.node_id, node_data)?;
+                }
+                for edge in &structure.edges {
+                    let edge_data = EdgeData {
+                        edge_type: edge.edge_type.clone(),
+                        metadata: edge.metadata.clone(),
+                    };
+                    graph.add_edge(edge.edge_id, edge.source_id, edge.target_id, edge_data)?;
+                }
+
+                // Save graph once for the whole import
+                self.repository.save_graph(&graph).await?;
+
+                Ok(super::import_events(graph_id, &structure, chrono::Utc::now()))
+            }
+
+            GraphCommand::ChangeNodeMetadata {
+                graph_id,
+                node_id,
+                new_metadata,
+            } => {
+                self.replace_node_metadata(graph_id, node_id, new_metadata).await
+            }
+
+            GraphCommand::MergeNodeMetadata {
+                graph_id,
+                node_id,
+                metadata,
+            } => {
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+
+                let mut merged = graph.get_node(node_id)?.metadata;
+                merged.extend(metadata);
+                self.replace_node_metadata(graph_id, node_id, merged).await
+            }
+
+            GraphCommand::TagNode {
+                graph_id,
+                node_id,
+                tag,
+            } => {
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+                let node = graph.get_node(node_id)?;
+
+                // Already tagged nodes produce no events
+                match tags::with_tag(&node.metadata, &tag)? {
+                    Some(new_metadata) => {
+                        self.replace_node_metadata(graph_id, node_id, new_metadata).await
+                    }
+                    None => Ok(Vec::new()),
+                }
+            }
+
+            GraphCommand::UntagNode {
+                graph_id,
+                node_id,
+                tag,
+            } => {
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+                let node = graph.get_node(node_id)?;
+
+                match tags::without_tag(&node.metadata, &tag)? {
+                    Some(new_metadata) => {
+                        self.replace_node_metadata(graph_id, node_id, new_metadata).await
+                    }
+                    None => Ok(Vec::new()),
+                }
+            }
+
+            GraphCommand::RelabelNodeType {
+                graph_id,
+                node_id,
+                new_node_type,
+            } => {
+                // Validate input
+                if new_node_type.trim().is_empty() {
+                    return Err(GraphCommandError::InvalidCommand(
+                        "Node type cannot be empty".to_string(),
+                    ));
+                }
+
+                // Load graph
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+
+                // Store the node again under its new type
+                let mut node = graph.get_node(node_id)?;
+                node.node_type = new_node_type.clone();
+                graph.add_node(node_id, node)?;
+
+                // Save graph
+                self.repository.save_graph(&graph).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::NodeUpdated(NodeUpdated {
                     graph_id,
                     node_id,
-                    position: crate::value_objects::Position3D::new(
-                        old_data.position.x,
-                        old_data.position.y,
-                        old_data.position.z,
-                    ),
-                    node_type: old_data.node_type,
-                    metadata: new_metadata,
+                    position: None,
+                    metadata: std::collections::HashMap::new(),
+                    node_type: Some(new_node_type),
+                    removed_keys: Vec::new(),
+                    updated_at: chrono::Utc::now(),
                 });
 
-                Ok(vec![remove_event, add_event])
+                Ok(vec![event])
+            }
+
+            GraphCommand::RenameGraph { graph_id, new_name } => {
+                // Validate input
+                if new_name.trim().is_empty() {
+                    return Err(GraphCommandError::InvalidCommand(
+                        "Graph name cannot be empty".to_string(),
+                    ));
+                }
+
+                // Load graph
+                let graph_type_str = self
+                    .determine_graph_type(Some(graph_id), &Default::default())
+                    .await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+
+                // Rename graph
+                graph.rename(new_name.clone())?;
+
+                // Save graph
+                self.repository.save_graph(&graph).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::GraphUpdated(GraphUpdated {
+                    graph_id,
+                    name: Some(new_name),
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                    updated_at: chrono::Utc::now(),
+                });
+
+                Ok(vec![event])
+            }
+
+            GraphCommand::AddHyperEdge {
+                graph_id,
+                node_ids,
+                edge_type,
+                metadata,
+            } => {
+                // Load graph
+                let graph_type_str = self.determine_graph_type(Some(graph_id), &metadata).await?;
+                let mut graph = self
+                    .repository
+                    .load_graph(graph_id, Some(&graph_type_str))
+                    .await?;
+                let edge_id = self.repository.next_edge_id().await?;
+
+                // Validate input, reporting every failure together
+                let mut failures = Vec::new();
+                if edge_type.trim().is_empty() {
+                    failures.push("Edge type cannot be empty".to_string());
+                }
+                failures.extend(metadata_failures(&metadata));
+                GraphCommandError::from_failures(failures)?;
+
+                // Add hyperedge to graph
+                graph.add_hyperedge(
+                    edge_id,
+                    node_ids.clone(),
+                    edge_type.clone(),
+                    metadata.clone(),
+                )?;
+
+                // Save graph
+                self.repository.save_graph(&graph).await?;
+
+                // Generate event
+                let event = GraphDomainEvent::HyperEdgeAdded(HyperEdgeAdded {
+                    graph_id,
+                    edge_id,
+                    node_ids,
+                    edge_type,
+                    metadata,
+                    created_at: chrono::Utc::now(),
+                });
+
+                Ok(vec![event])
             }
         }
     }
@@ -375,6 +1006,17 @@ impl GraphCommandHandler for UnifiedGraphCommandHandler {
         self.process_graph_command(command, &envelope).await
     }
 
+    async fn validate_only(
+        &self,
+        command: GraphCommand,
+    ) -> GraphCommandResult<Vec<GraphDomainEvent>> {
+        let dry_run = UnifiedGraphCommandHandler::new(Arc::new(DryRunUnifiedRepository {
+            inner: self.repository.clone(),
+        }));
+        let envelope = CommandEnvelope::new(command.clone(), "unified-handler".to_string());
+        dry_run.process_graph_command(command, &envelope).await
+    }
+
     async fn handle_node_command(
         &self,
         command: NodeCommand,
@@ -431,6 +1073,7 @@ impl GraphCommandHandler for UnifiedGraphCommandHandler {
                     target_id,
                     edge_type,
                     metadata,
+                    relationship: None,
                 };
                 self.handle_graph_command(graph_command).await
             }
@@ -482,6 +1125,7 @@ mod tests {
                 GraphType::Concept(_) => "concept",
                 GraphType::Workflow(_) => "workflow",
                 GraphType::Ipld(_) => "ipld",
+                GraphType::Generic(_) => "generic",
             };
             graphs.insert(graph.id(), (graph.clone(), graph_type.to_string()));
             Ok(())
@@ -521,6 +1165,7 @@ mod tests {
             ("concept", "Concept Graph"),
             ("workflow", "Workflow Graph"),
             ("ipld", "IPLD Graph"),
+            ("generic", "Generic Graph"),
         ] {
             let mut metadata = HashMap::new();
             metadata.insert(
@@ -546,6 +1191,31 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_unknown_graph_type_is_rejected() {
+        let repository = Arc::new(MockUnifiedRepository::new());
+        let handler = UnifiedGraphCommandHandler::new(repository);
+
+        let result = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Typo".to_string(),
+                description: String::new(),
+                metadata: HashMap::from([("graph_type".to_string(), serde_json::json!("grpah"))]),
+            })
+            .await;
+        match result {
+            Err(GraphCommandError::InvalidCommand(message)) => {
+                assert!(message.contains("grpah"));
+                assert!(message.contains("context, concept, workflow, ipld"));
+            }
+            other => panic!("Expected InvalidCommand, got {other:?}"),
+        }
+
+        // Without the key the graph defaults to a context graph
+        let graph_type = handler.determine_graph_type(None, &HashMap::new()).await.unwrap();
+        assert_eq!(graph_type, "context");
+    }
+
     #[tokio::test]
     async fn test_unified_handler_with_nodes_and_edges() {
         let repository = Arc::new(MockUnifiedRepository::new());
@@ -612,6 +1282,7 @@ mod tests {
             target_id: node2_id,
             edge_type: "sequence".to_string(),
             metadata: HashMap::new(),
+            relationship: None,
         };
 
         let edge_events = handler
@@ -629,4 +1300,112 @@ mod tests {
             _ => panic!("Expected EdgeAdded event"),
         }
     }
+
+    #[tokio::test]
+    async fn test_contract_edge_is_saved() {
+        let repository = Arc::new(MockUnifiedRepository::new());
+        let handler = UnifiedGraphCommandHandler::new(repository.clone());
+
+        let events = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Contract".to_string(),
+                description: String::new(),
+                metadata: HashMap::from([("graph_type".to_string(), serde_json::json!("generic"))]),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &events[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let mut graph = repository.load_graph(graph_id, None).await.unwrap();
+        let [kept, merged, other] = [NodeId::new(), NodeId::new(), NodeId::new()];
+        for node_id in [kept, merged, other] {
+            let data = NodeData {
+                node_type: "step".to_string(),
+                position: Position3D::default(),
+                metadata: HashMap::new(),
+            };
+            graph.add_node(node_id, data).unwrap();
+        }
+        let [contracted, moved] = [EdgeId::new(), EdgeId::new()];
+        for (edge_id, source, target) in [(contracted, kept, merged), (moved, other, merged)] {
+            let data = EdgeData {
+                edge_type: "next".to_string(),
+                metadata: HashMap::new(),
+            };
+            graph.add_edge(edge_id, source, target, data).unwrap();
+        }
+        repository.save_graph(&graph).await.unwrap();
+
+        handler
+            .handle_graph_command(GraphCommand::ContractEdge {
+                graph_id,
+                edge_id: contracted,
+                metadata_merge: crate::abstraction::ConflictResolution::KeepFirst,
+            })
+            .await
+            .unwrap();
+
+        let graph = repository.load_graph(graph_id, None).await.unwrap();
+        assert_eq!(graph.get_edge_endpoints(moved).unwrap(), (other, kept));
+    }
+
+    #[tokio::test]
+    async fn test_split_node_is_saved() {
+        let repository = Arc::new(MockUnifiedRepository::new());
+        let handler = UnifiedGraphCommandHandler::new(repository.clone());
+
+        let events = handler
+            .handle_graph_command(GraphCommand::CreateGraph {
+                name: "Split".to_string(),
+                description: String::new(),
+                metadata: HashMap::from([("graph_type".to_string(), serde_json::json!("generic"))]),
+            })
+            .await
+            .unwrap();
+        let graph_id = match &events[0] {
+            GraphDomainEvent::GraphCreated(event) => event.graph_id,
+            _ => panic!("Expected GraphCreated event"),
+        };
+
+        let mut graph = repository.load_graph(graph_id, None).await.unwrap();
+        let [before, split, after] = [NodeId::new(), NodeId::new(), NodeId::new()];
+        for node_id in [before, split, after] {
+            let data = NodeData {
+                node_type: "step".to_string(),
+                position: Position3D::default(),
+                metadata: HashMap::new(),
+            };
+            graph.add_node(node_id, data).unwrap();
+        }
+        let [incoming, outgoing] = [EdgeId::new(), EdgeId::new()];
+        for (edge_id, source, target) in [(incoming, before, split), (outgoing, split, after)] {
+            let data = EdgeData {
+                edge_type: "next".to_string(),
+                metadata: HashMap::new(),
+            };
+            graph.add_edge(edge_id, source, target, data).unwrap();
+        }
+        repository.save_graph(&graph).await.unwrap();
+
+        let events = handler
+            .handle_graph_command(GraphCommand::SplitNode {
+                graph_id,
+                node_id: split,
+                incoming_to_first: vec![incoming],
+                outgoing_to_second: vec![outgoing],
+            })
+            .await
+            .unwrap();
+        let second = match &events[0] {
+            GraphDomainEvent::NodeAdded(event) => event.node_id,
+            _ => panic!("Expected NodeAdded event"),
+        };
+
+        let graph = repository.load_graph(graph_id, None).await.unwrap();
+        assert_eq!(graph.get_edge_endpoints(incoming).unwrap(), (before, split));
+        assert_eq!(graph.get_edge_endpoints(outgoing).unwrap(), (second, after));
+    }
 }
@@ -68,6 +68,7 @@ impl UnifiedGraphRepositoryImpl {
                 "concept" => GraphType::new_concept(graph_id, ""),
                 "workflow" => GraphType::new_workflow(graph_id, ""),
                 "ipld" => GraphType::new_ipld(graph_id),
+                "generic" => GraphType::new_generic(graph_id, ""),
                 _ => GraphType::new_context(graph_id, ""), // Default
             };
             return Ok(graph_type);
@@ -81,6 +82,7 @@ impl UnifiedGraphRepositoryImpl {
                 Some("concept") => GraphType::new_concept(graph_id, &summary.name),
                 Some("workflow") => GraphType::new_workflow(graph_id, &summary.name),
                 Some("ipld") => GraphType::new_ipld(graph_id),
+                Some("generic") => GraphType::new_generic(graph_id, &summary.name),
                 _ => GraphType::new_context(graph_id, &summary.name), // Default
             };
             return Ok(graph_type);
@@ -4,7 +4,9 @@
 //! including 3D layouts, tree-specific layouts, and optimized force-directed algorithms.
 
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::ControlFlow;
 use crate::NodeId;
+use super::constraints::LayoutConstraints;
 
 // Define Vec3 locally since glam is not available
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -121,11 +123,51 @@ impl FruchtermanReingoldLayout {
         nodes: &mut HashMap<NodeId, Vec3>,
         edges: &[(NodeId, NodeId)],
         bounds: Vec3,
+    ) {
+        self.apply_with_progress(nodes, edges, bounds, |_, _| ControlFlow::Continue(()));
+    }
+
+    /// Run the layout, reporting `(completed iterations, max_iterations)` after each iteration
+    ///
+    /// Returning `ControlFlow::Break` from `progress` stops the layout, leaving `nodes` at
+    /// the positions reached so far.
+    pub fn apply_with_progress(
+        &mut self,
+        nodes: &mut HashMap<NodeId, Vec3>,
+        edges: &[(NodeId, NodeId)],
+        bounds: Vec3,
+        progress: impl FnMut(usize, usize) -> ControlFlow<()>,
+    ) {
+        self.run(nodes, edges, bounds, &LayoutConstraints::default(), progress);
+    }
+
+    /// Run the layout while respecting `constraints`
+    ///
+    /// Pinned nodes still repel and attract their neighbours but never move; the other
+    /// constraints are enforced after every iteration.
+    pub fn apply_constrained(
+        &mut self,
+        nodes: &mut HashMap<NodeId, Vec3>,
+        edges: &[(NodeId, NodeId)],
+        bounds: Vec3,
+        constraints: &LayoutConstraints,
+    ) {
+        self.run(nodes, edges, bounds, constraints, |_, _| ControlFlow::Continue(()));
+    }
+
+    fn run(
+        &mut self,
+        nodes: &mut HashMap<NodeId, Vec3>,
+        edges: &[(NodeId, NodeId)],
+        bounds: Vec3,
+        constraints: &LayoutConstraints,
+        mut progress: impl FnMut(usize, usize) -> ControlFlow<()>,
     ) {
         let node_count = nodes.len() as f32;
         if node_count == 0.0 {
             return;
         }
+        constraints.place_pinned(nodes);
 
         // Calculate area and ideal distance
         let area = bounds.x * bounds.y * bounds.z;
@@ -135,7 +177,8 @@ impl FruchtermanReingoldLayout {
         // Create a vector of node IDs for indexed access
         let node_ids: Vec<NodeId> = nodes.keys().cloned().collect();
         
-        for _iteration in 0..self.max_iterations {
+        let total = self.max_iterations as usize;
+        for iteration in 0..total {
             // Calculate repulsive forces
             let mut displacements: HashMap<NodeId, Vec3> = HashMap::new();
             
@@ -179,6 +222,9 @@ impl FruchtermanReingoldLayout {
             
             // Apply displacements with temperature
             for (id, displacement) in displacements {
+                if constraints.is_pinned(&id) {
+                    continue;
+                }
                 if let Some(pos) = nodes.get_mut(&id) {
                     let disp_length = displacement.length();
                     if disp_length > 0.0 {
@@ -193,8 +239,14 @@ impl FruchtermanReingoldLayout {
                 }
             }
             
+            constraints.enforce(nodes);
+
             // Cool down temperature
             self.temperature *= self.cooling_rate;
+
+            if progress(iteration + 1, total).is_break() {
+                break;
+            }
             
             // Early exit if temperature is too low
             if self.temperature < 0.01 {
@@ -375,8 +427,44 @@ impl Default for SpectralLayout {
 
 impl SpectralLayout {
     pub fn apply(&self, nodes: &mut HashMap<NodeId, Vec3>, edges: &[(NodeId, NodeId)]) {
+        self.apply_with_progress(nodes, edges, |_, _| ControlFlow::Continue(()));
+    }
+
+    /// Run the layout, reporting `(placed nodes, node count)` after each node is placed
+    ///
+    /// Returning `ControlFlow::Break` from `progress` stops the layout; nodes not yet placed
+    /// keep their previous positions.
+    pub fn apply_with_progress(
+        &self,
+        nodes: &mut HashMap<NodeId, Vec3>,
+        edges: &[(NodeId, NodeId)],
+        progress: impl FnMut(usize, usize) -> ControlFlow<()>,
+    ) {
+        self.run(nodes, edges, &LayoutConstraints::default(), progress);
+    }
+
+    /// Run the layout, then move the result to satisfy `constraints`
+    ///
+    /// Pinned nodes are skipped when placing nodes, so they stay where they are pinned.
+    pub fn apply_constrained(
+        &self,
+        nodes: &mut HashMap<NodeId, Vec3>,
+        edges: &[(NodeId, NodeId)],
+        constraints: &LayoutConstraints,
+    ) {
+        self.run(nodes, edges, constraints, |_, _| ControlFlow::Continue(()));
+    }
+
+    fn run(
+        &self,
+        nodes: &mut HashMap<NodeId, Vec3>,
+        edges: &[(NodeId, NodeId)],
+        constraints: &LayoutConstraints,
+        mut progress: impl FnMut(usize, usize) -> ControlFlow<()>,
+    ) {
         let node_count = nodes.len();
         if node_count < 2 {
+            constraints.enforce(nodes);
             return;
         }
 
@@ -435,10 +523,17 @@ impl SpectralLayout {
             let y = radius * angle.sin();
             let z = normalized_degree * scale * 0.5; // Higher degree nodes elevated
 
-            if let Some(pos) = nodes.get_mut(node_id) {
-                *pos = Vec3::new(x, y, z);
+            if !constraints.is_pinned(node_id) {
+                if let Some(pos) = nodes.get_mut(node_id) {
+                    *pos = Vec3::new(x, y, z);
+                }
+            }
+
+            if progress(i + 1, node_count).is_break() {
+                break;
             }
         }
+        constraints.enforce(nodes);
     }
 }
 
@@ -520,6 +615,57 @@ mod tests {
         assert_ne!(nodes[&id2], Vec3::new(10.0, 0.0, 0.0));
     }
 
+    #[test]
+    fn test_layout_progress_and_early_stop() {
+        let mut nodes = HashMap::new();
+        let id1 = NodeId::new();
+        let id2 = NodeId::new();
+        nodes.insert(id1.clone(), Vec3::new(0.0, 0.0, 0.0));
+        nodes.insert(id2.clone(), Vec3::new(10.0, 0.0, 0.0));
+        let edges = vec![(id1.clone(), id2.clone())];
+        let bounds = Vec3::new(500.0, 500.0, 500.0);
+
+        // Cooling slowly enough that the temperature never ends the run early
+        let mut layout = FruchtermanReingoldLayout {
+            max_iterations: 5,
+            cooling_rate: 0.99,
+            ..Default::default()
+        };
+        let mut calls = Vec::new();
+        layout.apply_with_progress(&mut nodes, &edges, bounds, |done, total| {
+            calls.push((done, total));
+            ControlFlow::Continue(())
+        });
+        assert_eq!(calls, (1..=5).map(|done| (done, 5)).collect::<Vec<_>>());
+
+        // Stopping after the first iteration matches a single-iteration run
+        let start = HashMap::from([
+            (id1.clone(), Vec3::new(0.0, 0.0, 0.0)),
+            (id2.clone(), Vec3::new(10.0, 0.0, 0.0)),
+        ]);
+        let mut stopped = start.clone();
+        let mut iterations = 0;
+        FruchtermanReingoldLayout::default().apply_with_progress(
+            &mut stopped,
+            &edges,
+            bounds,
+            |_, _| {
+                iterations += 1;
+                ControlFlow::Break(())
+            },
+        );
+        let mut single = start.clone();
+        FruchtermanReingoldLayout {
+            max_iterations: 1,
+            ..Default::default()
+        }
+        .apply(&mut single, &edges, bounds);
+
+        assert_eq!(iterations, 1);
+        assert_ne!(stopped, start);
+        assert_eq!(stopped, single);
+    }
+
     #[test]
     fn test_sphere_layout() {
         let mut nodes = HashMap::new();
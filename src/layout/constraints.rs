@@ -0,0 +1,182 @@
+//! Layout constraints
+//!
+//! Constraints are enforced on top of a layout algorithm's output: pinned nodes are put back
+//! at their fixed positions, aligned groups share a y coordinate, and nodes closer than the
+//! minimum distance are pushed apart.
+
+use super::advanced_layouts::Vec3;
+use crate::value_objects::Position3D;
+use crate::NodeId;
+use std::collections::{HashMap, HashSet};
+
+/// Rounds of pairwise separation before giving up on the minimum distance
+const MAX_SEPARATION_PASSES: usize = 100;
+
+/// Constraints a layout must respect
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LayoutConstraints {
+    /// Nodes fixed at a position; the layout never moves them
+    pub pinned: HashMap<NodeId, Position3D>,
+    /// Smallest allowed distance between any two nodes; 0.0 disables separation
+    pub min_distance: f64,
+    /// Groups of nodes placed on a common horizontal line
+    pub aligned_groups: Vec<Vec<NodeId>>,
+}
+
+impl LayoutConstraints {
+    /// Whether the node is pinned
+    pub fn is_pinned(&self, node_id: &NodeId) -> bool {
+        self.pinned.contains_key(node_id)
+    }
+
+    /// Move `nodes` so they satisfy the constraints
+    ///
+    /// Aligned groups take the y of a pinned member, or their mean y. Separation runs last,
+    /// moving only unpinned nodes and moving aligned nodes only within their plane, so two
+    /// pinned nodes may stay closer than `min_distance`.
+    pub fn enforce(&self, nodes: &mut HashMap<NodeId, Vec3>) {
+        self.place_pinned(nodes);
+        self.align_groups(nodes);
+        self.separate(nodes);
+    }
+
+    /// Put pinned nodes at their fixed positions
+    pub(crate) fn place_pinned(&self, nodes: &mut HashMap<NodeId, Vec3>) {
+        for (node_id, position) in &self.pinned {
+            if let Some(pos) = nodes.get_mut(node_id) {
+                *pos = Vec3::new(position.x as f32, position.y as f32, position.z as f32);
+            }
+        }
+    }
+
+    fn align_groups(&self, nodes: &mut HashMap<NodeId, Vec3>) {
+        for group in &self.aligned_groups {
+            let members: Vec<&NodeId> = group.iter().filter(|id| nodes.contains_key(id)).collect();
+            if members.is_empty() {
+                continue;
+            }
+            let line = match members.iter().find_map(|id| self.pinned.get(*id)) {
+                Some(position) => position.y as f32,
+                None => {
+                    members.iter().map(|id| nodes[*id].y).sum::<f32>() / members.len() as f32
+                }
+            };
+            for node_id in members {
+                if !self.is_pinned(node_id) {
+                    if let Some(pos) = nodes.get_mut(node_id) {
+                        pos.y = line;
+                    }
+                }
+            }
+        }
+    }
+
+    fn separate(&self, nodes: &mut HashMap<NodeId, Vec3>) {
+        let min_distance = self.min_distance as f32;
+        if min_distance <= 0.0 {
+            return;
+        }
+
+        let aligned: HashSet<&NodeId> = self.aligned_groups.iter().flatten().collect();
+        // Aligned nodes keep their y, so their share of a push is flattened
+        let flatten = |node_id: &NodeId, push: Vec3| {
+            if !aligned.contains(node_id) {
+                return push;
+            }
+            let flat = Vec3::new(push.x, 0.0, push.z);
+            if flat.length() > 0.0 {
+                flat
+            } else {
+                Vec3::new(push.length(), 0.0, 0.0)
+            }
+        };
+
+        let node_ids: Vec<NodeId> = nodes.keys().copied().collect();
+        for _ in 0..MAX_SEPARATION_PASSES {
+            let mut moved = false;
+            for i in 0..node_ids.len() {
+                for j in (i + 1)..node_ids.len() {
+                    let (a, b) = (&node_ids[i], &node_ids[j]);
+                    let (a_pinned, b_pinned) = (self.is_pinned(a), self.is_pinned(b));
+                    if a_pinned && b_pinned {
+                        continue;
+                    }
+
+                    let delta = nodes[b] - nodes[a];
+                    let distance = delta.length();
+                    if distance >= min_distance {
+                        continue;
+                    }
+                    // Coincident nodes are split along a direction derived from their order
+                    let direction = if distance > 0.0 {
+                        delta.normalize()
+                    } else {
+                        let angle = (i * node_ids.len() + j) as f32;
+                        Vec3::new(angle.cos(), angle.sin(), 0.0)
+                    };
+                    // Overshoot slightly so rounding cannot leave the pair just short
+                    let push = direction * ((min_distance - distance) * 1.001);
+
+                    match (a_pinned, b_pinned) {
+                        (true, _) => *nodes.get_mut(b).unwrap() += flatten(b, push),
+                        (_, true) => *nodes.get_mut(a).unwrap() -= flatten(a, push),
+                        _ => {
+                            *nodes.get_mut(a).unwrap() -= flatten(a, push * 0.5);
+                            *nodes.get_mut(b).unwrap() += flatten(b, push * 0.5);
+                        }
+                    }
+                    moved = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{FruchtermanReingoldLayout, SpectralLayout};
+
+    #[test]
+    fn test_pinned_node_keeps_exact_position() {
+        let anchor = NodeId::new();
+        let others: Vec<NodeId> = (0..4).map(|_| NodeId::new()).collect();
+        let start: HashMap<NodeId, Vec3> = std::iter::once(anchor)
+            .chain(others.iter().copied())
+            .enumerate()
+            .map(|(i, id)| (id, Vec3::new(i as f32 * 3.0, 0.0, 0.0)))
+            .collect();
+        let edges: Vec<(NodeId, NodeId)> = others.iter().map(|id| (anchor, *id)).collect();
+
+        let pinned_at = Position3D::new(42.0, -7.5, 3.0);
+        let constraints = LayoutConstraints {
+            pinned: HashMap::from([(anchor, pinned_at)]),
+            min_distance: 25.0,
+            aligned_groups: vec![others[..2].to_vec()],
+        };
+        let expected = Vec3::new(42.0, -7.5, 3.0);
+
+        let mut nodes = start.clone();
+        FruchtermanReingoldLayout {
+            max_iterations: 20,
+            ..Default::default()
+        }
+        .apply_constrained(&mut nodes, &edges, Vec3::new(500.0, 500.0, 500.0), &constraints);
+        assert_eq!(nodes[&anchor], expected);
+        assert_eq!(nodes[&others[0]].y, nodes[&others[1]].y);
+
+        let mut nodes = start;
+        SpectralLayout::default().apply_constrained(&mut nodes, &edges, &constraints);
+        assert_eq!(nodes[&anchor], expected);
+
+        let ids: Vec<&NodeId> = nodes.keys().collect();
+        for (i, a) in ids.iter().enumerate() {
+            for b in &ids[i + 1..] {
+                assert!((nodes[*a] - nodes[*b]).length() >= 25.0);
+            }
+        }
+    }
+}
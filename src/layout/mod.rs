@@ -4,8 +4,14 @@
 //! in 2D and 3D space.
 
 pub mod advanced_layouts;
+pub mod constraints;
+pub mod overlaps;
+pub mod quality;
 
 pub use advanced_layouts::{
     FruchtermanReingoldLayout, SphereLayout, RadialTreeLayout, 
     SpectralLayout, BipartiteLayout
-};
\ No newline at end of file
+};
+pub use constraints::LayoutConstraints;
+pub use overlaps::find_overlaps;
+pub use quality::{score_layout, LayoutQuality, OVERLAP_DISTANCE};
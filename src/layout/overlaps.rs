@@ -0,0 +1,83 @@
+//! Overlap detection between node bounding boxes
+//!
+//! Boxes are bucketed into a uniform grid whose cells are as large as the largest box, so
+//! each box lands in at most eight cells and only boxes sharing a cell are compared.
+
+use crate::components::{BoundingBox, Size};
+use crate::value_objects::Position3D;
+use crate::NodeId;
+use std::collections::{BTreeSet, HashMap};
+
+/// Pairs of nodes whose bounding boxes intersect
+///
+/// Each node's box is centered on its position with the given size. Boxes that only touch
+/// count as overlapping, as in [`BoundingBox::intersects`]. Pairs are listed once, in input
+/// order.
+pub fn find_overlaps(nodes: &[(NodeId, Position3D, Size)]) -> Vec<(NodeId, NodeId)> {
+    let boxes: Vec<BoundingBox> = nodes
+        .iter()
+        .map(|(_, center, size)| {
+            let half = Position3D::new(
+                f64::from(size.width) / 2.0,
+                f64::from(size.height) / 2.0,
+                f64::from(size.depth) / 2.0,
+            );
+            BoundingBox {
+                min: Position3D::new(center.x - half.x, center.y - half.y, center.z - half.z),
+                max: Position3D::new(center.x + half.x, center.y + half.y, center.z + half.z),
+            }
+        })
+        .collect();
+
+    let largest = nodes
+        .iter()
+        .map(|(_, _, size)| f64::from(size.width.max(size.height).max(size.depth)))
+        .fold(0.0, f64::max);
+    let cell_size = if largest.is_finite() && largest > 0.0 { largest } else { 1.0 };
+    let cell = |value: f64| (value / cell_size).floor() as i64;
+
+    let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+    for (index, bounds) in boxes.iter().enumerate() {
+        for x in cell(bounds.min.x)..=cell(bounds.max.x) {
+            for y in cell(bounds.min.y)..=cell(bounds.max.y) {
+                for z in cell(bounds.min.z)..=cell(bounds.max.z) {
+                    grid.entry((x, y, z)).or_default().push(index);
+                }
+            }
+        }
+    }
+
+    // Boxes sharing several cells are compared once
+    let mut pairs: BTreeSet<(usize, usize)> = BTreeSet::new();
+    for members in grid.values() {
+        for (i, &a) in members.iter().enumerate() {
+            for &b in &members[i + 1..] {
+                if boxes[a].intersects(&boxes[b]) {
+                    pairs.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+    }
+
+    pairs.into_iter().map(|(a, b)| (nodes[a].0, nodes[b].0)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_overlaps_reports_close_nodes_only() {
+        let [a, b, far] = [(); 3].map(|_| NodeId::new());
+        let size = Size::new(10.0, 10.0, 10.0);
+        let nodes = [
+            (a, Position3D::new(0.0, 0.0, 0.0), size),
+            // Closer than the half-widths add up to, so the boxes intersect
+            (b, Position3D::new(6.0, 3.0, 0.0), size),
+            (far, Position3D::new(100.0, 0.0, 0.0), size),
+        ];
+
+        assert_eq!(find_overlaps(&nodes), vec![(a, b)]);
+        assert!(find_overlaps(&nodes[1..]).is_empty());
+    }
+}
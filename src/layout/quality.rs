@@ -0,0 +1,169 @@
+//! Headless layout quality scoring
+//!
+//! Scores node positions for a graph structure without going through the ECS, so
+//! layouts produced by different algorithms can be compared in tests or services.
+
+use crate::queries::GraphStructure;
+use crate::value_objects::Position3D;
+use crate::NodeId;
+use std::collections::HashMap;
+
+/// Nodes closer than this are counted as overlapping
+pub const OVERLAP_DISTANCE: f64 = 30.0;
+
+/// Quality measures for a layout; lower is better for all of them
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LayoutQuality {
+    /// Number of pairs of edges whose drawings cross in the x-y plane
+    pub edge_crossing_count: usize,
+    /// Variance of the edge lengths around their mean
+    pub edge_length_variance: f64,
+    /// Number of node pairs closer than [`OVERLAP_DISTANCE`]
+    pub node_overlap_count: usize,
+}
+
+/// Score a layout of `structure`
+///
+/// Nodes without a position are ignored, as are edges touching them. Crossings are
+/// counted on the x-y projection; edges sharing an endpoint and collinear overlaps are
+/// not counted as crossings.
+pub fn score_layout(
+    structure: &GraphStructure,
+    positions: &HashMap<NodeId, Position3D>,
+) -> LayoutQuality {
+    let segments: Vec<(NodeId, NodeId, Position3D, Position3D)> = structure
+        .edges
+        .iter()
+        .filter(|edge| edge.source_id != edge.target_id)
+        .filter_map(|edge| {
+            let source = positions.get(&edge.source_id)?;
+            let target = positions.get(&edge.target_id)?;
+            Some((edge.source_id, edge.target_id, *source, *target))
+        })
+        .collect();
+
+    let mut edge_crossing_count = 0;
+    for (i, (a1, a2, p1, p2)) in segments.iter().enumerate() {
+        for (b1, b2, q1, q2) in &segments[i + 1..] {
+            let shares_endpoint = a1 == b1 || a1 == b2 || a2 == b1 || a2 == b2;
+            if !shares_endpoint && segments_cross(p1, p2, q1, q2) {
+                edge_crossing_count += 1;
+            }
+        }
+    }
+
+    let lengths: Vec<f64> = segments
+        .iter()
+        .map(|(_, _, source, target)| source.distance_to(target))
+        .collect();
+    let edge_length_variance = if lengths.is_empty() {
+        0.0
+    } else {
+        let mean = lengths.iter().sum::<f64>() / lengths.len() as f64;
+        lengths
+            .iter()
+            .map(|length| (length - mean).powi(2))
+            .sum::<f64>()
+            / lengths.len() as f64
+    };
+
+    let placed: Vec<&Position3D> = structure
+        .nodes
+        .iter()
+        .filter_map(|node| positions.get(&node.node_id))
+        .collect();
+    let mut node_overlap_count = 0;
+    for (i, a) in placed.iter().enumerate() {
+        for b in &placed[i + 1..] {
+            if a.distance_to(b) < OVERLAP_DISTANCE {
+                node_overlap_count += 1;
+            }
+        }
+    }
+
+    LayoutQuality {
+        edge_crossing_count,
+        edge_length_variance,
+        node_overlap_count,
+    }
+}
+
+/// Whether segments p1-p2 and q1-q2 properly intersect in the x-y plane
+fn segments_cross(p1: &Position3D, p2: &Position3D, q1: &Position3D, q2: &Position3D) -> bool {
+    fn orientation(a: &Position3D, b: &Position3D, c: &Position3D) -> f64 {
+        (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+    }
+
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::{EdgeInfo, NodeInfo};
+    use crate::{EdgeId, GraphId};
+
+    fn cycle(nodes: &[NodeId]) -> GraphStructure {
+        let graph_id = GraphId::new();
+        let node_infos = nodes
+            .iter()
+            .map(|node_id| NodeInfo {
+                node_id: *node_id,
+                graph_id,
+                node_type: "point".to_string(),
+                position_2d: None,
+                position_3d: None,
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+                last_modified: chrono::Utc::now(),
+            })
+            .collect();
+        let edges = (0..nodes.len())
+            .map(|i| EdgeInfo {
+                edge_id: EdgeId::new(),
+                graph_id,
+                source_id: nodes[i],
+                target_id: nodes[(i + 1) % nodes.len()],
+                edge_type: "ring".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+                last_modified: chrono::Utc::now(),
+            })
+            .collect();
+        GraphStructure {
+            nodes: node_infos,
+            edges,
+            adjacency_list: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_planar_layout_has_no_crossings() {
+        let nodes: Vec<NodeId> = (0..4).map(|_| NodeId::new()).collect();
+        let structure = cycle(&nodes);
+        let corners = [(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0)];
+
+        // Ring drawn around the square
+        let planar: HashMap<NodeId, Position3D> = nodes
+            .iter()
+            .zip(corners)
+            .map(|(node_id, (x, y))| (*node_id, Position3D::new(x, y, 0.0)))
+            .collect();
+        let quality = score_layout(&structure, &planar);
+        assert_eq!(quality.edge_crossing_count, 0);
+        assert_eq!(quality.node_overlap_count, 0);
+        assert!(quality.edge_length_variance.abs() < 1e-9);
+
+        // Swapping two corners draws the ring as a bow tie
+        let mut tangled = planar.clone();
+        tangled.insert(nodes[1], planar[&nodes[2]]);
+        tangled.insert(nodes[2], planar[&nodes[1]]);
+        let quality = score_layout(&structure, &tangled);
+        assert_eq!(quality.edge_crossing_count, 1);
+        assert!(quality.edge_length_variance > 0.0);
+    }
+}
@@ -7,11 +7,14 @@
 pub mod abstraction;
 pub mod aggregate;
 pub mod bridge;
+pub mod builder;
 pub mod commands;
 pub mod components;
 pub mod deployment;
 pub mod domain_events;
 pub mod events;
+pub mod generators;
+pub mod groups;
 pub mod handlers;
 pub mod infrastructure;
 pub mod layout;
@@ -20,6 +23,7 @@ pub mod plugin;
 pub mod projections;
 pub mod queries;
 pub mod systems;
+pub mod tags;
 pub mod value_objects;
 
 // Re-export main types
@@ -33,13 +37,22 @@ pub use abstraction::{
     GraphOperationError, GraphResult, GraphType, IpldGraphAdapter, NodeData, WorkflowGraphAdapter,
 };
 
+// Re-export the graph builder
+pub use builder::{BuiltGraph, GraphBuilder};
+
 // Re-export commands and their types
-pub use commands::{EdgeCommand, GraphCommand, GraphCommandError, GraphCommandResult, NodeCommand};
+pub use commands::{
+    EdgeCommand, GraphCommand, GraphCommandError, GraphCommandResult, NodeCommand, UndoStack,
+};
 
 // Re-export query types
 pub use queries::{
-    EdgeInfo, FilterParams, GraphInfo, GraphMetrics, GraphQueryError, GraphQueryHandler,
-    GraphQueryHandlerImpl, GraphQueryResult, GraphStructure, NodeInfo, PaginationParams,
+    detect_conflicts, evaluate_condition, export_mermaid, AlgorithmConfig, AlgorithmLimits,
+    CacheStats, CancellationToken, ConditionOperator, Conflict, ConflictKind, EdgeInfo,
+    FilterParams, GraphDiff, GraphDocument, GraphInfo, GraphMetrics, GraphQueryError,
+    GraphQueryHandler, GraphQueryHandlerImpl, GraphQueryResult, GraphSearchResult, GraphStructure,
+    HyperEdgeInfo, MetadataPredicate, NodeInfo, NodeRole, PaginationParams, SpanningForest,
+    VALID_FROM_KEY, VALID_UNTIL_KEY,
 };
 
 // Re-export command handlers
@@ -48,7 +61,10 @@ pub use handlers::{
 };
 
 // Re-export value objects
-pub use value_objects::{Color, EdgeType, NodeType, Position2D, Position3D, Style};
+pub use value_objects::{
+    Color, DistanceMetric, EdgeType, GraphMode, LayoutAlgorithm, NodeType, Position2D,
+    Position3D, Style,
+};
 
 // Re-export projections
 pub use projections::{GraphProjection, GraphSummaryProjection, NodeListProjection};
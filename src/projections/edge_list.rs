@@ -4,7 +4,7 @@
 
 use crate::{
     domain_events::GraphDomainEvent,
-    events::{EdgeAdded, EdgeRemoved},
+    events::{EdgeAdded, EdgeRemoved, EdgeUpdated, HyperEdgeAdded, NodeRemoved},
     EdgeId, GraphId, NodeId,
 };
 use async_trait::async_trait;
@@ -28,6 +28,25 @@ pub struct EdgeInfo {
     pub edge_type: String,
     /// Additional metadata about the edge
     pub metadata: HashMap<String, serde_json::Value>,
+    /// When the edge was added
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// When the edge was last added or updated
+    pub last_modified: chrono::DateTime<chrono::Utc>,
+}
+
+/// Information about a hyperedge for listing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperEdgeInfo {
+    /// Unique identifier of the hyperedge
+    pub edge_id: EdgeId,
+    /// ID of the graph this hyperedge belongs to
+    pub graph_id: GraphId,
+    /// Nodes connected by the hyperedge
+    pub node_ids: Vec<NodeId>,
+    /// Type/relationship of the hyperedge
+    pub edge_type: String,
+    /// Additional metadata about the hyperedge
+    pub metadata: HashMap<String, serde_json::Value>,
 }
 
 /// Projection that maintains a searchable list of edges
@@ -39,7 +58,12 @@ pub struct EdgeListProjection {
     edges_by_node: HashMap<NodeId, Vec<EdgeId>>,
     incoming_edges: HashMap<NodeId, Vec<EdgeId>>,
     outgoing_edges: HashMap<NodeId, Vec<EdgeId>>,
+    typed_adjacency: HashMap<(GraphId, String), HashMap<NodeId, Vec<NodeId>>>,
+    hyperedges: HashMap<EdgeId, HyperEdgeInfo>,
+    hyperedges_by_node: HashMap<NodeId, Vec<EdgeId>>,
+    removed_edges: HashMap<GraphId, HashMap<EdgeId, chrono::DateTime<chrono::Utc>>>,
     checkpoint: Option<EventSequence>,
+    generation: u64,
 }
 
 impl Default for EdgeListProjection {
@@ -58,7 +82,12 @@ impl EdgeListProjection {
             edges_by_node: HashMap::new(),
             incoming_edges: HashMap::new(),
             outgoing_edges: HashMap::new(),
+            typed_adjacency: HashMap::new(),
+            hyperedges: HashMap::new(),
+            hyperedges_by_node: HashMap::new(),
+            removed_edges: HashMap::new(),
             checkpoint: None,
+            generation: 0,
         }
     }
 
@@ -88,6 +117,26 @@ impl EdgeListProjection {
             .unwrap_or_default()
     }
 
+    /// Get the edges of a graph removed after `since`
+    ///
+    /// A removed edge that is added again is no longer reported.
+    pub fn get_removed_since(
+        &self,
+        graph_id: &GraphId,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Vec<EdgeId> {
+        self.removed_edges
+            .get(graph_id)
+            .map(|removed| {
+                removed
+                    .iter()
+                    .filter(|(_, removed_at)| **removed_at > since)
+                    .map(|(edge_id, _)| *edge_id)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Get all edges connected to a node (incoming and outgoing)
     pub fn get_edges_for_node(&self, node_id: &NodeId) -> Vec<&EdgeInfo> {
         self.edges_by_node
@@ -96,6 +145,16 @@ impl EdgeListProjection {
             .unwrap_or_default()
     }
 
+    /// Get hyperedges that include a node
+    ///
+    /// Hyperedges are kept apart from binary edges, so they never appear in the other lookups.
+    pub fn get_hyperedges_for_node(&self, node_id: &NodeId) -> Vec<&HyperEdgeInfo> {
+        self.hyperedges_by_node
+            .get(node_id)
+            .map(|ids| ids.iter().filter_map(|id| self.hyperedges.get(id)).collect())
+            .unwrap_or_default()
+    }
+
     /// Get incoming edges for a node
     pub fn get_incoming_edges(&self, node_id: &NodeId) -> Vec<&EdgeInfo> {
         self.incoming_edges
@@ -112,6 +171,16 @@ impl EdgeListProjection {
             .unwrap_or_default()
     }
 
+    /// Number of edges pointing at a node
+    pub fn in_degree(&self, node_id: &NodeId) -> usize {
+        self.incoming_edges.get(node_id).map_or(0, Vec::len)
+    }
+
+    /// Number of edges leaving a node
+    pub fn out_degree(&self, node_id: &NodeId) -> usize {
+        self.outgoing_edges.get(node_id).map_or(0, Vec::len)
+    }
+
     /// Get total number of edges
     pub fn total_edges(&self) -> usize {
         self.edges.len()
@@ -133,6 +202,15 @@ impl EdgeListProjection {
             .collect()
     }
 
+    /// Get edge count by type within one graph
+    pub fn count_by_type_in_graph(&self, graph_id: &GraphId) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for edge in self.get_edges_by_graph(graph_id) {
+            *counts.entry(edge.edge_type.clone()).or_default() += 1;
+        }
+        counts
+    }
+
     /// Build adjacency list for a graph
     pub fn get_adjacency_list(&self, graph_id: &GraphId) -> HashMap<NodeId, Vec<NodeId>> {
         let mut adjacency: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
@@ -150,6 +228,237 @@ impl EdgeListProjection {
         
         adjacency
     }
+
+    /// Adjacency list of a graph restricted to edges of one type
+    ///
+    /// The index is maintained as edges are added and removed, so typed traversals don't
+    /// rescan the graph's edges. Parallel edges repeat the target.
+    pub fn get_typed_adjacency(
+        &self,
+        graph_id: &GraphId,
+        edge_type: &str,
+    ) -> Option<&HashMap<NodeId, Vec<NodeId>>> {
+        self.typed_adjacency.get(&(*graph_id, edge_type.to_string()))
+    }
+
+    /// Build reverse adjacency list for a graph, mapping each node to its predecessors
+    pub fn get_predecessor_list(&self, graph_id: &GraphId) -> HashMap<NodeId, Vec<NodeId>> {
+        let mut predecessors: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+
+        if let Some(edge_ids) = self.edges_by_graph.get(graph_id) {
+            for edge_id in edge_ids {
+                if let Some(edge) = self.edges.get(edge_id) {
+                    predecessors
+                        .entry(edge.target_id)
+                        .or_default()
+                        .push(edge.source_id);
+                }
+            }
+        }
+
+        predecessors
+    }
+
+    /// Build adjacency list for a graph following edges in both directions
+    ///
+    /// Each node maps to its successors followed by its predecessors. Self-loops appear once.
+    pub fn get_bidirectional_adjacency(
+        &self,
+        graph_id: &GraphId,
+    ) -> HashMap<NodeId, Vec<NodeId>> {
+        let mut adjacency = self.get_adjacency_list(graph_id);
+
+        if let Some(edge_ids) = self.edges_by_graph.get(graph_id) {
+            for edge_id in edge_ids {
+                if let Some(edge) = self.edges.get(edge_id) {
+                    if edge.source_id != edge.target_id {
+                        adjacency
+                            .entry(edge.target_id)
+                            .or_default()
+                            .push(edge.source_id);
+                    }
+                }
+            }
+        }
+
+        adjacency
+    }
+
+    /// Weight of an edge, or `None` if the edge is unknown
+    ///
+    /// Edges without a numeric `weight` in their metadata weigh 1.0.
+    pub fn get_edge_weight(&self, edge_id: &EdgeId) -> Option<f64> {
+        self.edges.get(edge_id).map(|edge| {
+            edge.metadata
+                .get("weight")
+                .and_then(|w| w.as_f64())
+                .unwrap_or(1.0)
+        })
+    }
+
+    /// Build adjacency list for a graph with each neighbor's edge weight
+    pub fn get_weighted_adjacency_list(
+        &self,
+        graph_id: &GraphId,
+    ) -> HashMap<NodeId, Vec<(NodeId, f64)>> {
+        let mut adjacency: HashMap<NodeId, Vec<(NodeId, f64)>> = HashMap::new();
+
+        if let Some(edge_ids) = self.edges_by_graph.get(graph_id) {
+            for edge_id in edge_ids {
+                if let (Some(edge), Some(weight)) =
+                    (self.edges.get(edge_id), self.get_edge_weight(edge_id))
+                {
+                    adjacency
+                        .entry(edge.source_id)
+                        .or_default()
+                        .push((edge.target_id, weight));
+                }
+            }
+        }
+
+        adjacency
+    }
+
+    /// Combine another shard into this projection
+    ///
+    /// An edge present in both keeps the copy with the later `created_at`, preferring
+    /// `other` on ties; hyperedges from `other` always replace existing ones. Indexes are
+    /// updated for every replaced or added edge; the checkpoint of `self` is kept.
+    pub fn merge(&mut self, other: Self) {
+        for (edge_id, edge_info) in other.edges {
+            if let Some(existing) = self.edges.get(&edge_id) {
+                if existing.created_at > edge_info.created_at {
+                    continue;
+                }
+                self.unindex_edge(&edge_id);
+            }
+            self.index_edge(edge_info);
+        }
+        for (graph_id, removed) in other.removed_edges {
+            self.removed_edges.entry(graph_id).or_default().extend(
+                removed
+                    .into_iter()
+                    .filter(|(edge_id, _)| !self.edges.contains_key(edge_id)),
+            );
+        }
+
+        for (edge_id, hyperedge) in other.hyperedges {
+            if let Some(existing) = self.hyperedges.remove(&edge_id) {
+                for node_id in &existing.node_ids {
+                    if let Some(edges) = self.hyperedges_by_node.get_mut(node_id) {
+                        edges.retain(|id| id != &edge_id);
+                    }
+                }
+            }
+            for node_id in &hyperedge.node_ids {
+                self.hyperedges_by_node
+                    .entry(*node_id)
+                    .or_default()
+                    .push(edge_id);
+            }
+            self.hyperedges.insert(edge_id, hyperedge);
+        }
+        self.generation += 1;
+    }
+
+    /// Store an edge and add it to every index
+    fn index_edge(&mut self, edge_info: EdgeInfo) {
+        let edge_id = edge_info.edge_id;
+        if let Some(removed) = self.removed_edges.get_mut(&edge_info.graph_id) {
+            removed.remove(&edge_id);
+        }
+        self.edges_by_graph
+            .entry(edge_info.graph_id)
+            .or_default()
+            .push(edge_id);
+        self.edges_by_type
+            .entry(edge_info.edge_type.clone())
+            .or_default()
+            .push(edge_id);
+        self.edges_by_node
+            .entry(edge_info.source_id)
+            .or_default()
+            .push(edge_id);
+        self.edges_by_node
+            .entry(edge_info.target_id)
+            .or_default()
+            .push(edge_id);
+        self.outgoing_edges
+            .entry(edge_info.source_id)
+            .or_default()
+            .push(edge_id);
+        self.incoming_edges
+            .entry(edge_info.target_id)
+            .or_default()
+            .push(edge_id);
+        self.typed_adjacency
+            .entry((edge_info.graph_id, edge_info.edge_type.clone()))
+            .or_default()
+            .entry(edge_info.source_id)
+            .or_default()
+            .push(edge_info.target_id);
+        self.edges.insert(edge_id, edge_info);
+    }
+
+    /// Drop a node from a hyperedge, removing the hyperedge once it joins fewer than two nodes
+    fn detach_from_hyperedge(&mut self, edge_id: EdgeId, node_id: NodeId) {
+        let emptied = match self.hyperedges.get_mut(&edge_id) {
+            Some(hyperedge) => {
+                hyperedge.node_ids.retain(|id| *id != node_id);
+                hyperedge.node_ids.len() < 2
+            }
+            None => false,
+        };
+        if !emptied {
+            return;
+        }
+        if let Some(hyperedge) = self.hyperedges.remove(&edge_id) {
+            for member in hyperedge.node_ids {
+                if let Some(edge_ids) = self.hyperedges_by_node.get_mut(&member) {
+                    edge_ids.retain(|id| *id != edge_id);
+                }
+            }
+        }
+    }
+
+    /// Remove an edge and its entries in every index
+    fn unindex_edge(&mut self, edge_id: &EdgeId) {
+        let Some(edge_info) = self.edges.remove(edge_id) else {
+            return;
+        };
+        if let Some(edges) = self.edges_by_graph.get_mut(&edge_info.graph_id) {
+            edges.retain(|id| id != edge_id);
+        }
+        if let Some(edges) = self.edges_by_type.get_mut(&edge_info.edge_type) {
+            edges.retain(|id| id != edge_id);
+        }
+        if let Some(edges) = self.edges_by_node.get_mut(&edge_info.source_id) {
+            edges.retain(|id| id != edge_id);
+        }
+        if let Some(edges) = self.edges_by_node.get_mut(&edge_info.target_id) {
+            edges.retain(|id| id != edge_id);
+        }
+        if let Some(edges) = self.outgoing_edges.get_mut(&edge_info.source_id) {
+            edges.retain(|id| id != edge_id);
+        }
+        if let Some(edges) = self.incoming_edges.get_mut(&edge_info.target_id) {
+            edges.retain(|id| id != edge_id);
+        }
+        let type_key = (edge_info.graph_id, edge_info.edge_type);
+        if let Some(adjacency) = self.typed_adjacency.get_mut(&type_key) {
+            if let Some(targets) = adjacency.get_mut(&edge_info.source_id) {
+                if let Some(index) = targets.iter().position(|t| t == &edge_info.target_id) {
+                    targets.remove(index);
+                }
+                if targets.is_empty() {
+                    adjacency.remove(&edge_info.source_id);
+                }
+            }
+            if adjacency.is_empty() {
+                self.typed_adjacency.remove(&type_key);
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -167,7 +476,12 @@ impl Projection for EdgeListProjection {
         self.edges_by_node.clear();
         self.incoming_edges.clear();
         self.outgoing_edges.clear();
+        self.typed_adjacency.clear();
+        self.hyperedges.clear();
+        self.hyperedges_by_node.clear();
+        self.removed_edges.clear();
         self.checkpoint = None;
+        self.generation += 1;
         Ok(())
     }
 
@@ -193,80 +507,97 @@ impl super::GraphProjection for EdgeListProjection {
                 relationship: _,
                 edge_type,
                 metadata,
+                created_at,
             }) => {
-                let edge_info = EdgeInfo {
+                // A repeated EdgeAdded replaces the edge instead of indexing it twice
+                self.unindex_edge(&edge_id);
+                self.index_edge(EdgeInfo {
                     edge_id,
                     graph_id,
                     source_id: source,
                     target_id: target,
-                    edge_type: edge_type.clone(),
+                    edge_type,
                     metadata,
-                };
-
-                // Add to main index
-                self.edges.insert(edge_id, edge_info);
-
-                // Add to graph index
-                self.edges_by_graph
-                    .entry(graph_id)
-                    .or_default()
-                    .push(edge_id);
-
-                // Add to type index
-                self.edges_by_type
-                    .entry(edge_type)
-                    .or_default()
-                    .push(edge_id);
-
-                // Add to node indices
-                self.edges_by_node
-                    .entry(source)
-                    .or_default()
-                    .push(edge_id);
-                self.edges_by_node
-                    .entry(target)
-                    .or_default()
-                    .push(edge_id);
+                    created_at,
+                    last_modified: created_at,
+                });
+                self.generation += 1;
+            }
 
-                // Add to directional indices
-                self.outgoing_edges
-                    .entry(source)
-                    .or_default()
-                    .push(edge_id);
-                self.incoming_edges
-                    .entry(target)
-                    .or_default()
-                    .push(edge_id);
+            GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+                graph_id,
+                edge_id,
+                removed_at,
+                ..
+            }) => {
+                if self.edges.contains_key(&edge_id) {
+                    self.unindex_edge(&edge_id);
+                    self.removed_edges
+                        .entry(graph_id)
+                        .or_default()
+                        .insert(edge_id, removed_at);
+                    self.generation += 1;
+                }
             }
 
-            GraphDomainEvent::EdgeRemoved(EdgeRemoved { graph_id, edge_id }) => {
-                // Remove from main index
-                if let Some(edge_info) = self.edges.remove(&edge_id) {
-                    // Remove from graph index
-                    if let Some(edges) = self.edges_by_graph.get_mut(&graph_id) {
-                        edges.retain(|id| id != &edge_id);
+            GraphDomainEvent::EdgeUpdated(EdgeUpdated {
+                edge_id,
+                metadata,
+                endpoints,
+                updated_at,
+                ..
+            }) => {
+                // Updated metadata keys are merged into the existing metadata
+                if let Some(edge_info) = self.edges.get_mut(&edge_id) {
+                    edge_info.metadata.extend(metadata);
+                    edge_info.last_modified = updated_at;
+
+                    // Redirected edges move between the node indices
+                    if let Some((source_id, target_id)) = endpoints {
+                        let mut edge_info = edge_info.clone();
+                        edge_info.source_id = source_id;
+                        edge_info.target_id = target_id;
+                        self.unindex_edge(&edge_id);
+                        self.index_edge(edge_info);
                     }
+                    self.generation += 1;
+                }
+            }
 
-                    // Remove from type index
-                    if let Some(edges) = self.edges_by_type.get_mut(&edge_info.edge_type) {
-                        edges.retain(|id| id != &edge_id);
-                    }
+            GraphDomainEvent::HyperEdgeAdded(HyperEdgeAdded {
+                graph_id,
+                edge_id,
+                node_ids,
+                edge_type,
+                metadata,
+                ..
+            }) => {
+                for node_id in &node_ids {
+                    self.hyperedges_by_node
+                        .entry(*node_id)
+                        .or_default()
+                        .push(edge_id);
+                }
 
-                    // Remove from node indices
-                    if let Some(edges) = self.edges_by_node.get_mut(&edge_info.source_id) {
-                        edges.retain(|id| id != &edge_id);
-                    }
-                    if let Some(edges) = self.edges_by_node.get_mut(&edge_info.target_id) {
-                        edges.retain(|id| id != &edge_id);
-                    }
+                let hyperedge_info = HyperEdgeInfo {
+                    edge_id,
+                    graph_id,
+                    node_ids,
+                    edge_type,
+                    metadata,
+                };
+                self.hyperedges.insert(edge_id, hyperedge_info);
 
-                    // Remove from directional indices
-                    if let Some(edges) = self.outgoing_edges.get_mut(&edge_info.source_id) {
-                        edges.retain(|id| id != &edge_id);
-                    }
-                    if let Some(edges) = self.incoming_edges.get_mut(&edge_info.target_id) {
-                        edges.retain(|id| id != &edge_id);
+                self.generation += 1;
+            }
+
+            GraphDomainEvent::NodeRemoved(NodeRemoved { node_id, .. }) => {
+                // A removed node leaves its hyperedges, as in the aggregate
+                if let Some(edge_ids) = self.hyperedges_by_node.remove(&node_id) {
+                    for edge_id in edge_ids {
+                        self.detach_from_hyperedge(edge_id, node_id);
                     }
+                    self.generation += 1;
                 }
             }
 
@@ -277,6 +608,10 @@ impl super::GraphProjection for EdgeListProjection {
 
         Ok(())
     }
+
+    fn generation(&self) -> u64 {
+        self.generation
+    }
 }
 
 #[cfg(test)]
@@ -284,6 +619,7 @@ mod tests {
     use super::*;
     use crate::projections::GraphProjection;
     use crate::components::EdgeRelationship;
+    use std::collections::HashSet;
 
     #[tokio::test]
     async fn test_edge_list_projection() {
@@ -305,6 +641,7 @@ mod tests {
             },
             edge_type: "dependency".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
 
         projection.handle_graph_event(add_event).await.unwrap();
@@ -348,13 +685,19 @@ mod tests {
             relationship: EdgeRelationship::Similarity { score: 0.8 },
             edge_type: "similarity".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
 
         projection.handle_graph_event(add_event).await.unwrap();
         assert_eq!(projection.total_edges(), 1);
 
         // Remove the edge
-        let remove_event = GraphDomainEvent::EdgeRemoved(EdgeRemoved { graph_id, edge_id });
+        let remove_event = GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+            graph_id,
+            edge_id,
+            removed: None,
+            removed_at: chrono::Utc::now(),
+        });
 
         projection.handle_graph_event(remove_event).await.unwrap();
         assert_eq!(projection.total_edges(), 0);
@@ -381,6 +724,7 @@ mod tests {
             },
             edge_type: "dependency".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
 
         let edge2 = GraphDomainEvent::EdgeAdded(EdgeAdded {
@@ -394,6 +738,7 @@ mod tests {
             },
             edge_type: "dependency".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
 
         projection.handle_graph_event(edge1).await.unwrap();
@@ -405,4 +750,130 @@ mod tests {
         assert!(adjacency.get(&node1).unwrap().contains(&node2));
         assert!(adjacency.get(&node1).unwrap().contains(&node3));
     }
-} 
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_predecessor_list() {
+        let mut projection = EdgeListProjection::new();
+        let graph_id = GraphId::new();
+        let node1 = NodeId::new();
+        let node2 = NodeId::new();
+        let node3 = NodeId::new();
+
+        // Add edges: node1 -> node3, node2 -> node3, node3 -> node1
+        for (source, target) in [(node1, node3), (node2, node3), (node3, node1)] {
+            let event = GraphDomainEvent::EdgeAdded(EdgeAdded {
+                graph_id,
+                edge_id: EdgeId::new(),
+                source,
+                target,
+                relationship: EdgeRelationship::Dependency {
+                    dependency_type: "test".to_string(),
+                    strength: 1.0,
+                },
+                edge_type: "dependency".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            });
+            projection.handle_graph_event(event).await.unwrap();
+        }
+
+        let predecessors = projection.get_predecessor_list(&graph_id);
+        let incoming: HashSet<NodeId> = projection
+            .get_incoming_edges(&node3)
+            .iter()
+            .map(|edge| edge.source_id)
+            .collect();
+        let of_node3: HashSet<NodeId> = predecessors[&node3].iter().copied().collect();
+        assert_eq!(of_node3, incoming);
+        assert_eq!(of_node3, HashSet::from([node1, node2]));
+        assert!(!predecessors.contains_key(&node2));
+
+        let both = projection.get_bidirectional_adjacency(&graph_id);
+        let around_node1: HashSet<NodeId> = both[&node1].iter().copied().collect();
+        assert_eq!(around_node1, HashSet::from([node3]));
+        assert_eq!(both[&node3].len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_typed_adjacency() {
+        let mut projection = EdgeListProjection::new();
+        let graph_id = GraphId::new();
+        let start = NodeId::new();
+        let next = NodeId::new();
+        let branch = NodeId::new();
+
+        let mut sequence_ids = Vec::new();
+        let edges = [(next, "sequence"), (branch, "conditional"), (branch, "sequence")];
+        for (target, edge_type) in edges {
+            let edge_id = EdgeId::new();
+            if edge_type == "sequence" {
+                sequence_ids.push(edge_id);
+            }
+            let event = GraphDomainEvent::EdgeAdded(EdgeAdded {
+                graph_id,
+                edge_id,
+                source: start,
+                target,
+                relationship: EdgeRelationship::association(edge_type),
+                edge_type: edge_type.to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            });
+            projection.handle_graph_event(event).await.unwrap();
+        }
+
+        let sequence = projection.get_typed_adjacency(&graph_id, "sequence").unwrap();
+        assert_eq!(sequence[&start], vec![next, branch]);
+        let conditional = projection.get_typed_adjacency(&graph_id, "conditional").unwrap();
+        assert_eq!(conditional[&start], vec![branch]);
+
+        let removal = GraphDomainEvent::EdgeRemoved(EdgeRemoved {
+            graph_id,
+            edge_id: sequence_ids[1],
+            removed: None,
+            removed_at: chrono::Utc::now(),
+        });
+        projection.handle_graph_event(removal).await.unwrap();
+
+        let sequence = projection.get_typed_adjacency(&graph_id, "sequence").unwrap();
+        assert_eq!(sequence[&start], vec![next]);
+        let conditional = projection.get_typed_adjacency(&graph_id, "conditional").unwrap();
+        assert_eq!(conditional[&start], vec![branch]);
+        assert!(projection.get_typed_adjacency(&GraphId::new(), "sequence").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_node_removal_detaches_hyperedges() {
+        let mut projection = EdgeListProjection::new();
+        let graph_id = GraphId::new();
+        let pair_id = EdgeId::new();
+        let triple_id = EdgeId::new();
+        let (a, b, c) = (NodeId::new(), NodeId::new(), NodeId::new());
+
+        for (edge_id, node_ids) in [(pair_id, vec![a, b]), (triple_id, vec![a, b, c])] {
+            let event = GraphDomainEvent::HyperEdgeAdded(HyperEdgeAdded {
+                graph_id,
+                edge_id,
+                node_ids,
+                edge_type: "join".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            });
+            projection.handle_graph_event(event).await.unwrap();
+        }
+
+        let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved {
+            graph_id,
+            node_id: a,
+            removed_at: chrono::Utc::now(),
+        });
+        projection.handle_graph_event(remove_event).await.unwrap();
+
+        // The pair drops below two nodes and is removed; the triple keeps b and c
+        assert!(projection.get_hyperedges_for_node(&a).is_empty());
+        let remaining = projection.get_hyperedges_for_node(&b);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].edge_id, triple_id);
+        assert_eq!(remaining[0].node_ids, vec![b, c]);
+    }
+}
@@ -4,7 +4,10 @@
 
 use crate::{
     domain_events::GraphDomainEvent,
-    events::{EdgeAdded, EdgeRemoved, GraphCreated, NodeAdded, NodeRemoved},
+    events::{
+        EdgeAdded, EdgeRemoved, EdgeUpdated, GraphCreated, GraphUpdated, HyperEdgeAdded,
+        NodeAdded, NodeMoved, NodeRemoved, NodeUpdated,
+    },
     GraphId,
 };
 use async_trait::async_trait;
@@ -37,11 +40,25 @@ pub struct GraphSummary {
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
+/// Field used to order graph summaries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortBy {
+    /// Order by graph name (case-insensitive)
+    Name,
+    /// Order by creation time
+    CreatedAt,
+    /// Order by last modification time
+    LastModified,
+    /// Order by number of nodes
+    NodeCount,
+}
+
 /// Projection that maintains graph summaries
 #[derive(Debug, Clone)]
 pub struct GraphSummaryProjection {
     summaries: HashMap<GraphId, GraphSummary>,
     checkpoint: Option<EventSequence>,
+    generation: u64,
 }
 
 impl Default for GraphSummaryProjection {
@@ -56,6 +73,7 @@ impl GraphSummaryProjection {
         Self {
             summaries: HashMap::new(),
             checkpoint: None,
+            generation: 0,
         }
     }
 
@@ -79,10 +97,50 @@ impl GraphSummaryProjection {
         self.summaries.values().skip(offset).take(limit).collect()
     }
 
+    /// Get summaries ordered by `sort`, with pagination
+    ///
+    /// Ties are broken by graph ID so pages are stable across calls.
+    pub fn get_summaries_sorted(
+        &self,
+        sort: SortBy,
+        ascending: bool,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<&GraphSummary> {
+        let mut summaries: Vec<&GraphSummary> = self.summaries.values().collect();
+        summaries.sort_by(|a, b| {
+            let ordering = match sort {
+                SortBy::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+                SortBy::CreatedAt => a.created_at.cmp(&b.created_at),
+                SortBy::LastModified => a.last_modified.cmp(&b.last_modified),
+                SortBy::NodeCount => a.node_count.cmp(&b.node_count),
+            };
+            let ordering = if ascending { ordering } else { ordering.reverse() };
+            ordering.then_with(|| a.graph_id.as_uuid().cmp(b.graph_id.as_uuid()))
+        });
+        summaries.into_iter().skip(offset).take(limit).collect()
+    }
+
     /// Get total number of graphs
     pub fn total_graphs(&self) -> usize {
         self.summaries.len()
     }
+
+    /// Combine another shard into this projection
+    ///
+    /// A graph present in both keeps the summary with the later `last_modified`, preferring
+    /// `other` on ties. The checkpoint of `self` is kept.
+    pub fn merge(&mut self, other: Self) {
+        for (graph_id, summary) in other.summaries {
+            match self.summaries.get(&graph_id) {
+                Some(existing) if existing.last_modified > summary.last_modified => {}
+                _ => {
+                    self.summaries.insert(graph_id, summary);
+                }
+            }
+        }
+        self.generation += 1;
+    }
 }
 
 #[async_trait]
@@ -96,6 +154,7 @@ impl Projection for GraphSummaryProjection {
     async fn clear(&mut self) -> Result<(), String> {
         self.summaries.clear();
         self.checkpoint = None;
+        self.generation += 1;
         Ok(())
     }
 
@@ -146,12 +205,35 @@ impl super::GraphProjection for GraphSummaryProjection {
                     metadata,
                 };
                 self.summaries.insert(graph_id, summary);
+                self.generation += 1;
+            }
+
+            GraphDomainEvent::GraphUpdated(GraphUpdated {
+                graph_id,
+                name,
+                description,
+                metadata,
+                ..
+            }) => {
+                // Only the fields carried by the event change
+                if let Some(summary) = self.summaries.get_mut(&graph_id) {
+                    if let Some(name) = name {
+                        summary.name = name;
+                    }
+                    if let Some(description) = description {
+                        summary.description = description;
+                    }
+                    summary.metadata.extend(metadata);
+                    summary.last_modified = Utc::now();
+                    self.generation += 1;
+                }
             }
 
             GraphDomainEvent::NodeAdded(NodeAdded { graph_id, .. }) => {
                 if let Some(summary) = self.summaries.get_mut(&graph_id) {
                     summary.node_count += 1;
                     summary.last_modified = Utc::now();
+                    self.generation += 1;
                 }
             }
 
@@ -159,6 +241,15 @@ impl super::GraphProjection for GraphSummaryProjection {
                 if let Some(summary) = self.summaries.get_mut(&graph_id) {
                     summary.node_count = summary.node_count.saturating_sub(1);
                     summary.last_modified = Utc::now();
+                    self.generation += 1;
+                }
+            }
+
+            GraphDomainEvent::NodeUpdated(NodeUpdated { graph_id, .. })
+            | GraphDomainEvent::NodeMoved(NodeMoved { graph_id, .. }) => {
+                if let Some(summary) = self.summaries.get_mut(&graph_id) {
+                    summary.last_modified = Utc::now();
+                    self.generation += 1;
                 }
             }
 
@@ -166,6 +257,7 @@ impl super::GraphProjection for GraphSummaryProjection {
                 if let Some(summary) = self.summaries.get_mut(&graph_id) {
                     summary.edge_count += 1;
                     summary.last_modified = Utc::now();
+                    self.generation += 1;
                 }
             }
 
@@ -173,12 +265,32 @@ impl super::GraphProjection for GraphSummaryProjection {
                 if let Some(summary) = self.summaries.get_mut(&graph_id) {
                     summary.edge_count = summary.edge_count.saturating_sub(1);
                     summary.last_modified = Utc::now();
+                    self.generation += 1;
+                }
+            }
+
+            GraphDomainEvent::EdgeUpdated(EdgeUpdated { graph_id, .. }) => {
+                if let Some(summary) = self.summaries.get_mut(&graph_id) {
+                    summary.last_modified = Utc::now();
+                    self.generation += 1;
+                }
+            }
+
+            GraphDomainEvent::HyperEdgeAdded(HyperEdgeAdded { graph_id, .. }) => {
+                // Hyperedges are not counted as binary edges
+                if let Some(summary) = self.summaries.get_mut(&graph_id) {
+                    summary.last_modified = Utc::now();
+                    self.generation += 1;
                 }
             }
         }
 
         Ok(())
     }
+
+    fn generation(&self) -> u64 {
+        self.generation
+    }
 }
 
 #[cfg(test)]
@@ -217,6 +329,7 @@ mod tests {
             position: crate::value_objects::Position3D::default(),
             node_type: "TestNode".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
 
         projection.handle_graph_event(node_event).await.unwrap();
@@ -245,4 +358,47 @@ mod tests {
         assert!(projection.get_checkpoint().await.is_none());
         assert_eq!(projection.total_graphs(), 0);
     }
+
+    #[tokio::test]
+    async fn test_sorted_summaries() {
+        let mut projection = GraphSummaryProjection::new();
+
+        for name in ["charlie", "Alpha", "delta", "bravo", "echo"] {
+            let event = GraphDomainEvent::GraphCreated(GraphCreated {
+                graph_id: GraphId::new(),
+                name: name.to_string(),
+                description: String::new(),
+                graph_type: None,
+                metadata: HashMap::new(),
+                created_at: Utc::now(),
+            });
+            projection.handle_graph_event(event).await.unwrap();
+        }
+
+        let names = |summaries: Vec<&GraphSummary>| {
+            summaries
+                .into_iter()
+                .map(|summary| summary.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(projection.get_summaries_sorted(SortBy::Name, true, 0, 10)),
+            vec!["Alpha", "bravo", "charlie", "delta", "echo"]
+        );
+        assert_eq!(
+            names(projection.get_summaries_sorted(SortBy::Name, false, 0, 2)),
+            vec!["echo", "delta"]
+        );
+
+        // Pages over the sorted set are stable and disjoint
+        let first_page = names(projection.get_summaries_sorted(SortBy::Name, true, 0, 3));
+        let second_page = names(projection.get_summaries_sorted(SortBy::Name, true, 3, 3));
+        assert_eq!(first_page, vec!["Alpha", "bravo", "charlie"]);
+        assert_eq!(second_page, vec!["delta", "echo"]);
+        assert_eq!(
+            first_page,
+            names(projection.get_summaries_sorted(SortBy::Name, true, 0, 3))
+        );
+    }
 }
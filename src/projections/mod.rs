@@ -12,6 +12,7 @@ pub use graph_summary::*;
 pub use node_list::*;
 
 use crate::domain_events::GraphDomainEvent;
+use crate::GraphId;
 use async_trait::async_trait;
 
 /// Trait for graph-specific projections
@@ -19,4 +20,119 @@ use async_trait::async_trait;
 pub trait GraphProjection: Send + Sync {
     /// Handle a graph domain event to update the projection
     async fn handle_graph_event(&mut self, event: GraphDomainEvent) -> Result<(), String>;
+
+    /// Handle a batch of graph domain events in order
+    ///
+    /// The default applies each event through `handle_graph_event`. Projections can
+    /// override this to batch their internal index updates when bootstrapping.
+    async fn handle_graph_events(&mut self, events: Vec<GraphDomainEvent>) -> Result<(), String> {
+        for event in events {
+            self.handle_graph_event(event).await?;
+        }
+        Ok(())
+    }
+
+    /// Monotonic counter that increases whenever an event changes the projection
+    ///
+    /// Caches built on top of a projection can compare generations to detect staleness.
+    fn generation(&self) -> u64;
+}
+
+/// Report where the three projections disagree about a graph
+///
+/// Checks that the summary exists, that its node and edge counts match the node and edge
+/// lists, and that every edge connects nodes of the graph. An empty result means the
+/// projections are consistent.
+pub fn verify_consistency(
+    summary: &GraphSummaryProjection,
+    nodes: &NodeListProjection,
+    edges: &EdgeListProjection,
+    graph_id: GraphId,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    let graph_nodes = nodes.get_nodes_by_graph(&graph_id);
+    let graph_edges = edges.get_edges_by_graph(&graph_id);
+
+    match summary.get_summary(&graph_id) {
+        Some(graph_summary) => {
+            if graph_summary.node_count != graph_nodes.len() {
+                problems.push(format!(
+                    "Summary node count {} does not match {} listed nodes",
+                    graph_summary.node_count,
+                    graph_nodes.len()
+                ));
+            }
+            if graph_summary.edge_count != graph_edges.len() {
+                problems.push(format!(
+                    "Summary edge count {} does not match {} listed edges",
+                    graph_summary.edge_count,
+                    graph_edges.len()
+                ));
+            }
+        }
+        None => problems.push(format!("Graph {graph_id} has no summary")),
+    }
+
+    let known = |node_id| {
+        nodes
+            .get_node(node_id)
+            .is_some_and(|node| node.graph_id == graph_id)
+    };
+    for edge in graph_edges {
+        for endpoint in [&edge.source_id, &edge.target_id] {
+            if !known(endpoint) {
+                problems.push(format!(
+                    "Edge {} references unknown node {endpoint}",
+                    edge.edge_id
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{GraphCreated, NodeAdded};
+    use crate::value_objects::Position3D;
+    use crate::NodeId;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_verify_consistency_detects_count_drift() {
+        let graph_id = GraphId::new();
+        let mut summary = GraphSummaryProjection::new();
+        let mut nodes = NodeListProjection::new();
+        let edges = EdgeListProjection::new();
+
+        let created = GraphDomainEvent::GraphCreated(GraphCreated {
+            graph_id,
+            name: "Drift".to_string(),
+            description: String::new(),
+            graph_type: None,
+            metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
+        });
+        let added = GraphDomainEvent::NodeAdded(NodeAdded {
+            graph_id,
+            node_id: NodeId::new(),
+            position: Position3D::default(),
+            node_type: "task".to_string(),
+            metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
+        });
+        summary.handle_graph_event(created).await.unwrap();
+        summary.handle_graph_event(added.clone()).await.unwrap();
+        nodes.handle_graph_event(added.clone()).await.unwrap();
+        assert!(verify_consistency(&summary, &nodes, &edges, graph_id).is_empty());
+
+        // The summary sees the event a second time, the node list does not
+        summary.handle_graph_event(added).await.unwrap();
+        assert_eq!(
+            verify_consistency(&summary, &nodes, &edges, graph_id),
+            vec!["Summary node count 2 does not match 1 listed nodes".to_string()]
+        );
+    }
 }
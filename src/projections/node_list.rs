@@ -3,9 +3,10 @@
 //! Provides a searchable list of all nodes across graphs.
 
 use crate::{
+    components::SpatialHash,
     domain_events::GraphDomainEvent,
-    events::{NodeAdded, NodeRemoved},
-    value_objects::{Position2D, Position3D},
+    events::{NodeAdded, NodeMoved, NodeRemoved, NodeUpdated},
+    value_objects::{DistanceMetric, Position2D, Position3D},
     GraphId, NodeId,
 };
 use async_trait::async_trait;
@@ -31,15 +32,25 @@ pub struct NodeInfo {
     pub position_3d: Option<Position3D>,
     /// Additional metadata about the node
     pub metadata: HashMap<String, serde_json::Value>,
+    /// When the node was added
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// When the node was last added, updated or moved
+    pub last_modified: chrono::DateTime<chrono::Utc>,
 }
 
+/// Side length of the grid cells used by the spatial index
+pub const SPATIAL_CELL_SIZE: f32 = 100.0;
+
 /// Projection that maintains a searchable list of nodes
 #[derive(Debug, Clone)]
 pub struct NodeListProjection {
     nodes: HashMap<NodeId, NodeInfo>,
     nodes_by_graph: HashMap<GraphId, Vec<NodeId>>,
     nodes_by_type: HashMap<String, Vec<NodeId>>,
+    nodes_by_cell: HashMap<SpatialHash, Vec<NodeId>>,
+    removed_nodes: HashMap<GraphId, HashMap<NodeId, chrono::DateTime<chrono::Utc>>>,
     checkpoint: Option<EventSequence>,
+    generation: u64,
 }
 
 impl Default for NodeListProjection {
@@ -55,7 +66,10 @@ impl NodeListProjection {
             nodes: HashMap::new(),
             nodes_by_graph: HashMap::new(),
             nodes_by_type: HashMap::new(),
+            nodes_by_cell: HashMap::new(),
+            removed_nodes: HashMap::new(),
             checkpoint: None,
+            generation: 0,
         }
     }
 
@@ -85,6 +99,26 @@ impl NodeListProjection {
             .unwrap_or_default()
     }
 
+    /// Get the nodes of a graph removed after `since`
+    ///
+    /// A removed node that is added again is no longer reported.
+    pub fn get_removed_since(
+        &self,
+        graph_id: &GraphId,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Vec<NodeId> {
+        self.removed_nodes
+            .get(graph_id)
+            .map(|removed| {
+                removed
+                    .iter()
+                    .filter(|(_, removed_at)| **removed_at > since)
+                    .map(|(node_id, _)| *node_id)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Search nodes by name (case-insensitive partial match)
     pub fn search_by_name(&self, query: &str) -> Vec<&NodeInfo> {
         let query_lower = query.to_lowercase();
@@ -99,12 +133,177 @@ impl NodeListProjection {
             .collect()
     }
 
+    /// Find nodes of a graph within `radius` of `center` under `metric`
+    ///
+    /// Only the grid cells overlapping the query square are visited; every supported
+    /// metric's ball fits inside that square.
+    pub fn find_nodes_near(
+        &self,
+        graph_id: &GraphId,
+        center: Position2D,
+        radius: f64,
+        metric: DistanceMetric,
+    ) -> Vec<&NodeInfo> {
+        if radius < 0.0 {
+            return Vec::new();
+        }
+
+        let cell_size = SPATIAL_CELL_SIZE as f64;
+        // SpatialHash rounds to the nearest cell, so cell k covers [(k - 0.5), (k + 0.5)) * size
+        let min_x = ((center.x - radius) / cell_size).round() as i64;
+        let max_x = ((center.x + radius) / cell_size).round() as i64;
+        let min_y = ((center.y - radius) / cell_size).round() as i64;
+        let max_y = ((center.y + radius) / cell_size).round() as i64;
+
+        let within_radius = |node: &&NodeInfo| {
+            node.graph_id == *graph_id
+                && node
+                    .position_2d
+                    .is_some_and(|pos| metric.distance(&pos, &center) <= radius)
+        };
+
+        // Fall back to scanning the graph when the query covers more cells than it has nodes
+        let cell_count = (max_x - min_x + 1).saturating_mul(max_y - min_y + 1);
+        if cell_count > self.get_node_count_for_graph(graph_id) as i64 {
+            return self
+                .get_nodes_by_graph(graph_id)
+                .into_iter()
+                .filter(within_radius)
+                .collect();
+        }
+
+        let mut found = Vec::new();
+        for cell_x in min_x..=max_x {
+            for cell_y in min_y..=max_y {
+                let cell_center = Position3D::new(
+                    cell_x as f64 * cell_size,
+                    cell_y as f64 * cell_size,
+                    0.0,
+                );
+                let hash = SpatialHash::from_position(&cell_center, SPATIAL_CELL_SIZE);
+                if let Some(ids) = self.nodes_by_cell.get(&hash) {
+                    found.extend(
+                        ids.iter()
+                            .filter_map(|id| self.nodes.get(id))
+                            .filter(within_radius),
+                    );
+                }
+            }
+        }
+        found
+    }
+
+    /// Grid cell of a node position, ignoring depth
+    fn cell_of(position: &Position2D) -> SpatialHash {
+        SpatialHash::from_position(
+            &Position3D::new(position.x, position.y, 0.0),
+            SPATIAL_CELL_SIZE,
+        )
+    }
+
+    /// Store a node's new position and move it to the matching spatial cell
+    fn relocate(
+        nodes_by_cell: &mut HashMap<SpatialHash, Vec<NodeId>>,
+        node_info: &mut NodeInfo,
+        position: Position3D,
+    ) {
+        let node_id = node_info.node_id;
+        let new_position = Position2D::new(position.x, position.y);
+        if let Some(old_position) = &node_info.position_2d {
+            let cell = Self::cell_of(old_position);
+            if let Some(nodes) = nodes_by_cell.get_mut(&cell) {
+                nodes.retain(|id| id != &node_id);
+                if nodes.is_empty() {
+                    nodes_by_cell.remove(&cell);
+                }
+            }
+        }
+        nodes_by_cell
+            .entry(Self::cell_of(&new_position))
+            .or_default()
+            .push(node_id);
+        node_info.position_2d = Some(new_position);
+        node_info.position_3d = Some(position);
+    }
+
+    /// Combine another shard into this projection
+    ///
+    /// A node present in both keeps the copy with the later `created_at`, preferring `other`
+    /// on ties. Indexes are updated for every replaced or added node; the checkpoint of
+    /// `self` is kept.
+    pub fn merge(&mut self, other: Self) {
+        for (node_id, node_info) in other.nodes {
+            if let Some(existing) = self.nodes.get(&node_id) {
+                if existing.created_at > node_info.created_at {
+                    continue;
+                }
+                self.unindex_node(&node_id);
+            }
+            self.index_node(node_info);
+        }
+        for (graph_id, removed) in other.removed_nodes {
+            self.removed_nodes.entry(graph_id).or_default().extend(
+                removed
+                    .into_iter()
+                    .filter(|(node_id, _)| !self.nodes.contains_key(node_id)),
+            );
+        }
+        self.generation += 1;
+    }
+
+    /// Store a node and add it to every index
+    fn index_node(&mut self, node_info: NodeInfo) {
+        let node_id = node_info.node_id;
+        if let Some(removed) = self.removed_nodes.get_mut(&node_info.graph_id) {
+            removed.remove(&node_id);
+        }
+        if let Some(position) = &node_info.position_2d {
+            self.nodes_by_cell
+                .entry(Self::cell_of(position))
+                .or_default()
+                .push(node_id);
+        }
+        self.nodes_by_graph
+            .entry(node_info.graph_id)
+            .or_default()
+            .push(node_id);
+        self.nodes_by_type
+            .entry(node_info.node_type.clone())
+            .or_default()
+            .push(node_id);
+        self.nodes.insert(node_id, node_info);
+    }
+
+    /// Remove a node and its entries in every index
+    fn unindex_node(&mut self, node_id: &NodeId) {
+        let Some(node_info) = self.nodes.remove(node_id) else {
+            return;
+        };
+        if let Some(nodes) = self.nodes_by_graph.get_mut(&node_info.graph_id) {
+            nodes.retain(|id| id != node_id);
+        }
+        if let Some(nodes) = self.nodes_by_type.get_mut(&node_info.node_type) {
+            nodes.retain(|id| id != node_id);
+        }
+        if let Some(position) = &node_info.position_2d {
+            let cell = Self::cell_of(position);
+            if let Some(nodes) = self.nodes_by_cell.get_mut(&cell) {
+                nodes.retain(|id| id != node_id);
+                if nodes.is_empty() {
+                    self.nodes_by_cell.remove(&cell);
+                }
+            }
+        }
+    }
+
     /// Get total number of nodes
     pub fn total_nodes(&self) -> usize {
         self.nodes.len()
     }
 
     /// Get node count for a specific graph
+    ///
+    /// Reads the size of the per-graph index, which is kept in step with every event.
     pub fn get_node_count_for_graph(&self, graph_id: &GraphId) -> usize {
         self.nodes_by_graph
             .get(graph_id)
@@ -119,6 +318,15 @@ impl NodeListProjection {
             .map(|(node_type, ids)| (node_type.clone(), ids.len()))
             .collect()
     }
+
+    /// Get node count by type within one graph
+    pub fn count_by_type_in_graph(&self, graph_id: &GraphId) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for node in self.get_nodes_by_graph(graph_id) {
+            *counts.entry(node.node_type.clone()).or_default() += 1;
+        }
+        counts
+    }
 }
 
 #[async_trait]
@@ -133,7 +341,10 @@ impl Projection for NodeListProjection {
         self.nodes.clear();
         self.nodes_by_graph.clear();
         self.nodes_by_type.clear();
+        self.nodes_by_cell.clear();
+        self.removed_nodes.clear();
         self.checkpoint = None;
+        self.generation += 1;
         Ok(())
     }
 
@@ -157,7 +368,7 @@ impl super::GraphProjection for NodeListProjection {
                 position,
                 node_type,
                 metadata,
-                ..
+                created_at,
             }) => {
                 // Extract name from metadata if present
                 let name = metadata
@@ -172,41 +383,93 @@ impl super::GraphProjection for NodeListProjection {
                 let node_info = NodeInfo {
                     node_id,
                     graph_id,
-                    node_type: node_type.clone(),
+                    node_type,
                     name,
                     position_2d,
                     position_3d,
                     metadata,
+                    created_at,
+                    last_modified: created_at,
                 };
 
-                // Add to main index
-                self.nodes.insert(node_id, node_info);
-
-                // Add to graph index
-                self.nodes_by_graph
-                    .entry(graph_id)
-                    .or_default()
-                    .push(node_id);
+                // A repeated NodeAdded replaces the node instead of indexing it twice
+                self.unindex_node(&node_id);
+                self.index_node(node_info);
+                self.generation += 1;
+            }
 
-                // Add to type index
-                self.nodes_by_type
-                    .entry(node_type)
-                    .or_default()
-                    .push(node_id);
+            GraphDomainEvent::NodeRemoved(NodeRemoved {
+                graph_id,
+                node_id,
+                removed_at,
+            }) => {
+                if self.nodes.contains_key(&node_id) {
+                    self.unindex_node(&node_id);
+                    self.removed_nodes
+                        .entry(graph_id)
+                        .or_default()
+                        .insert(node_id, removed_at);
+                    self.generation += 1;
+                }
             }
 
-            GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id }) => {
-                // Remove from main index
-                if let Some(node_info) = self.nodes.remove(&node_id) {
-                    // Remove from graph index
-                    if let Some(nodes) = self.nodes_by_graph.get_mut(&graph_id) {
-                        nodes.retain(|id| id != &node_id);
+            GraphDomainEvent::NodeUpdated(NodeUpdated {
+                node_id,
+                position,
+                metadata,
+                node_type,
+                removed_keys,
+                updated_at,
+                ..
+            }) => {
+                if let Some(node_info) = self.nodes.get_mut(&node_id) {
+                    node_info.last_modified = updated_at;
+
+                    // Move the node to its new type bucket
+                    if let Some(node_type) = node_type {
+                        if node_type != node_info.node_type {
+                            if let Some(nodes) = self.nodes_by_type.get_mut(&node_info.node_type) {
+                                nodes.retain(|id| id != &node_id);
+                            }
+                            self.nodes_by_type
+                                .entry(node_type.clone())
+                                .or_default()
+                                .push(node_id);
+                            node_info.node_type = node_type;
+                        }
+                    }
+
+                    if let Some(position) = position {
+                        Self::relocate(&mut self.nodes_by_cell, node_info, position);
                     }
 
-                    // Remove from type index
-                    if let Some(nodes) = self.nodes_by_type.get_mut(&node_info.node_type) {
-                        nodes.retain(|id| id != &node_id);
+                    // Updated metadata keys are merged into the existing metadata
+                    for key in &removed_keys {
+                        node_info.metadata.remove(key);
+                    }
+                    if removed_keys.iter().any(|key| key == "name") {
+                        node_info.name = None;
                     }
+                    if let Some(name) = metadata.get("name").and_then(|v| v.as_str()) {
+                        node_info.name = Some(name.to_string());
+                    }
+                    node_info.metadata.extend(metadata);
+
+                    self.generation += 1;
+                }
+            }
+
+            GraphDomainEvent::NodeMoved(NodeMoved {
+                node_id,
+                position,
+                moved_at,
+                ..
+            }) => {
+                // Only the position changes; metadata is left untouched
+                if let Some(node_info) = self.nodes.get_mut(&node_id) {
+                    Self::relocate(&mut self.nodes_by_cell, node_info, position);
+                    node_info.last_modified = moved_at;
+                    self.generation += 1;
                 }
             }
 
@@ -217,6 +480,24 @@ impl super::GraphProjection for NodeListProjection {
 
         Ok(())
     }
+
+    async fn handle_graph_events(&mut self, events: Vec<GraphDomainEvent>) -> Result<(), String> {
+        // Size the main index once instead of growing it per event
+        let added = events
+            .iter()
+            .filter(|event| matches!(event, GraphDomainEvent::NodeAdded(_)))
+            .count();
+        self.nodes.reserve(added);
+
+        for event in events {
+            self.handle_graph_event(event).await?;
+        }
+        Ok(())
+    }
+
+    fn generation(&self) -> u64 {
+        self.generation
+    }
 }
 
 #[cfg(test)]
@@ -243,6 +524,7 @@ mod tests {
             position: crate::value_objects::Position3D::default(),
             node_type: "TestType".to_string(),
             metadata,
+            created_at: chrono::Utc::now(),
         });
 
         projection.handle_graph_event(add_event).await.unwrap();
@@ -278,16 +560,267 @@ mod tests {
             position: crate::value_objects::Position3D::default(),
             node_type: "TestType".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
 
         projection.handle_graph_event(add_event).await.unwrap();
         assert_eq!(projection.total_nodes(), 1);
 
         // Remove the node
-        let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved { graph_id, node_id });
+        let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved {
+            graph_id,
+            node_id,
+            removed_at: chrono::Utc::now(),
+        });
 
         projection.handle_graph_event(remove_event).await.unwrap();
         assert_eq!(projection.total_nodes(), 0);
         assert!(projection.get_node(&node_id).is_none());
     }
+
+    #[tokio::test]
+    async fn test_generation_tracks_applied_events() {
+        let mut projection = NodeListProjection::new();
+        let graph_id = GraphId::new();
+        let node_id = NodeId::new();
+        assert_eq!(projection.generation(), 0);
+
+        let add_event = GraphDomainEvent::NodeAdded(NodeAdded {
+            graph_id,
+            node_id,
+            position: crate::value_objects::Position3D::default(),
+            node_type: "TestType".to_string(),
+            metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
+        });
+        projection.handle_graph_event(add_event).await.unwrap();
+        assert_eq!(projection.generation(), 1);
+
+        // Removing an unknown node leaves the projection unchanged
+        let noop_event = GraphDomainEvent::NodeRemoved(NodeRemoved {
+            graph_id,
+            node_id: NodeId::new(),
+            removed_at: chrono::Utc::now(),
+        });
+        projection.handle_graph_event(noop_event).await.unwrap();
+        assert_eq!(projection.generation(), 1);
+
+        let remove_event = GraphDomainEvent::NodeRemoved(NodeRemoved {
+
+            graph_id,
+
+            node_id,
+
+            removed_at: chrono::Utc::now(),
+
+        });
+        projection.handle_graph_event(remove_event).await.unwrap();
+        assert_eq!(projection.generation(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_application_matches_sequential() {
+        let graph_id = GraphId::new();
+        let node_ids: Vec<NodeId> = (0..100).map(|_| NodeId::new()).collect();
+
+        let mut events: Vec<GraphDomainEvent> = node_ids
+            .iter()
+            .enumerate()
+            .map(|(i, node_id)| {
+                GraphDomainEvent::NodeAdded(NodeAdded {
+                    graph_id,
+                    node_id: *node_id,
+                    position: crate::value_objects::Position3D::default(),
+                    node_type: format!("Type{}", i % 3),
+                    metadata: HashMap::new(),
+                    created_at: chrono::Utc::now(),
+                })
+            })
+            .collect();
+        events.extend(node_ids.iter().step_by(4).map(|node_id| {
+            GraphDomainEvent::NodeRemoved(NodeRemoved {
+                graph_id,
+                node_id: *node_id,
+                removed_at: chrono::Utc::now(),
+            })
+        }));
+
+        let mut sequential = NodeListProjection::new();
+        for event in events.clone() {
+            sequential.handle_graph_event(event).await.unwrap();
+        }
+
+        let mut bulk = NodeListProjection::new();
+        bulk.handle_graph_events(events).await.unwrap();
+
+        assert_eq!(bulk.total_nodes(), sequential.total_nodes());
+        assert_eq!(bulk.generation(), sequential.generation());
+
+        let ids = |projection: &NodeListProjection| {
+            projection
+                .get_nodes_by_graph(&graph_id)
+                .iter()
+                .map(|node| node.node_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&bulk), ids(&sequential));
+
+        for node_type in ["Type0", "Type1", "Type2"] {
+            assert_eq!(
+                bulk.get_nodes_by_type(node_type).len(),
+                sequential.get_nodes_by_type(node_type).len()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spatial_index_matches_brute_force() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut projection = NodeListProjection::new();
+        let graph_id = GraphId::new();
+        let other_graph = GraphId::new();
+
+        let mut node_ids = Vec::new();
+        for i in 0..300 {
+            let node_id = NodeId::new();
+            let event = GraphDomainEvent::NodeAdded(NodeAdded {
+                graph_id: if i % 5 == 0 { other_graph } else { graph_id },
+                node_id,
+                position: Position3D::new(
+                    rng.gen_range(-500.0..500.0),
+                    rng.gen_range(-500.0..500.0),
+                    0.0,
+                ),
+                node_type: "TestType".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            });
+            projection.handle_graph_event(event).await.unwrap();
+            node_ids.push((i, node_id));
+        }
+
+        for (i, node_id) in node_ids.iter().step_by(3) {
+            let event = GraphDomainEvent::NodeRemoved(NodeRemoved {
+                graph_id: if i % 5 == 0 { other_graph } else { graph_id },
+                node_id: *node_id,
+                removed_at: chrono::Utc::now(),
+            });
+            projection.handle_graph_event(event).await.unwrap();
+        }
+
+        for _ in 0..50 {
+            let center = Position2D::new(
+                rng.gen_range(-600.0..600.0),
+                rng.gen_range(-600.0..600.0),
+            );
+            let radius = rng.gen_range(0.0..250.0);
+
+            let mut indexed: Vec<NodeId> = projection
+                .find_nodes_near(&graph_id, center, radius, DistanceMetric::Euclidean)
+                .iter()
+                .map(|node| node.node_id)
+                .collect();
+            let mut brute_force: Vec<NodeId> = projection
+                .get_nodes_by_graph(&graph_id)
+                .iter()
+                .filter(|node| {
+                    let pos = node.position_2d.unwrap();
+                    ((pos.x - center.x).powi(2) + (pos.y - center.y).powi(2)).sqrt() <= radius
+                })
+                .map(|node| node.node_id)
+                .collect();
+
+            indexed.sort_by_key(|id| id.to_string());
+            brute_force.sort_by_key(|id| id.to_string());
+            assert_eq!(indexed, brute_force);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_shards() {
+        let graph_a = GraphId::new();
+        let graph_b = GraphId::new();
+        let shared = NodeId::new();
+        let earlier = chrono::Utc::now();
+        let later = earlier + chrono::Duration::seconds(1);
+
+        let add = |graph_id, node_id, node_type: &str, created_at| {
+            GraphDomainEvent::NodeAdded(NodeAdded {
+                graph_id,
+                node_id,
+                position: crate::value_objects::Position3D::default(),
+                node_type: node_type.to_string(),
+                metadata: HashMap::new(),
+                created_at,
+            })
+        };
+
+        let mut first = NodeListProjection::new();
+        let mut second = NodeListProjection::new();
+        for event in [
+            add(graph_a, NodeId::new(), "task", earlier),
+            add(graph_a, shared, "task", earlier),
+            add(graph_b, NodeId::new(), "task", earlier),
+        ] {
+            first.handle_graph_event(event).await.unwrap();
+        }
+        for event in [
+            add(graph_a, NodeId::new(), "task", earlier),
+            add(graph_a, shared, "decision", later),
+            add(graph_b, NodeId::new(), "decision", earlier),
+        ] {
+            second.handle_graph_event(event).await.unwrap();
+        }
+
+        first.merge(second);
+
+        assert_eq!(first.total_nodes(), 5);
+        assert_eq!(first.get_node_count_for_graph(&graph_a), 3);
+        assert_eq!(first.get_node_count_for_graph(&graph_b), 2);
+        // The later copy of the shared node wins and moves to its type bucket
+        assert_eq!(first.get_node(&shared).unwrap().node_type, "decision");
+        assert_eq!(first.get_nodes_by_type("task").len(), 3);
+        assert_eq!(first.get_nodes_by_type("decision").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_relabel_moves_node_between_type_buckets() {
+        let mut projection = NodeListProjection::new();
+        let graph_id = GraphId::new();
+        let node_id = NodeId::new();
+
+        projection
+            .handle_graph_event(GraphDomainEvent::NodeAdded(NodeAdded {
+                graph_id,
+                node_id,
+                position: Position3D::default(),
+                node_type: "task".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            }))
+            .await
+            .unwrap();
+
+        projection
+            .handle_graph_event(GraphDomainEvent::NodeUpdated(NodeUpdated {
+                graph_id,
+                node_id,
+                position: None,
+                metadata: HashMap::new(),
+                node_type: Some("decision".to_string()),
+                removed_keys: Vec::new(),
+                updated_at: chrono::Utc::now(),
+            }))
+            .await
+            .unwrap();
+
+        assert!(projection.get_nodes_by_type("task").is_empty());
+        let relabeled = projection.get_nodes_by_type("decision");
+        assert_eq!(relabeled.len(), 1);
+        assert_eq!(relabeled[0].node_id, node_id);
+        assert_eq!(projection.total_nodes(), 1);
+    }
 }
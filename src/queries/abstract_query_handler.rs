@@ -66,6 +66,8 @@ impl AbstractGraphQueryHandler {
                     node_data.position.z,
                 )),
                 metadata: node_data.metadata,
+                created_at: chrono::Utc::now(), // Would come from event store
+                last_modified: chrono::Utc::now(),
             })
             .collect();
 
@@ -0,0 +1,92 @@
+//! Settings shared by the weighted graph algorithms
+
+use super::{GraphQueryError, GraphQueryResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Where weighted algorithms read edge weights from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlgorithmConfig {
+    /// Edge metadata key holding the weight
+    pub weight_key: String,
+    /// Weight of edges without a numeric value under `weight_key`
+    pub default_weight: f64,
+}
+
+impl Default for AlgorithmConfig {
+    fn default() -> Self {
+        Self::new("weight", 1.0)
+    }
+}
+
+impl AlgorithmConfig {
+    /// Read weights from `weight_key`, falling back to `default_weight`
+    pub fn new(weight_key: impl Into<String>, default_weight: f64) -> Self {
+        Self {
+            weight_key: weight_key.into(),
+            default_weight,
+        }
+    }
+
+    /// Read flow capacities from `capacity`, as `max_flow` and `min_cut` do
+    pub fn capacity() -> Self {
+        Self::new("capacity", 1.0)
+    }
+
+    /// Weight of an edge with the given metadata
+    pub fn weight_of(&self, metadata: &HashMap<String, serde_json::Value>) -> f64 {
+        metadata
+            .get(&self.weight_key)
+            .and_then(|w| w.as_f64())
+            .unwrap_or(self.default_weight)
+    }
+}
+
+/// Bounds that stop heavy algorithms before they run away on pathological graphs
+///
+/// A tripped limit fails the query with `InvalidQuery("limit exceeded: ...")`. Every limit
+/// defaults to `usize::MAX`, so nothing is bounded unless configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorithmLimits {
+    /// Most nodes a graph may have for a heavy algorithm to run on it
+    pub max_nodes: usize,
+    /// Most search steps or refinement rounds an algorithm may take
+    pub max_iterations: usize,
+    /// Most paths a path enumeration may produce
+    pub max_paths: usize,
+}
+
+impl Default for AlgorithmLimits {
+    fn default() -> Self {
+        Self {
+            max_nodes: usize::MAX,
+            max_iterations: usize::MAX,
+            max_paths: usize::MAX,
+        }
+    }
+}
+
+impl AlgorithmLimits {
+    /// Fail if `count` nodes is above `max_nodes`
+    pub fn check_nodes(&self, count: usize) -> GraphQueryResult<()> {
+        Self::check("max_nodes", count, self.max_nodes)
+    }
+
+    /// Fail if `count` iterations is above `max_iterations`
+    pub fn check_iterations(&self, count: usize) -> GraphQueryResult<()> {
+        Self::check("max_iterations", count, self.max_iterations)
+    }
+
+    /// Fail if `count` paths is above `max_paths`
+    pub fn check_paths(&self, count: usize) -> GraphQueryResult<()> {
+        Self::check("max_paths", count, self.max_paths)
+    }
+
+    fn check(name: &str, count: usize, limit: usize) -> GraphQueryResult<()> {
+        if count > limit {
+            Err(GraphQueryError::InvalidQuery(format!("limit exceeded: {name} is {limit}")))
+        } else {
+            Ok(())
+        }
+    }
+}
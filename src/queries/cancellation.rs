@@ -0,0 +1,30 @@
+//! Cancellation of long-running queries
+//!
+//! A client keeps a clone of the token passed to a query and cancels it when the result is no
+//! longer wanted; the query checks the token as it works and stops early.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared flag that asks a running query to stop
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that is not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel every query holding a clone of this token
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
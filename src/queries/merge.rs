@@ -0,0 +1,160 @@
+//! Three-way conflict detection between concurrently edited graph structures
+
+use super::{EdgeInfo, GraphStructure, NodeInfo};
+use crate::{EdgeId, NodeId};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// How both branches changed the same node or edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflictKind {
+    /// Both branches added it, with different contents
+    BothAdded,
+    /// Both branches modified it, to different contents
+    BothModified,
+    /// One branch modified it and the other removed it
+    ModifiedAndRemoved,
+}
+
+/// A node or edge changed divergently in two branches of a graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Conflict {
+    /// A conflicting node
+    Node { node_id: NodeId, kind: ConflictKind },
+    /// A conflicting edge
+    Edge { edge_id: EdgeId, kind: ConflictKind },
+}
+
+/// Nodes and edges changed differently in branches `a` and `b` of a common `base`
+///
+/// A change only one branch made, or the same change made by both, merges cleanly and is not
+/// reported. Nodes are compared by type, positions and metadata; edges by endpoints, type and
+/// metadata. Creation timestamps are ignored. Node conflicts come first, each list ordered by
+/// id.
+pub fn detect_conflicts(
+    base: &GraphStructure,
+    a: &GraphStructure,
+    b: &GraphStructure,
+) -> Vec<Conflict> {
+    let nodes = diverging(&node_contents(base), &node_contents(a), &node_contents(b));
+    let edges = diverging(&edge_contents(base), &edge_contents(a), &edge_contents(b));
+    nodes
+        .into_iter()
+        .map(|(node_id, kind)| Conflict::Node { node_id, kind })
+        .chain(edges.into_iter().map(|(edge_id, kind)| Conflict::Edge { edge_id, kind }))
+        .collect()
+}
+
+#[derive(PartialEq)]
+struct NodeContents<'a> {
+    node_type: &'a str,
+    position_2d: Option<crate::value_objects::Position2D>,
+    position_3d: Option<crate::value_objects::Position3D>,
+    metadata: &'a HashMap<String, serde_json::Value>,
+}
+
+impl<'a> NodeContents<'a> {
+    fn of(node: &'a NodeInfo) -> Self {
+        Self {
+            node_type: &node.node_type,
+            position_2d: node.position_2d,
+            position_3d: node.position_3d,
+            metadata: &node.metadata,
+        }
+    }
+}
+
+#[derive(PartialEq)]
+struct EdgeContents<'a> {
+    source_id: NodeId,
+    target_id: NodeId,
+    edge_type: &'a str,
+    metadata: &'a HashMap<String, serde_json::Value>,
+}
+
+impl<'a> EdgeContents<'a> {
+    fn of(edge: &'a EdgeInfo) -> Self {
+        Self {
+            source_id: edge.source_id,
+            target_id: edge.target_id,
+            edge_type: &edge.edge_type,
+            metadata: &edge.metadata,
+        }
+    }
+}
+
+fn node_contents(structure: &GraphStructure) -> HashMap<NodeId, NodeContents<'_>> {
+    structure.nodes.iter().map(|node| (node.node_id, NodeContents::of(node))).collect()
+}
+
+fn edge_contents(structure: &GraphStructure) -> HashMap<EdgeId, EdgeContents<'_>> {
+    structure.edges.iter().map(|edge| (edge.edge_id, EdgeContents::of(edge))).collect()
+}
+
+/// Ids both branches changed to different states, with how they diverged
+fn diverging<Id: Copy + Eq + Hash + Display, T: PartialEq>(
+    base: &HashMap<Id, T>,
+    a: &HashMap<Id, T>,
+    b: &HashMap<Id, T>,
+) -> Vec<(Id, ConflictKind)> {
+    let ids: HashSet<Id> = base.keys().chain(a.keys()).chain(b.keys()).copied().collect();
+    let mut ids: Vec<Id> = ids.into_iter().collect();
+    ids.sort_by_key(|id| id.to_string());
+    ids.into_iter()
+        .filter_map(|id| {
+            let (base, a, b) = (base.get(&id), a.get(&id), b.get(&id));
+            if a == base || b == base || a == b {
+                return None;
+            }
+            let kind = match (base, a, b) {
+                (None, _, _) => ConflictKind::BothAdded,
+                (Some(_), Some(_), Some(_)) => ConflictKind::BothModified,
+                _ => ConflictKind::ModifiedAndRemoved,
+            };
+            Some((id, kind))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GraphId;
+
+    fn structure(nodes: Vec<NodeInfo>) -> GraphStructure {
+        GraphStructure {
+            nodes,
+            edges: Vec::new(),
+            adjacency_list: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_detect_conflicts_reports_divergent_node_metadata() {
+        let graph_id = GraphId::new();
+        let [x, y] = [(); 2].map(|_| NodeId::new());
+        let node = |id: NodeId, owner: &str| {
+            NodeInfo::new(id, graph_id, "task")
+                .with_metadata(HashMap::from([("owner".to_string(), serde_json::json!(owner))]))
+        };
+        let base = structure(vec![node(x, "ops"), node(y, "ops")]);
+
+        // Both branches change x, differently
+        let a = structure(vec![node(x, "dev"), node(y, "ops")]);
+        let b = structure(vec![node(x, "qa"), node(y, "ops")]);
+        assert_eq!(
+            detect_conflicts(&base, &a, &b),
+            vec![Conflict::Node {
+                node_id: x,
+                kind: ConflictKind::BothModified
+            }]
+        );
+
+        // Each branch changes a different node
+        let a = structure(vec![node(x, "dev"), node(y, "ops")]);
+        let b = structure(vec![node(x, "ops"), node(y, "qa")]);
+        assert!(detect_conflicts(&base, &a, &b).is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+//! Mermaid export of runtime graph structures
+
+use super::GraphStructure;
+use crate::NodeId;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// Link colors, assigned to edge types in alphabetical order
+const EDGE_COLORS: [&str; 6] = ["#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b"];
+
+/// Render a graph structure as a Mermaid `graph TD` diagram
+///
+/// Nodes are labeled by their `label` or `name` metadata, falling back to the node type.
+/// Edges are labeled with their type, and all edges of one type share a color. Edges whose
+/// endpoints are not in the structure are left out.
+pub fn export_mermaid(structure: &GraphStructure) -> String {
+    let mut output = String::new();
+    writeln!(&mut output, "graph TD").unwrap();
+
+    let mut ids: HashMap<NodeId, String> = HashMap::new();
+    for (index, node) in structure.nodes.iter().enumerate() {
+        let id = format!("n{index}");
+        let label = ["label", "name"]
+            .iter()
+            .find_map(|key| node.metadata.get(*key).and_then(|value| value.as_str()))
+            .unwrap_or(&node.node_type);
+        writeln!(&mut output, "    {id}[\"{}\"]", escape(label)).unwrap();
+        ids.insert(node.node_id, id);
+    }
+
+    // Mermaid numbers links in declaration order
+    let mut links_by_type: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    let mut link_count = 0;
+    for edge in &structure.edges {
+        let (Some(source), Some(target)) = (ids.get(&edge.source_id), ids.get(&edge.target_id))
+        else {
+            continue;
+        };
+        let edge_type = escape(&edge.edge_type);
+        writeln!(&mut output, "    {source} -->|\"{edge_type}\"| {target}").unwrap();
+        links_by_type.entry(&edge.edge_type).or_default().push(link_count);
+        link_count += 1;
+    }
+
+    for (index, links) in links_by_type.values().enumerate() {
+        let links: Vec<String> = links.iter().map(usize::to_string).collect();
+        let color = EDGE_COLORS[index % EDGE_COLORS.len()];
+        writeln!(&mut output, "    linkStyle {} stroke:{color}", links.join(",")).unwrap();
+    }
+
+    output
+}
+
+/// Escape text for a quoted Mermaid label
+fn escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::{EdgeInfo, NodeInfo};
+    use crate::{EdgeId, GraphId};
+
+    #[test]
+    fn test_export_mermaid_counts_nodes_and_edges() {
+        let graph_id = GraphId::new();
+        let [a, b, c] = [(); 3].map(|_| NodeId::new());
+        let nodes = vec![
+            NodeInfo::new(a, graph_id, "step").with_metadata(HashMap::from([(
+                "label".to_string(),
+                serde_json::json!("Say \"hi\""),
+            )])),
+            NodeInfo::new(b, graph_id, "step"),
+            NodeInfo::new(c, graph_id, "step"),
+        ];
+        // a -> b -> c -> a is a cycle, plus an edge to a node outside the structure
+        let edges = vec![
+            EdgeInfo::new(EdgeId::new(), graph_id, a, b, "next"),
+            EdgeInfo::new(EdgeId::new(), graph_id, b, c, "next"),
+            EdgeInfo::new(EdgeId::new(), graph_id, c, a, "retry"),
+            EdgeInfo::new(EdgeId::new(), graph_id, c, NodeId::new(), "next"),
+        ];
+        let structure = GraphStructure {
+            nodes,
+            edges,
+            adjacency_list: HashMap::new(),
+        };
+
+        let mermaid = export_mermaid(&structure);
+        let lines: Vec<&str> = mermaid.lines().collect();
+        assert_eq!(lines[0], "graph TD");
+        assert_eq!(lines.iter().filter(|line| line.contains("[\"")).count(), 3);
+        assert_eq!(lines.iter().filter(|line| line.contains("-->")).count(), 3);
+        assert!(mermaid.contains("n0[\"Say #quot;hi#quot;\"]"));
+        assert!(mermaid.contains("linkStyle 0,1 stroke:#1f77b4"));
+        assert!(mermaid.contains("linkStyle 2 stroke:#ff7f0e"));
+    }
+}
@@ -0,0 +1,223 @@
+//! Graph metric computation
+//!
+//! Metrics are computed from plain node and edge lists so the serial and parallel
+//! paths share one definition. With the `parallel` feature the independent parts
+//! (edge indexing, component labeling and cycle detection) run on the rayon pool.
+
+use super::GraphMetrics;
+use crate::NodeId;
+use std::collections::{HashMap, VecDeque};
+
+/// Compute metrics for a directed graph, in parallel when the `parallel` feature is enabled
+pub(crate) fn compute_metrics(node_ids: &[NodeId], edges: &[(NodeId, NodeId)]) -> GraphMetrics {
+    #[cfg(feature = "parallel")]
+    {
+        compute_metrics_parallel(node_ids, edges)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        compute_metrics_serial(node_ids, edges)
+    }
+}
+
+/// Compute metrics on the current thread
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+pub(crate) fn compute_metrics_serial(
+    node_ids: &[NodeId],
+    edges: &[(NodeId, NodeId)],
+) -> GraphMetrics {
+    let index = index_nodes(node_ids);
+    let pairs: Vec<(usize, usize)> = edges
+        .iter()
+        .filter_map(|edge| index_edge(&index, edge))
+        .collect();
+
+    let connected_components = count_components(node_ids.len(), &pairs);
+    let has_cycles = has_cycle(node_ids.len(), &pairs);
+
+    build_metrics(node_ids.len(), edges.len(), connected_components, has_cycles)
+}
+
+/// Compute metrics using the rayon thread pool
+#[cfg(feature = "parallel")]
+pub(crate) fn compute_metrics_parallel(
+    node_ids: &[NodeId],
+    edges: &[(NodeId, NodeId)],
+) -> GraphMetrics {
+    use rayon::prelude::*;
+
+    let index = index_nodes(node_ids);
+    let pairs: Vec<(usize, usize)> = edges
+        .par_iter()
+        .filter_map(|edge| index_edge(&index, edge))
+        .collect();
+
+    let (connected_components, has_cycles) = rayon::join(
+        || count_components(node_ids.len(), &pairs),
+        || has_cycle(node_ids.len(), &pairs),
+    );
+
+    build_metrics(node_ids.len(), edges.len(), connected_components, has_cycles)
+}
+
+/// Density of a directed graph: edges / (nodes * (nodes - 1))
+pub(crate) fn density(node_count: usize, edge_count: usize) -> f64 {
+    if node_count > 1 {
+        edge_count as f64 / (node_count as f64 * (node_count - 1) as f64)
+    } else {
+        0.0
+    }
+}
+
+fn build_metrics(
+    node_count: usize,
+    edge_count: usize,
+    connected_components: usize,
+    has_cycles: bool,
+) -> GraphMetrics {
+    let density = density(node_count, edge_count);
+
+    // Average out-degree
+    let average_degree = if node_count > 0 {
+        edge_count as f64 / node_count as f64
+    } else {
+        0.0
+    };
+
+    GraphMetrics {
+        node_count,
+        edge_count,
+        density,
+        average_degree,
+        connected_components,
+        has_cycles,
+        node_type_counts: HashMap::new(),
+        edge_type_counts: HashMap::new(),
+    }
+}
+
+fn index_nodes(node_ids: &[NodeId]) -> HashMap<NodeId, usize> {
+    node_ids
+        .iter()
+        .enumerate()
+        .map(|(i, node_id)| (*node_id, i))
+        .collect()
+}
+
+/// Map an edge onto node indices, skipping edges whose endpoints are not in the graph
+fn index_edge(
+    index: &HashMap<NodeId, usize>,
+    (source, target): &(NodeId, NodeId),
+) -> Option<(usize, usize)> {
+    Some((*index.get(source)?, *index.get(target)?))
+}
+
+/// Sizes of the weakly connected components, largest first
+pub(crate) fn component_sizes(node_ids: &[NodeId], edges: &[(NodeId, NodeId)]) -> Vec<usize> {
+    let index = index_nodes(node_ids);
+    let mut parent: Vec<usize> = (0..node_ids.len()).collect();
+    for (source, target) in edges.iter().filter_map(|edge| index_edge(&index, edge)) {
+        let a = find(&mut parent, source);
+        let b = find(&mut parent, target);
+        if a != b {
+            parent[a] = b;
+        }
+    }
+
+    let mut sizes: HashMap<usize, usize> = HashMap::new();
+    for node in 0..node_ids.len() {
+        *sizes.entry(find(&mut parent, node)).or_default() += 1;
+    }
+    let mut sizes: Vec<usize> = sizes.into_values().collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes
+}
+
+/// Union-find root of `node`, halving the path on the way
+pub(crate) fn find(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+/// Count weakly connected components with union-find
+fn count_components(node_count: usize, pairs: &[(usize, usize)]) -> usize {
+    let mut parent: Vec<usize> = (0..node_count).collect();
+    let mut components = node_count;
+    for &(source, target) in pairs {
+        let a = find(&mut parent, source);
+        let b = find(&mut parent, target);
+        if a != b {
+            parent[a] = b;
+            components -= 1;
+        }
+    }
+    components
+}
+
+/// Detect directed cycles with Kahn's algorithm
+fn has_cycle(node_count: usize, pairs: &[(usize, usize)]) -> bool {
+    let mut in_degree = vec![0usize; node_count];
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+    for &(source, target) in pairs {
+        in_degree[target] += 1;
+        successors[source].push(target);
+    }
+
+    let mut queue: VecDeque<usize> = (0..node_count).filter(|&n| in_degree[n] == 0).collect();
+    let mut removed = 0;
+    while let Some(node) = queue.pop_front() {
+        removed += 1;
+        for &next in &successors[node] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    removed < node_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serial_metrics() {
+        let nodes: Vec<NodeId> = (0..4).map(|_| NodeId::new()).collect();
+        // 0 -> 1 -> 2 -> 0 forms a cycle, 3 is isolated
+        let edges = vec![(nodes[0], nodes[1]), (nodes[1], nodes[2]), (nodes[2], nodes[0])];
+
+        let metrics = compute_metrics_serial(&nodes, &edges);
+        assert_eq!(metrics.node_count, 4);
+        assert_eq!(metrics.edge_count, 3);
+        assert_eq!(metrics.connected_components, 2);
+        assert!(metrics.has_cycles);
+        assert!((metrics.density - 0.25).abs() < f64::EPSILON);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial_on_large_graph() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let nodes: Vec<NodeId> = (0..10_000).map(|_| NodeId::new()).collect();
+        let edges: Vec<(NodeId, NodeId)> = (0..30_000)
+            .map(|_| {
+                (
+                    nodes[rng.gen_range(0..nodes.len())],
+                    nodes[rng.gen_range(0..nodes.len())],
+                )
+            })
+            .collect();
+
+        let serial = compute_metrics_serial(&nodes, &edges);
+        let parallel = compute_metrics_parallel(&nodes, &edges);
+        assert_eq!(serial, parallel);
+    }
+}
@@ -0,0 +1,98 @@
+//! Conversion between graph structures and `petgraph` graphs
+//!
+//! Node and edge infos travel as the petgraph weights, so ids, types and metadata survive a
+//! round trip and petgraph's algorithms can be run on query results.
+
+use super::{EdgeInfo, GraphStructure, NodeInfo};
+use crate::NodeId;
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use std::collections::HashMap;
+
+/// Build a directed petgraph graph from a graph structure
+///
+/// Edges whose endpoints are not in the structure are left out.
+pub fn to_petgraph(structure: &GraphStructure) -> Graph<NodeInfo, EdgeInfo> {
+    let mut graph = Graph::with_capacity(structure.nodes.len(), structure.edges.len());
+    let mut indices: HashMap<NodeId, NodeIndex> = HashMap::new();
+    for node in &structure.nodes {
+        indices.insert(node.node_id, graph.add_node(node.clone()));
+    }
+    for edge in &structure.edges {
+        if let (Some(&source), Some(&target)) =
+            (indices.get(&edge.source_id), indices.get(&edge.target_id))
+        {
+            graph.add_edge(source, target, edge.clone());
+        }
+    }
+    graph
+}
+
+/// Build a graph structure from a petgraph graph
+///
+/// Edge endpoints are taken from the petgraph topology, so edges rewired in petgraph keep
+/// their new endpoints. The adjacency list is rebuilt from the edges.
+pub fn from_petgraph(graph: &Graph<NodeInfo, EdgeInfo>) -> GraphStructure {
+    let nodes: Vec<NodeInfo> = graph.node_weights().cloned().collect();
+    let mut adjacency_list: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+    let edges: Vec<EdgeInfo> = graph
+        .edge_references()
+        .map(|edge| {
+            let mut info = edge.weight().clone();
+            info.source_id = graph[edge.source()].node_id;
+            info.target_id = graph[edge.target()].node_id;
+            adjacency_list
+                .entry(info.source_id)
+                .or_default()
+                .push(info.target_id);
+            info
+        })
+        .collect();
+
+    GraphStructure {
+        nodes,
+        edges,
+        adjacency_list,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EdgeId, GraphId};
+
+    #[test]
+    fn test_petgraph_round_trip_preserves_counts() {
+        let graph_id = GraphId::new();
+        let [a, b, c] = [(); 3].map(|_| NodeId::new());
+        let structure = GraphStructure {
+            nodes: [a, b, c]
+                .iter()
+                .map(|&node_id| NodeInfo::new(node_id, graph_id, "item"))
+                .collect(),
+            edges: vec![
+                EdgeInfo::new(EdgeId::new(), graph_id, a, b, "link"),
+                EdgeInfo::new(EdgeId::new(), graph_id, b, c, "link"),
+                EdgeInfo::new(EdgeId::new(), graph_id, c, a, "link"),
+                EdgeInfo::new(EdgeId::new(), graph_id, a, a, "self"),
+            ],
+            adjacency_list: HashMap::new(),
+        };
+
+        let graph = to_petgraph(&structure);
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 4);
+
+        let back = from_petgraph(&graph);
+        assert_eq!(back.nodes.len(), structure.nodes.len());
+        assert_eq!(back.edges.len(), structure.edges.len());
+        for (original, restored) in structure.edges.iter().zip(&back.edges) {
+            assert_eq!(original.edge_id, restored.edge_id);
+            assert_eq!(
+                (original.source_id, original.target_id),
+                (restored.source_id, restored.target_id)
+            );
+        }
+        assert_eq!(back.adjacency_list[&a], vec![b, a]);
+    }
+}
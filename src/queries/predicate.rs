@@ -0,0 +1,238 @@
+//! Metadata predicates
+//!
+//! A predicate is a list of key/operator/value conditions that must all hold for a node's
+//! metadata to match. Single conditions can also be evaluated against the fields of a JSON
+//! object with [`evaluate_condition`].
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// Comparison applied between a metadata value and a condition value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConditionOperator {
+    /// Values are equal
+    Equals,
+    /// Values differ, or the key is missing
+    NotEquals,
+    /// Metadata value is greater
+    GreaterThan,
+    /// Metadata value is greater or equal
+    GreaterThanOrEqual,
+    /// Metadata value is smaller
+    LessThan,
+    /// Metadata value is smaller or equal
+    LessThanOrEqual,
+    /// Metadata string contains the value as a substring, or metadata array contains it
+    Contains,
+    /// The key is present; the condition value is ignored
+    Exists,
+    /// Metadata value is one of the elements of the condition's array value
+    In,
+    /// Metadata string matches the condition value as a regular expression
+    Matches,
+}
+
+/// A single condition on one metadata key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataCondition {
+    pub key: String,
+    pub operator: ConditionOperator,
+    pub value: serde_json::Value,
+}
+
+impl MetadataCondition {
+    /// Whether `metadata` satisfies this condition
+    ///
+    /// Ordering operators compare two numbers or two strings; any other pair of values,
+    /// like a missing key, does not match.
+    pub fn matches(&self, metadata: &HashMap<String, serde_json::Value>) -> bool {
+        self.evaluate(metadata.get(&self.key))
+    }
+
+    /// Whether the value found under the condition's key satisfies it
+    fn evaluate(&self, actual: Option<&serde_json::Value>) -> bool {
+        match self.operator {
+            ConditionOperator::Exists => actual.is_some(),
+            ConditionOperator::Equals => actual == Some(&self.value),
+            ConditionOperator::NotEquals => actual != Some(&self.value),
+            ConditionOperator::Contains => match actual {
+                Some(serde_json::Value::String(text)) => {
+                    self.value.as_str().is_some_and(|part| text.contains(part))
+                }
+                Some(serde_json::Value::Array(items)) => items.contains(&self.value),
+                _ => false,
+            },
+            ConditionOperator::GreaterThan => self.ordering(actual) == Some(Ordering::Greater),
+            ConditionOperator::GreaterThanOrEqual => matches!(
+                self.ordering(actual),
+                Some(Ordering::Greater | Ordering::Equal)
+            ),
+            ConditionOperator::LessThan => self.ordering(actual) == Some(Ordering::Less),
+            ConditionOperator::LessThanOrEqual => {
+                matches!(self.ordering(actual), Some(Ordering::Less | Ordering::Equal))
+            }
+            ConditionOperator::In => match (actual, &self.value) {
+                (Some(actual), serde_json::Value::Array(items)) => items.contains(actual),
+                _ => false,
+            },
+            ConditionOperator::Matches => match (actual, &self.value) {
+                (Some(serde_json::Value::String(text)), serde_json::Value::String(pattern)) => {
+                    compiled(pattern).is_some_and(|regex| regex.is_match(text))
+                }
+                _ => false,
+            },
+        }
+    }
+
+    /// Order of the metadata value relative to the condition value, if they are comparable
+    fn ordering(&self, actual: Option<&serde_json::Value>) -> Option<Ordering> {
+        match (actual?, &self.value) {
+            (serde_json::Value::Number(a), serde_json::Value::Number(b)) => {
+                a.as_f64()?.partial_cmp(&b.as_f64()?)
+            }
+            (serde_json::Value::String(a), serde_json::Value::String(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+/// Most `Matches` patterns kept compiled at once
+const MAX_CACHED_PATTERNS: usize = 256;
+
+/// Compiled form of a `Matches` pattern, or `None` if it is not a valid regular expression
+///
+/// Patterns are compiled once and shared, since conditions are evaluated against every node
+/// or object in turn. Once [`MAX_CACHED_PATTERNS`] are cached, new patterns are compiled on
+/// every use instead, so callers passing arbitrary patterns can't grow the cache unbounded.
+fn compiled(pattern: &str) -> Option<Regex> {
+    static PATTERNS: OnceLock<Mutex<HashMap<String, Option<Regex>>>> = OnceLock::new();
+
+    // The map is only ever inserted into, so a poisoned lock still holds valid entries
+    let mut patterns = PATTERNS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(regex) = patterns.get(pattern) {
+        return regex.clone();
+    }
+    let regex = Regex::new(pattern).ok();
+    if patterns.len() < MAX_CACHED_PATTERNS {
+        patterns.insert(pattern.to_string(), regex.clone());
+    }
+    regex
+}
+
+/// Whether a field of a JSON object satisfies `cond`
+///
+/// The condition's key names a top-level field of `object`; values that are not objects
+/// have no fields. Values of the wrong type, and patterns that are not valid regular
+/// expressions, do not match.
+pub fn evaluate_condition(cond: &MetadataCondition, object: &serde_json::Value) -> bool {
+    cond.evaluate(object.get(&cond.key))
+}
+
+/// Conditions that must all hold for metadata to match
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetadataPredicate {
+    pub conditions: Vec<MetadataCondition>,
+}
+
+impl MetadataPredicate {
+    /// Predicate with a single condition
+    pub fn new(
+        key: impl Into<String>,
+        operator: ConditionOperator,
+        value: serde_json::Value,
+    ) -> Self {
+        Self::default().and(key, operator, value)
+    }
+
+    /// Add a condition that must also hold
+    pub fn and(
+        mut self,
+        key: impl Into<String>,
+        operator: ConditionOperator,
+        value: serde_json::Value,
+    ) -> Self {
+        self.conditions.push(MetadataCondition {
+            key: key.into(),
+            operator,
+            value,
+        });
+        self
+    }
+
+    /// Whether `metadata` satisfies every condition; an empty predicate matches everything
+    pub fn matches(&self, metadata: &HashMap<String, serde_json::Value>) -> bool {
+        self.conditions.iter().all(|condition| condition.matches(metadata))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn holds(key: &str, operator: ConditionOperator, value: serde_json::Value) -> bool {
+        let object = json!({
+            "name": "order-42",
+            "status": "open",
+            "total": 120,
+            "tags": ["urgent", "retail"],
+        });
+        let cond = MetadataCondition {
+            key: key.to_string(),
+            operator,
+            value,
+        };
+        evaluate_condition(&cond, &object)
+    }
+
+    #[test]
+    fn test_evaluate_condition_operators() {
+        use ConditionOperator::*;
+
+        // Each operator with a matching and a non-matching condition value
+        let cases = [
+            (Equals, "status", json!("open"), json!("closed")),
+            (NotEquals, "status", json!("closed"), json!("open")),
+            (GreaterThan, "total", json!(100), json!(120)),
+            (GreaterThanOrEqual, "total", json!(120), json!(121)),
+            (LessThan, "total", json!(200), json!(120)),
+            (LessThanOrEqual, "total", json!(120), json!(119)),
+            (Contains, "name", json!("der-4"), json!("invoice")),
+            (Contains, "tags", json!("urgent"), json!("wholesale")),
+            (In, "status", json!(["open", "pending"]), json!(["closed"])),
+            (Matches, "name", json!("^order-\\d+$"), json!("^invoice-")),
+        ];
+        for (operator, key, matching, non_matching) in cases {
+            assert!(holds(key, operator, matching), "{operator:?} on {key}");
+            assert!(!holds(key, operator, non_matching), "{operator:?} on {key}");
+        }
+
+        assert!(holds("total", Exists, json!(null)));
+        assert!(!holds("owner", Exists, json!(null)));
+    }
+
+    #[test]
+    fn test_evaluate_condition_type_mismatch_is_false() {
+        use ConditionOperator::*;
+
+        assert!(!holds("name", GreaterThan, json!(1)));
+        assert!(!holds("total", Contains, json!("1")));
+        assert!(!holds("total", Matches, json!("1.*")));
+        assert!(!holds("status", In, json!("open")));
+        assert!(!holds("name", Matches, json!("(")));
+
+        // Only objects have fields
+        let cond = MetadataCondition {
+            key: "total".to_string(),
+            operator: Equals,
+            value: json!(120),
+        };
+        assert!(!evaluate_condition(&cond, &json!([120])));
+    }
+}
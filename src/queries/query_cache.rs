@@ -0,0 +1,133 @@
+//! Per-graph cache of derived query data
+//!
+//! The query handler's projections don't change once it is built, so cached entries never go
+//! stale; the cache only bounds how many graphs are kept, evicting the oldest first.
+
+use super::GraphMetrics;
+use crate::{GraphId, NodeId};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Hit and miss counts of a query cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that had to compute their result
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Fraction of lookups answered from the cache, 0.0 before any lookup
+    pub fn hit_rate(&self) -> f32 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f32 / total as f32
+        }
+    }
+}
+
+#[derive(Default)]
+struct Entries {
+    metrics: HashMap<GraphId, GraphMetrics>,
+    adjacency: HashMap<GraphId, Arc<HashMap<NodeId, Vec<NodeId>>>>,
+    /// Cached graphs, oldest first
+    order: VecDeque<GraphId>,
+}
+
+impl Entries {
+    /// Make room for `graph_id`, evicting the oldest graphs beyond `capacity`
+    fn admit(&mut self, graph_id: GraphId, capacity: usize) {
+        if self.order.contains(&graph_id) {
+            return;
+        }
+        self.order.push_back(graph_id);
+        while self.order.len() > capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.metrics.remove(&evicted);
+                self.adjacency.remove(&evicted);
+            }
+        }
+    }
+}
+
+/// Cache of metrics and traversal adjacency for up to `capacity` graphs
+pub(crate) struct QueryCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl QueryCache {
+    /// Create a cache holding up to `capacity` graphs; 0 disables caching
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(Entries::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Cached metrics of a graph, counting the lookup as a hit or miss
+    pub(crate) fn metrics(&self, graph_id: &GraphId) -> Option<GraphMetrics> {
+        let cached = self.lock().metrics.get(graph_id).cloned();
+        self.record(cached.is_some());
+        cached
+    }
+
+    pub(crate) fn store_metrics(&self, graph_id: GraphId, metrics: GraphMetrics) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.lock();
+        entries.admit(graph_id, self.capacity);
+        entries.metrics.insert(graph_id, metrics);
+    }
+
+    /// Cached traversal adjacency of a graph, counting the lookup as a hit or miss
+    ///
+    /// Hits share the cached map rather than copying it.
+    pub(crate) fn adjacency(
+        &self,
+        graph_id: &GraphId,
+    ) -> Option<Arc<HashMap<NodeId, Vec<NodeId>>>> {
+        let cached = self.lock().adjacency.get(graph_id).cloned();
+        self.record(cached.is_some());
+        cached
+    }
+
+    pub(crate) fn store_adjacency(
+        &self,
+        graph_id: GraphId,
+        adjacency: Arc<HashMap<NodeId, Vec<NodeId>>>,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.lock();
+        entries.admit(graph_id, self.capacity);
+        entries.adjacency.insert(graph_id, adjacency);
+    }
+
+    fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        // Entries are plain maps, so a panic mid-update can't leave them inconsistent
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
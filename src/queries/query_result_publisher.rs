@@ -5,6 +5,7 @@
 
 use crate::queries::{GraphQueryHandler, GraphQueryError, GraphInfo, NodeInfo, EdgeInfo, 
     GraphStructure, GraphMetrics, PaginationParams, FilterParams, Position2D};
+use crate::value_objects::DistanceMetric;
 use crate::{GraphId, NodeId, EdgeId};
 use cim_domain::{QueryEnvelope, QueryHandler, QueryResponse, Query};
 use serde::{Deserialize, Serialize};
@@ -24,7 +25,13 @@ pub enum GraphQuery {
     GetNode { node_id: NodeId },
     GetNodesInGraph { graph_id: GraphId },
     GetNodesByType { graph_id: GraphId, node_type: String },
-    FindNodesNearPosition { graph_id: GraphId, center: Position2D, radius: f64 },
+    FindNodesNearPosition {
+        graph_id: GraphId,
+        center: Position2D,
+        radius: f64,
+        #[serde(default)]
+        metric: DistanceMetric,
+    },
     
     // Edge-level queries
     GetEdge { edge_id: EdgeId },
@@ -104,8 +111,8 @@ impl ResultPublishingQueryHandler {
                 self.inner.get_nodes_by_type(*graph_id, node_type).await
                     .map(|infos| serde_json::to_value(infos).unwrap())
             }
-            GraphQuery::FindNodesNearPosition { graph_id, center, radius } => {
-                self.inner.find_nodes_near_position(*graph_id, *center, *radius).await
+            GraphQuery::FindNodesNearPosition { graph_id, center, radius, metric } => {
+                self.inner.find_nodes_near_position_with_metric(*graph_id, *center, *radius, *metric).await
                     .map(|infos| serde_json::to_value(infos).unwrap())
             }
             GraphQuery::GetEdge { edge_id } => {
@@ -0,0 +1,98 @@
+//! Fuzzy text matching for graph search
+//!
+//! Candidate text is split into words and each word is compared to the query using
+//! an edit distance that counts adjacent transpositions as one edit, so common typos
+//! like "grpah" still match "graph".
+
+/// Relevance of a field kind, multiplied into the match score
+pub(crate) const NAME_WEIGHT: f64 = 1.0;
+pub(crate) const DESCRIPTION_WEIGHT: f64 = 0.8;
+pub(crate) const METADATA_WEIGHT: f64 = 0.6;
+
+/// Score `query` against weighted fields, returning `None` when nothing matches
+///
+/// Substring matches score the field weight; fuzzy word matches score
+/// `weight * (1 - distance / query_length)`. The best field wins.
+pub(crate) fn relevance(query: &str, fields: &[(&str, f64)]) -> Option<f64> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+
+    let query_chars: Vec<char> = query.chars().collect();
+    let max_distance = (query_chars.len() / 4).max(1);
+
+    fields
+        .iter()
+        .filter_map(|(text, weight)| {
+            let text = text.to_lowercase();
+            if text.contains(&query) {
+                return Some(*weight);
+            }
+
+            text.split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(|word| edit_distance(&query_chars, &word.chars().collect::<Vec<_>>()))
+                .filter(|distance| *distance <= max_distance)
+                .min()
+                .map(|distance| weight * (1.0 - distance as f64 / query_chars.len() as f64))
+        })
+        .fold(None, |best: Option<f64>, score| {
+            Some(best.map_or(score, |best| best.max(score)))
+        })
+}
+
+/// Levenshtein distance extended with adjacent transpositions (optimal string alignment)
+pub(crate) fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distance(a: &str, b: &str) -> usize {
+        edit_distance(
+            &a.chars().collect::<Vec<_>>(),
+            &b.chars().collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(distance("graph", "graph"), 0);
+        assert_eq!(distance("grpah", "graph"), 1);
+        assert_eq!(distance("grap", "graph"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_relevance_prefers_exact_matches() {
+        let exact = relevance("graph", &[("Knowledge graph", NAME_WEIGHT)]).unwrap();
+        let fuzzy = relevance("grpah", &[("Knowledge graph", NAME_WEIGHT)]).unwrap();
+        assert!(exact > fuzzy);
+        assert!(relevance("grpah", &[("Sales pipeline", NAME_WEIGHT)]).is_none());
+    }
+}
@@ -0,0 +1,213 @@
+//! Applying bridge events to ECS entities
+
+use bevy_ecs::prelude::*;
+use crate::{
+    bridge::BridgeEvent,
+    components::*,
+    events::*,
+    value_objects::Position3D,
+    EdgeId, NodeId,
+};
+
+/// Bridge events waiting to be applied, filled from `AsyncSyncBridge::receive_events`
+#[derive(Resource, Default)]
+pub struct PendingBridgeEvents(pub Vec<BridgeEvent>);
+
+/// System that drains pending bridge events and applies them to node and edge entities
+///
+/// Runs with exclusive world access so that an event can update an entity spawned by an
+/// earlier event in the same batch. Graph lifecycle and hyperedge events are left to their
+/// own systems.
+pub fn apply_bridge_events_system(world: &mut World) {
+    let Some(mut pending) = world.get_resource_mut::<PendingBridgeEvents>() else {
+        return;
+    };
+    let events = std::mem::take(&mut pending.0);
+
+    for event in events {
+        match event {
+            BridgeEvent::NodeAdded(event) => spawn_node(world, event),
+            BridgeEvent::NodeUpdated(event) => update_node(world, event),
+            BridgeEvent::NodeRemoved(event) => remove_node(world, event.node_id),
+            BridgeEvent::EdgeAdded(event) => spawn_edge(world, event),
+            BridgeEvent::EdgeUpdated(event) => update_edge(world, event),
+            BridgeEvent::EdgeRemoved(event) => {
+                if let Some(entity) = find_edge(world, event.edge_id) {
+                    world.despawn(entity);
+                }
+            }
+            BridgeEvent::GraphCreated(_)
+            | BridgeEvent::GraphUpdated(_)
+            | BridgeEvent::GraphArchived(_)
+            | BridgeEvent::HyperEdgeAdded(_) => {}
+        }
+    }
+}
+
+fn spawn_node(world: &mut World, event: NodeAdded) {
+    let now = std::time::SystemTime::now();
+    let tags = event
+        .metadata
+        .get("tags")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+        .unwrap_or_default();
+
+    world.spawn((
+        NodeEntity {
+            node_id: event.node_id,
+            graph_id: event.graph_id,
+        },
+        event.position,
+        NodeStatus::Active,
+        NodeMetadata {
+            tags,
+            properties: event.metadata,
+            created_at: now,
+            updated_at: now,
+        },
+    ));
+    tracing::debug!("Bridge spawned node: {:?}", event.node_id);
+}
+
+fn update_node(world: &mut World, event: NodeUpdated) {
+    let Some(entity) = find_node(world, event.node_id) else {
+        return;
+    };
+
+    if let Some(new_pos) = event.position {
+        if let Some(mut position) = world.get_mut::<Position3D>(entity) {
+            *position = new_pos;
+        }
+    }
+    if let Some(mut metadata) = world.get_mut::<NodeMetadata>(entity) {
+        event.apply_metadata(&mut metadata.properties);
+        metadata.updated_at = std::time::SystemTime::now();
+    }
+}
+
+fn remove_node(world: &mut World, node_id: NodeId) {
+    let Some(entity) = find_node(world, node_id) else {
+        return;
+    };
+
+    // Edges at the node go with it
+    let incident: Vec<Entity> = world
+        .query::<(Entity, &EdgeEntity)>()
+        .iter(world)
+        .filter(|(_, edge)| edge.source == node_id || edge.target == node_id)
+        .map(|(entity, _)| entity)
+        .collect();
+    for edge in incident {
+        world.despawn(edge);
+    }
+    world.despawn(entity);
+    tracing::debug!("Bridge despawned node: {:?}", node_id);
+}
+
+fn spawn_edge(world: &mut World, event: EdgeAdded) {
+    let now = std::time::SystemTime::now();
+    let weight = EdgeWeight(event.relationship.weight().unwrap_or(1.0));
+
+    world.spawn((
+        EdgeEntity {
+            edge_id: event.edge_id,
+            graph_id: event.graph_id,
+            source: event.source,
+            target: event.target,
+        },
+        event.relationship,
+        weight,
+        EdgeMetadata {
+            tags: vec![],
+            properties: event.metadata,
+            created_at: now,
+            updated_at: now,
+        },
+    ));
+    tracing::debug!("Bridge spawned edge: {:?}", event.edge_id);
+}
+
+fn update_edge(world: &mut World, event: EdgeUpdated) {
+    let Some(entity) = find_edge(world, event.edge_id) else {
+        return;
+    };
+
+    if let Some((source, target)) = event.endpoints {
+        if let Some(mut edge) = world.get_mut::<EdgeEntity>(entity) {
+            edge.source = source;
+            edge.target = target;
+        }
+    }
+    if let Some(relationship) = event.relationship {
+        world.entity_mut(entity).insert(relationship);
+    }
+    if let Some(mut metadata) = world.get_mut::<EdgeMetadata>(entity) {
+        metadata.properties.extend(event.metadata);
+        metadata.updated_at = std::time::SystemTime::now();
+    }
+}
+
+fn find_node(world: &mut World, node_id: NodeId) -> Option<Entity> {
+    world
+        .query::<(Entity, &NodeEntity)>()
+        .iter(world)
+        .find(|(_, node)| node.node_id == node_id)
+        .map(|(entity, _)| entity)
+}
+
+fn find_edge(world: &mut World, edge_id: EdgeId) -> Option<Entity> {
+    world
+        .query::<(Entity, &EdgeEntity)>()
+        .iter(world)
+        .find(|(_, edge)| edge.edge_id == edge_id)
+        .map(|(entity, _)| entity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_node_added_bridge_event_spawns_node() {
+        let mut world = World::new();
+        let graph_id = GraphId::new();
+        let node_id = NodeId::new();
+
+        world.insert_resource(PendingBridgeEvents(vec![
+            BridgeEvent::NodeAdded(NodeAdded {
+                graph_id,
+                node_id,
+                position: Position3D::new(1.0, 2.0, 3.0),
+                node_type: "process".to_string(),
+                metadata: HashMap::new(),
+                created_at: chrono::Utc::now(),
+            }),
+            // Applies to the node spawned by the event before it
+            BridgeEvent::NodeUpdated(NodeUpdated {
+                graph_id,
+                node_id,
+                position: Some(Position3D::new(4.0, 5.0, 6.0)),
+                metadata: HashMap::from([("title".to_string(), serde_json::json!("Start"))]),
+                node_type: None,
+                removed_keys: Vec::new(),
+                updated_at: chrono::Utc::now(),
+            }),
+        ]));
+
+        let mut system = IntoSystem::into_system(apply_bridge_events_system);
+        system.initialize(&mut world);
+        system.run((), &mut world);
+
+        let mut query = world.query::<(&NodeEntity, &Position3D, &NodeMetadata)>();
+        let results: Vec<_> = query.iter(&world).collect();
+        assert_eq!(results.len(), 1);
+        let (entity, position, metadata) = results[0];
+        assert_eq!(entity.node_id, node_id);
+        assert_eq!(entity.graph_id, graph_id);
+        assert_eq!(*position, Position3D::new(4.0, 5.0, 6.0));
+        assert_eq!(metadata.properties["title"], serde_json::json!("Start"));
+        assert!(world.resource::<PendingBridgeEvents>().0.is_empty());
+    }
+}
@@ -69,7 +69,7 @@ pub fn add_edge_system(
 pub fn update_edge_system(
     mut events: EventReader<EdgeUpdated>,
     mut edge_query: Query<(
-        &EdgeEntity,
+        &mut EdgeEntity,
         &mut EdgeRelationship,
         &mut EdgeMetadata,
         Option<&mut EdgeWeight>,
@@ -77,8 +77,14 @@ pub fn update_edge_system(
 ) {
     for event in events.read() {
         // Find and update the edge
-        for (entity, mut relationship, mut metadata, weight) in edge_query.iter_mut() {
+        for (mut entity, mut relationship, mut metadata, weight) in edge_query.iter_mut() {
             if entity.edge_id == event.edge_id {
+                // Redirect the edge if new endpoints are given
+                if let Some((source, target)) = event.endpoints {
+                    entity.source = source;
+                    entity.target = target;
+                }
+
                 // Update relationship if provided
                 if let Some(new_rel) = &event.relationship {
                     *relationship = new_rel.clone();
@@ -152,6 +158,24 @@ pub fn validate_edges_system(
     }
 }
 
+/// System that keeps each edge's arrow style in sync with its relationship and direction
+pub fn sync_edge_arrow_system(
+    mut commands: Commands,
+    edge_query: Query<(
+        Entity,
+        &EdgeRelationship,
+        Option<&EdgeDirection>,
+        Option<&EdgeArrow>,
+    )>,
+) {
+    for (entity, relationship, direction, arrow) in edge_query.iter() {
+        let expected = EdgeArrow::for_edge(relationship, direction.copied());
+        if arrow != Some(&expected) {
+            commands.entity(entity).insert(expected);
+        }
+    }
+}
+
 /// Helper function to parse edge type from metadata
 fn parse_edge_type(edge_type_value: Option<&serde_json::Value>) -> Option<EdgeType> {
     edge_type_value
@@ -222,6 +246,7 @@ mod tests {
             },
             edge_type: "directed".to_string(),
             metadata,
+            created_at: chrono::Utc::now(),
         });
 
         // Run the system
@@ -284,6 +309,8 @@ mod tests {
                     strength: 0.9,
                 }),
                 metadata,
+                endpoints: None,
+                updated_at: chrono::Utc::now(),
             });
 
         // Run the system
@@ -323,7 +350,12 @@ mod tests {
         // Send remove event
         world
             .resource_mut::<Events<EdgeRemoved>>()
-            .send(EdgeRemoved { graph_id, edge_id });
+            .send(EdgeRemoved {
+                graph_id,
+                edge_id,
+                removed: None,
+                removed_at: chrono::Utc::now(),
+            });
 
         // Run the system
         let mut system = IntoSystem::into_system(remove_edge_system);
@@ -355,6 +387,7 @@ mod tests {
             },
             edge_type: "directed".to_string(),
             metadata: HashMap::new(),
+            created_at: chrono::Utc::now(),
         });
 
         // Run the system
@@ -367,4 +400,53 @@ mod tests {
         let mut edge_query = world.query::<&EdgeEntity>();
         assert_eq!(edge_query.iter(&world).count(), 0);
     }
+
+    #[test]
+    fn test_sync_edge_arrow_system() {
+        let mut world = setup_test_world();
+        let graph_id = GraphId::new();
+        let new_edge = || EdgeEntity {
+            edge_id: EdgeId::new(),
+            source: NodeId::new(),
+            target: NodeId::new(),
+            graph_id,
+        };
+
+        let dependency = world
+            .spawn((
+                new_edge(),
+                EdgeRelationship::Dependency {
+                    dependency_type: "requires".to_string(),
+                    strength: 1.0,
+                },
+            ))
+            .id();
+        let bidirectional = world
+            .spawn((
+                new_edge(),
+                EdgeRelationship::Dependency {
+                    dependency_type: "mutual".to_string(),
+                    strength: 1.0,
+                },
+                EdgeDirection::Bidirectional,
+            ))
+            .id();
+        let association = world
+            .spawn((
+                new_edge(),
+                EdgeRelationship::Association {
+                    association_type: "related".to_string(),
+                },
+            ))
+            .id();
+
+        let mut system = IntoSystem::into_system(sync_edge_arrow_system);
+        system.initialize(&mut world);
+        system.run((), &mut world);
+        system.apply_deferred(&mut world);
+
+        assert_eq!(world.get::<EdgeArrow>(dependency), Some(&EdgeArrow::Forward));
+        assert_eq!(world.get::<EdgeArrow>(bidirectional), Some(&EdgeArrow::Both));
+        assert_eq!(world.get::<EdgeArrow>(association), Some(&EdgeArrow::Both));
+    }
 }
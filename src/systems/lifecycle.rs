@@ -174,6 +174,7 @@ mod tests {
                 name: Some("Updated".to_string()),
                 description: None,
                 metadata: new_metadata,
+                updated_at: chrono::Utc::now(),
             });
 
         // Run the system
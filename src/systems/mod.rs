@@ -8,6 +8,7 @@ pub mod spatial;
 pub mod workflow;
 pub mod query;
 pub mod advanced_layout_system;
+pub mod bridge_sync;
 
 // Re-export all systems
 pub use lifecycle::{
@@ -27,6 +28,7 @@ pub use edge_management::{
     update_edge_system,
     remove_edge_system,
     validate_edges_system,
+    sync_edge_arrow_system,
 };
 
 pub use layout::{
@@ -39,6 +41,7 @@ pub use layout::{
 pub use spatial::*;
 pub use workflow::*;
 pub use query::*;
+pub use bridge_sync::{apply_bridge_events_system, PendingBridgeEvents};
 pub use advanced_layout_system::{
     AdvancedLayoutType, AdvancedLayoutConfig, ApplyAdvancedLayout,
     AdvancedLayoutPlugin, LayoutQualityMetrics,
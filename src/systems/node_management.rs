@@ -95,9 +95,7 @@ pub fn update_node_system(
                 }
                 
                 // Update metadata
-                for (key, value) in &event.metadata {
-                    metadata.properties.insert(key.clone(), value.clone());
-                }
+                event.apply_metadata(&mut metadata.properties);
                 metadata.updated_at = std::time::SystemTime::now();
                 
                 // Update content if present
@@ -214,6 +212,7 @@ mod tests {
             position: Position3D::new(10.0, 20.0, 30.0),
             node_type: "process".to_string(),
             metadata,
+            created_at: chrono::Utc::now(),
         });
         
         // Run the system
@@ -271,6 +270,9 @@ mod tests {
             node_id,
             position: Some(Position3D::new(50.0, 50.0, 0.0)),
             metadata,
+            node_type: None,
+            removed_keys: Vec::new(),
+            updated_at: chrono::Utc::now(),
         });
         
         // Run the system
@@ -330,6 +332,7 @@ mod tests {
         world.resource_mut::<Events<NodeRemoved>>().send(NodeRemoved {
             graph_id,
             node_id,
+            removed_at: chrono::Utc::now(),
         });
         
         // Run the system
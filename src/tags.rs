@@ -0,0 +1,105 @@
+//! Node tags
+//!
+//! Tags are stored in node metadata under the reserved [`TAGS_KEY`] as a JSON array
+//! of strings. Tags are case-insensitive: they are trimmed and lowercased before
+//! being stored or compared, and each tag appears at most once per node.
+
+use crate::commands::{GraphCommandError, GraphCommandResult};
+use std::collections::HashMap;
+
+/// Metadata key holding a node's tags
+pub const TAGS_KEY: &str = "tags";
+
+/// Normalize a tag for storage and comparison
+pub fn normalize_tag(tag: &str) -> GraphCommandResult<String> {
+    let tag = tag.trim().to_lowercase();
+    if tag.is_empty() {
+        return Err(GraphCommandError::InvalidCommand(
+            "Tag cannot be empty".to_string(),
+        ));
+    }
+    Ok(tag)
+}
+
+/// Tags stored in node metadata
+pub fn node_tags(metadata: &HashMap<String, serde_json::Value>) -> Vec<String> {
+    metadata
+        .get(TAGS_KEY)
+        .and_then(|value| value.as_array())
+        .map(|tags| {
+            tags.iter()
+                .filter_map(|tag| tag.as_str())
+                .map(|tag| tag.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Whether node metadata carries a tag, ignoring case
+pub fn has_tag(metadata: &HashMap<String, serde_json::Value>, tag: &str) -> bool {
+    let tag = tag.trim().to_lowercase();
+    node_tags(metadata).iter().any(|t| *t == tag)
+}
+
+/// Metadata with `tag` added, or `None` if the node already has it
+pub fn with_tag(
+    metadata: &HashMap<String, serde_json::Value>,
+    tag: &str,
+) -> GraphCommandResult<Option<HashMap<String, serde_json::Value>>> {
+    let tag = normalize_tag(tag)?;
+    let mut tags = node_tags(metadata);
+    if tags.contains(&tag) {
+        return Ok(None);
+    }
+    tags.push(tag);
+    Ok(Some(replace_tags(metadata, tags)))
+}
+
+/// Metadata with `tag` removed, or `None` if the node does not have it
+pub fn without_tag(
+    metadata: &HashMap<String, serde_json::Value>,
+    tag: &str,
+) -> GraphCommandResult<Option<HashMap<String, serde_json::Value>>> {
+    let tag = normalize_tag(tag)?;
+    let mut tags = node_tags(metadata);
+    let before = tags.len();
+    tags.retain(|t| *t != tag);
+    if tags.len() == before {
+        return Ok(None);
+    }
+    Ok(Some(replace_tags(metadata, tags)))
+}
+
+fn replace_tags(
+    metadata: &HashMap<String, serde_json::Value>,
+    tags: Vec<String>,
+) -> HashMap<String, serde_json::Value> {
+    let mut metadata = metadata.clone();
+    if tags.is_empty() {
+        metadata.remove(TAGS_KEY);
+    } else {
+        metadata.insert(TAGS_KEY.to_string(), serde_json::json!(tags));
+    }
+    metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_are_case_insensitive_and_deduplicated() {
+        let metadata = with_tag(&HashMap::new(), "Urgent").unwrap().unwrap();
+        assert_eq!(node_tags(&metadata), vec!["urgent"]);
+        assert!(has_tag(&metadata, "URGENT"));
+
+        // Same tag in a different case is a no-op
+        assert!(with_tag(&metadata, " urgent ").unwrap().is_none());
+
+        let metadata = without_tag(&metadata, "URGENT").unwrap().unwrap();
+        assert!(!metadata.contains_key(TAGS_KEY));
+        assert!(without_tag(&metadata, "urgent").unwrap().is_none());
+
+        assert!(with_tag(&metadata, "  ").is_err());
+    }
+}
@@ -162,6 +162,96 @@ impl Default for Position2D {
     }
 }
 
+/// Graph metadata key selecting the [`GraphMode`] at creation
+pub const GRAPH_MODE_KEY: &str = "mode";
+
+/// Whether a graph's edges have a direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GraphMode {
+    /// Edges lead from source to target only
+    #[default]
+    Directed,
+    /// Edges can be traversed both ways
+    Undirected,
+}
+
+impl GraphMode {
+    /// Read the mode from graph metadata
+    ///
+    /// `"undirected"` (any case) under [`GRAPH_MODE_KEY`] selects `Undirected`; anything
+    /// else, including a missing key, means `Directed`.
+    pub fn from_metadata(metadata: &std::collections::HashMap<String, serde_json::Value>) -> Self {
+        match metadata.get(GRAPH_MODE_KEY).and_then(|v| v.as_str()) {
+            Some(mode) if mode.eq_ignore_ascii_case("undirected") => GraphMode::Undirected,
+            _ => GraphMode::Directed,
+        }
+    }
+}
+
+/// Graph metadata key naming the graph's [`LayoutAlgorithm`]
+pub const LAYOUT_KEY: &str = "layout";
+
+/// Layout algorithm applied when a caller does not choose one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LayoutAlgorithm {
+    /// Spring-based layout
+    #[default]
+    ForceDirected,
+    /// Layered layout following edge direction
+    Hierarchical,
+    /// Nodes placed on a circle
+    Circular,
+    /// Nodes placed on a grid
+    Grid,
+    /// Nodes placed at random
+    Random,
+}
+
+impl LayoutAlgorithm {
+    /// Read the layout from graph metadata
+    ///
+    /// Names under [`LAYOUT_KEY`] are matched ignoring case, with `-` and `_` treated alike,
+    /// so `"force_directed"` and `"Force-Directed"` both select `ForceDirected`. A missing
+    /// key or an unknown name falls back to `ForceDirected`.
+    pub fn from_metadata(metadata: &std::collections::HashMap<String, serde_json::Value>) -> Self {
+        let Some(name) = metadata.get(LAYOUT_KEY).and_then(|v| v.as_str()) else {
+            return LayoutAlgorithm::default();
+        };
+        match name.to_ascii_lowercase().replace('-', "_").as_str() {
+            "hierarchical" => LayoutAlgorithm::Hierarchical,
+            "circular" => LayoutAlgorithm::Circular,
+            "grid" => LayoutAlgorithm::Grid,
+            "random" => LayoutAlgorithm::Random,
+            _ => LayoutAlgorithm::ForceDirected,
+        }
+    }
+}
+
+/// Metric used to measure distance between 2D positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// Straight-line distance
+    #[default]
+    Euclidean,
+    /// Sum of the axis distances (taxicab)
+    Manhattan,
+    /// Largest axis distance
+    Chebyshev,
+}
+
+impl DistanceMetric {
+    /// Distance between two positions under this metric
+    pub fn distance(&self, a: &Position2D, b: &Position2D) -> f64 {
+        let dx = (a.x - b.x).abs();
+        let dy = (a.y - b.y).abs();
+        match self {
+            DistanceMetric::Euclidean => (dx * dx + dy * dy).sqrt(),
+            DistanceMetric::Manhattan => dx + dy,
+            DistanceMetric::Chebyshev => dx.max(dy),
+        }
+    }
+}
+
 /// Represents the position of a node in 3D space
 #[derive(bevy_ecs::component::Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Position3D {
@@ -269,6 +359,32 @@ impl NodeSize {
     pub fn area(&self) -> f64 {
         self.width * self.height
     }
+
+    /// Width divided by height
+    pub fn aspect_ratio(&self) -> f64 {
+        self.width / self.height
+    }
+
+    /// Scale both dimensions by `factor`, which must be positive
+    pub fn scale(&self, factor: f64) -> Result<NodeSize, String> {
+        if !(factor.is_finite() && factor > 0.0) {
+            return Err(format!("Scale factor must be positive, got {factor}"));
+        }
+        NodeSize::new(self.width * factor, self.height * factor)
+    }
+
+    /// Shrink to fit inside `bounds` while preserving the aspect ratio
+    ///
+    /// A size that already fits is returned unchanged.
+    pub fn fit_within(&self, bounds: NodeSize) -> NodeSize {
+        let factor = (bounds.width / self.width)
+            .min(bounds.height / self.height)
+            .min(1.0);
+        NodeSize {
+            width: self.width * factor,
+            height: self.height * factor,
+        }
+    }
 }
 
 impl Default for NodeSize {
@@ -456,6 +572,23 @@ mod tests {
         assert_eq!(Color::GREEN.g, 255);
     }
 
+    #[test]
+    fn test_node_size_fit_within() {
+        let wide = NodeSize::new(200.0, 50.0).unwrap();
+        let square = NodeSize::new(100.0, 100.0).unwrap();
+
+        let fitted = wide.fit_within(square);
+        assert_eq!(fitted, NodeSize::new(100.0, 25.0).unwrap());
+        assert_eq!(fitted.aspect_ratio(), wide.aspect_ratio());
+
+        let small = NodeSize::new(10.0, 20.0).unwrap();
+        assert_eq!(small.fit_within(square), small);
+
+        assert_eq!(wide.scale(0.5).unwrap(), NodeSize::new(100.0, 25.0).unwrap());
+        assert!(wide.scale(0.0).is_err());
+        assert!(wide.scale(-2.0).is_err());
+    }
+
     #[test]
     fn test_style_validation() {
         assert!(Style::new(Color::WHITE, Color::BLACK, 1.0, 0.5).is_ok());
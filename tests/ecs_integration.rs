@@ -119,6 +119,7 @@ fn test_node_addition_system() {
         position,
         node_type: "concept".to_string(),
         metadata: HashMap::new(),
+        created_at: chrono::Utc::now(),
     });
 
     // Run the system
@@ -186,6 +187,7 @@ fn test_edge_connection_system() {
             association_type: "triggers".to_string(),
         },
         metadata: HashMap::new(),
+        created_at: chrono::Utc::now(),
     });
 
     // Run the system
@@ -239,6 +241,8 @@ fn test_node_update_system() {
             node_id,
             position: Some(new_pos),
             metadata: new_metadata,
+            removed_keys: Vec::new(),
+            updated_at: chrono::Utc::now(),
         });
 
     // Run the system
@@ -279,7 +283,11 @@ fn test_node_removal_system() {
     // Send NodeRemoved event
     world
         .resource_mut::<Events<NodeRemoved>>()
-        .send(NodeRemoved { graph_id, node_id });
+        .send(NodeRemoved {
+            graph_id,
+            node_id,
+            removed_at: chrono::Utc::now(),
+        });
 
     // Run the system
     run_system(&mut world, remove_node_system);
@@ -356,6 +364,7 @@ fn test_workflow_node_type_assignment() {
         position: cim_domain_graph::value_objects::Position3D::default(),
         node_type: "workflow_step".to_string(),
         metadata,
+        created_at: chrono::Utc::now(),
     });
 
     // Run the system
@@ -419,6 +428,7 @@ fn test_multiple_graphs_isolation() {
         position: cim_domain_graph::value_objects::Position3D::default(),
         node_type: "task".to_string(),
         metadata: HashMap::new(),
+        created_at: chrono::Utc::now(),
     });
 
     world.resource_mut::<Events<NodeAdded>>().send(NodeAdded {
@@ -427,6 +437,7 @@ fn test_multiple_graphs_isolation() {
         position: cim_domain_graph::value_objects::Position3D::default(),
         node_type: "concept".to_string(),
         metadata: HashMap::new(),
+        created_at: chrono::Utc::now(),
     });
 
     run_system(&mut world, add_node_system);